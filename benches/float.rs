@@ -1,6 +1,6 @@
 use std::hint::black_box;
 use criterion::{criterion_group, criterion_main, Criterion};
-use tifloats::{tifloat, Float};
+use tifloats::tifloat;
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("add", |b| {
@@ -0,0 +1,103 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::Float;
+
+/// Differences that overflow are never within epsilon.
+impl AbsDiffEq for Float {
+    type Epsilon = Float;
+
+    /// One ulp of 1, `1E-13`.
+    fn default_epsilon() -> Float {
+        Float::new_unchecked(false, -13, 0x10000000000000)
+    }
+
+    fn abs_diff_eq(&self, other: &Float, epsilon: Float) -> bool {
+        if self.is_undefined() || other.is_undefined() {
+            return false;
+        }
+
+        (*self - *other).is_ok_and(|difference| difference.abs() <= epsilon)
+    }
+}
+
+impl RelativeEq for Float {
+    /// One ulp of 1, `1E-13`.
+    fn default_max_relative() -> Float {
+        Float::default_epsilon()
+    }
+
+    fn relative_eq(&self, other: &Float, epsilon: Float, max_relative: Float) -> bool {
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        } else if self.is_undefined() || other.is_undefined() {
+            return false;
+        }
+
+        let largest = self.abs().max(other.abs());
+
+        // a tolerance below 1E-99 underflows, but then the Floats already differ by more
+        match (*self - *other, largest * max_relative) {
+            (Ok(difference), Ok(tolerance)) => difference.abs() <= tolerance,
+            _ => false,
+        }
+    }
+}
+
+impl UlpsEq for Float {
+    fn default_max_ulps() -> u32 {
+        4
+    }
+
+    fn ulps_eq(&self, other: &Float, epsilon: Float, max_ulps: u32) -> bool {
+        self.abs_diff_eq(other, epsilon) || self.approx_eq(other, max_ulps as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::{
+        assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq, AbsDiffEq,
+        UlpsEq,
+    };
+
+    use crate::{tifloat, Float};
+
+    #[test]
+    fn abs_diff_eq() {
+        let third = tifloat!(0x33333333333333 * 10 ^ -1);
+
+        assert_abs_diff_eq!(third, tifloat!(0x33333333333334 * 10 ^ -1));
+        assert_abs_diff_eq!(
+            Float::ONE,
+            tifloat!(0x10100000000000 * 10 ^ 0),
+            epsilon = tifloat!(0x10000000000000 * 10 ^ -2)
+        );
+        assert!(!Float::MAX.abs_diff_eq(&-Float::MAX, Float::MAX));
+    }
+
+    #[test]
+    fn relative_eq() {
+        let large = tifloat!(0x12345678901234 * 10 ^ 50);
+
+        assert_relative_eq!(large, large.next_up().unwrap());
+        assert_relative_ne!(large, tifloat!(0x12345678901334 * 10 ^ 50));
+        assert_relative_eq!(
+            large,
+            tifloat!(0x12345678901334 * 10 ^ 50),
+            max_relative = tifloat!(0x10000000000000 * 10 ^ -10)
+        );
+        assert_relative_ne!(Float::undefined(), Float::undefined());
+    }
+
+    #[test]
+    fn ulps_eq() {
+        let large = tifloat!(0x12345678901234 * 10 ^ 50);
+        let mut near = large;
+        for _ in 0..4 {
+            near = near.next_down().unwrap();
+        }
+
+        assert_ulps_eq!(large, near);
+        assert!(!large.ulps_eq(&near.next_down().unwrap(), Float::ZERO, 4));
+    }
+}
@@ -0,0 +1,49 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::Float;
+
+/// Generates valid, normalized Floats across the full exponent range. Zero is produced whenever
+/// the leading digit comes out as zero.
+impl<'a> Arbitrary<'a> for Float {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let negative = bool::arbitrary(u)?;
+        let exponent = u.int_in_range(-99..=99)?;
+
+        let mut mantissa = u.int_in_range(0..=9)?;
+        if mantissa == 0 {
+            return Ok(Float::ZERO);
+        }
+
+        for _ in 1..14 {
+            mantissa = mantissa << 4 | u.int_in_range(0..=9)?;
+        }
+
+        Ok(Float::new_unchecked(negative, exponent, mantissa))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (3, Some(16))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let data = (0..=255u8)
+            .cycle()
+            .step_by(7)
+            .take(4096)
+            .collect::<Vec<_>>();
+        let mut u = Unstructured::new(&data);
+
+        while !u.is_empty() {
+            let float = Float::arbitrary(&mut u).unwrap();
+
+            assert!(float.check().is_ok(), "{float:?}");
+            assert!(float.digits()[0] != 0 || float == Float::ZERO, "{float:?}");
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Float, FloatError};
+
+#[cfg(feature = "fast")]
+mod fast;
+
+#[cfg(feature = "fast")]
+pub use fast::FastFloat;
+
+/// The arithmetic shared by the float backends, so that code can be written once and run either
+/// bit-exactly on [`Float`] or quickly on `FastFloat` (with the `fast` feature).
+///
+/// Operations return [`FloatError::Overflow`], [`FloatError::Underflow`], and
+/// [`FloatError::DivideByZero`] in the same situations for every backend, up to the backend's
+/// accuracy near the edges of the range.
+pub trait TIFloat:
+    Copy
+    + Debug
+    + PartialEq
+    + PartialOrd
+    + From<Float>
+    + Neg<Output = Self>
+    + Add<Output = Result<Self, FloatError>>
+    + Sub<Output = Result<Self, FloatError>>
+    + Mul<Output = Result<Self, FloatError>>
+    + Div<Output = Result<Self, FloatError>>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Converts to the nearest Float.
+    fn to_float(self) -> Result<Float, FloatError>;
+
+    fn is_zero(&self) -> bool;
+
+    fn is_negative(&self) -> bool;
+}
+
+/// The BCD backend, which matches the calculator digit for digit.
+impl TIFloat for Float {
+    const ZERO: Self = Float::ZERO;
+    const ONE: Self = Float::ONE;
+
+    fn to_float(self) -> Result<Float, FloatError> {
+        Ok(self)
+    }
+
+    fn is_zero(&self) -> bool {
+        Float::is_zero(self)
+    }
+
+    fn is_negative(&self) -> bool {
+        Float::is_negative(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn mean<T: TIFloat>(values: &[T]) -> Result<T, FloatError> {
+        let mut sum = T::ZERO;
+        let mut count = T::ZERO;
+        for &value in values {
+            sum = (sum + value)?;
+            count = (count + T::ONE)?;
+        }
+
+        sum / count
+    }
+
+    #[test]
+    fn generic() {
+        let values = [
+            tifloat!(0x10000000000000 * 10 ^ 0),
+            tifloat!(0x20000000000000 * 10 ^ 0),
+            tifloat!(-0x60000000000000 * 10 ^ 0),
+        ];
+
+        assert_eq!(mean(&values).unwrap(), -Float::ONE);
+        assert!(matches!(mean::<Float>(&[]), Err(FloatError::DivideByZero)));
+    }
+}
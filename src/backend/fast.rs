@@ -0,0 +1,170 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::TIFloat;
+use crate::{Float, FloatError};
+
+/// The smallest magnitude that overflows.
+const OVERFLOW: f64 = 1E100;
+
+/// The smallest nonzero magnitude that doesn't underflow.
+const UNDERFLOW: f64 = 1E-99;
+
+/// An `f64`-based float for consumers that care more about speed than bit-exactness, such as
+/// emulators. It checks the TI range after every operation, but doesn't round to 14 digits, so
+/// results can differ from [`Float`]'s in the last few digits.
+///
+/// NaN stands for an undefined value, and propagates like one.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct FastFloat(f64);
+
+impl FastFloat {
+    /// Checks that a result is within the range of a Float.
+    fn new(value: f64) -> Result<Self, FloatError> {
+        let magnitude = value.abs();
+
+        if magnitude >= OVERFLOW {
+            Err(FloatError::Overflow)
+        } else if magnitude < UNDERFLOW && magnitude != 0.0 {
+            Err(FloatError::Underflow)
+        } else {
+            Ok(FastFloat(value))
+        }
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for FastFloat {
+    type Error = FloatError;
+
+    /// Fails for NaNs and infinities, and for values outside the range of a Float.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value.is_nan() {
+            Err(FloatError::NotANumber)
+        } else {
+            FastFloat::new(value)
+        }
+    }
+}
+
+impl From<Float> for FastFloat {
+    fn from(value: Float) -> Self {
+        if value.is_undefined() {
+            FastFloat(f64::NAN)
+        } else {
+            FastFloat(value.into())
+        }
+    }
+}
+
+impl TIFloat for FastFloat {
+    const ZERO: Self = FastFloat(0.0);
+    const ONE: Self = FastFloat(1.0);
+
+    /// Rounds half-up to 14 digits.
+    fn to_float(self) -> Result<Float, FloatError> {
+        if self.0.is_nan() {
+            Ok(Float::undefined())
+        } else {
+            Float::try_from(self.0)
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0.0
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0 < 0.0
+    }
+}
+
+impl Neg for FastFloat {
+    type Output = FastFloat;
+
+    fn neg(self) -> Self::Output {
+        FastFloat(-self.0)
+    }
+}
+
+impl Add for FastFloat {
+    type Output = Result<FastFloat, FloatError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        FastFloat::new(self.0 + rhs.0)
+    }
+}
+
+impl Sub for FastFloat {
+    type Output = Result<FastFloat, FloatError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        FastFloat::new(self.0 - rhs.0)
+    }
+}
+
+impl Mul for FastFloat {
+    type Output = Result<FastFloat, FloatError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        FastFloat::new(self.0 * rhs.0)
+    }
+}
+
+impl Div for FastFloat {
+    type Output = Result<FastFloat, FloatError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.0 == 0.0 && !self.0.is_nan() {
+            Err(FloatError::DivideByZero)
+        } else {
+            FastFloat::new(self.0 / rhs.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn polynomial<T: TIFloat>(x: T) -> Result<T, FloatError> {
+        let two = T::from(tifloat!(0x20000000000000 * 10 ^ 0));
+
+        ((x * x)? - (two * x)?)? + T::ONE
+    }
+
+    #[test]
+    fn matches_bcd() {
+        let x = tifloat!(0x31415926535898 * 10 ^ 0);
+
+        assert_eq!(
+            polynomial(FastFloat::from(x)).unwrap().to_float().unwrap(),
+            polynomial(x).unwrap()
+        );
+    }
+
+    #[test]
+    fn range() {
+        let big = FastFloat::from(tifloat!(0x50000000000000 * 10 ^ 99));
+        let small = FastFloat::from(Float::MIN_POSITIVE);
+
+        assert!(matches!(big + big, Err(FloatError::Overflow)));
+        assert!(matches!(small * small, Err(FloatError::Underflow)));
+        assert!(matches!(
+            FastFloat::ONE / FastFloat::ZERO,
+            Err(FloatError::DivideByZero)
+        ));
+        assert!(matches!(
+            FastFloat::try_from(f64::NAN),
+            Err(FloatError::NotANumber)
+        ));
+        assert!((FastFloat::from(Float::undefined()) / FastFloat::ZERO)
+            .unwrap()
+            .to_float()
+            .unwrap()
+            .is_undefined());
+    }
+}
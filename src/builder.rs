@@ -0,0 +1,124 @@
+use crate::{Flags, Float, FloatError};
+
+impl Float {
+    /// Produces a Float from its sign, exponent, and 14 mantissa digits (MSD = `digits[0]`).
+    ///
+    /// Fails with [`FloatError::InvalidMantissa`] if any digit is larger than 9 and with
+    /// [`FloatError::Overflow`] or [`FloatError::Underflow`] if the exponent is outside `-99..=99`.
+    pub fn from_parts(negative: bool, exponent: i8, digits: [u8; 14]) -> Result<Self, FloatError> {
+        FloatBuilder::new()
+            .negative(negative)
+            .exponent(exponent)
+            .digits(digits)
+            .build()
+    }
+
+    /// The sign, exponent, and 14 mantissa digits of this Float, as accepted by
+    /// [`Float::from_parts`].
+    pub fn to_parts(&self) -> (bool, i8, [u8; 14]) {
+        (self.is_negative(), self.exponent(), self.mantissa_digits())
+    }
+}
+
+/// Builds a Float with explicit flags, such as the undefined values used for the initial terms of
+/// sequences.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct FloatBuilder {
+    negative: bool,
+    exponent: i8,
+    digits: [u8; 14],
+    undefined: bool,
+    complex_half: bool,
+}
+
+impl FloatBuilder {
+    /// A builder for zero, with no flags set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn negative(mut self, negative: bool) -> Self {
+        self.negative = negative;
+        self
+    }
+
+    pub fn exponent(mut self, exponent: i8) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    /// The 14 mantissa digits, MSD first.
+    pub fn digits(mut self, digits: [u8; 14]) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Sets or clears [`Flags::UNDEFINED`].
+    pub fn undefined(mut self, undefined: bool) -> Self {
+        self.undefined = undefined;
+        self
+    }
+
+    /// Sets or clears [`Flags::COMPLEX_HALF`].
+    pub fn complex_half(mut self, complex_half: bool) -> Self {
+        self.complex_half = complex_half;
+        self
+    }
+
+    /// Fails with [`FloatError::InvalidMantissa`] if any digit is larger than 9 and with
+    /// [`FloatError::Overflow`] or [`FloatError::Underflow`] if the exponent is outside `-99..=99`.
+    pub fn build(self) -> Result<Float, FloatError> {
+        if self.digits.iter().any(|&digit| digit > 9) {
+            return Err(FloatError::InvalidMantissa);
+        }
+
+        let mut flags = Flags::empty();
+        flags.set(Flags::NEGATIVE, self.negative);
+        flags.set(Flags::UNDEFINED, self.undefined);
+        flags.set(Flags::COMPLEX_HALF, self.complex_half);
+
+        Float::new(false, self.exponent, Float::mantissa_from(&self.digits))
+            .map(|float| float.with_flags(flags))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn parts() {
+        let digits = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 8];
+        let float = tifloat!(-0x31415926535898 * 10 ^ -5);
+
+        assert_eq!(float.to_parts(), (true, -5, digits));
+        assert_eq!(Float::from_parts(true, -5, digits).ok().unwrap(), float);
+
+        assert!(matches!(
+            Float::from_parts(false, 0, [10; 14]),
+            Err(FloatError::InvalidMantissa)
+        ));
+        assert!(matches!(
+            Float::from_parts(false, 100, digits),
+            Err(FloatError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn builder() {
+        let float = FloatBuilder::new()
+            .negative(true)
+            .undefined(true)
+            .complex_half(true)
+            .build()
+            .ok()
+            .unwrap();
+
+        assert_eq!(
+            float.flags(),
+            Flags::NEGATIVE | Flags::UNDEFINED | Flags::COMPLEX_HALF
+        );
+        assert_eq!(float.to_raw_bytes()[0], 0x8E);
+    }
+}
@@ -0,0 +1,36 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::RawFloat;
+
+// SAFETY: RawFloat is a transparent wrapper around a byte array, so it has no padding and an
+// alignment of 1, and every bit pattern is a valid RawFloat, though not necessarily a valid Float.
+unsafe impl Zeroable for RawFloat {}
+
+// SAFETY: as above.
+unsafe impl Pod for RawFloat {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Float, ParseFloatError, RawFloat};
+
+    #[test]
+    fn cast_slice() {
+        let mut bytes = vec![];
+        for float in [Float::PI, Float::E, Float::MAX] {
+            bytes.extend(float.to_raw_bytes());
+        }
+
+        let raws: &[RawFloat] = bytemuck::cast_slice(&bytes);
+        assert_eq!(raws.len(), 3);
+        assert_eq!(RawFloat::validate(raws), Ok(()));
+        assert_eq!(raws[2].to_float().unwrap(), Float::MAX);
+        assert_eq!(bytemuck::cast_slice::<RawFloat, u8>(raws), bytes);
+
+        bytes[0] = 0x01;
+        assert_eq!(
+            RawFloat::validate(bytemuck::cast_slice(&bytes)),
+            Err(ParseFloatError::InvalidFlags.at(0))
+        );
+        assert!(bytemuck::try_cast_slice::<u8, RawFloat>(&bytes[..10]).is_err());
+    }
+}
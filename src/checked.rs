@@ -0,0 +1,105 @@
+use crate::{Float, FloatError};
+
+/// Clamps an out-of-range result: to zero on underflow, or to `±MAX` with the sign the result
+/// would have had.
+fn saturate(negative: bool, result: Result<Float, FloatError>) -> Float {
+    match result {
+        Ok(float) => float,
+        Err(FloatError::Underflow) => Float::ZERO,
+        Err(_) if negative => -Float::MAX,
+        Err(_) => Float::MAX,
+    }
+}
+
+impl Float {
+    /// Adds two floats, returning `None` if the result is out of range.
+    pub fn checked_add(self, rhs: Float) -> Option<Float> {
+        (self + rhs).ok()
+    }
+
+    /// Subtracts two floats, returning `None` if the result is out of range.
+    pub fn checked_sub(self, rhs: Float) -> Option<Float> {
+        (self - rhs).ok()
+    }
+
+    /// Multiplies two floats, returning `None` if the result is out of range.
+    pub fn checked_mul(self, rhs: Float) -> Option<Float> {
+        (self * rhs).ok()
+    }
+
+    /// Divides two floats, returning `None` if `rhs` is zero or the result is out of range.
+    pub fn checked_div(self, rhs: Float) -> Option<Float> {
+        (self / rhs).ok()
+    }
+
+    /// Adds two floats, clamping to `±MAX` on overflow and to zero on underflow, as many of the
+    /// OS's own routines do.
+    pub fn saturating_add(self, rhs: Float) -> Float {
+        saturate(self.is_negative(), self + rhs)
+    }
+
+    /// Subtracts two floats, clamping to `±MAX` on overflow and to zero on underflow.
+    pub fn saturating_sub(self, rhs: Float) -> Float {
+        saturate(self.is_negative(), self - rhs)
+    }
+
+    /// Multiplies two floats, clamping to `±MAX` on overflow and to zero on underflow.
+    pub fn saturating_mul(self, rhs: Float) -> Float {
+        saturate(self.is_negative() != rhs.is_negative(), self * rhs)
+    }
+
+    /// Divides two floats, clamping to `±MAX` on overflow and to zero on underflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, like the integer `saturating_div`.
+    pub fn saturating_div(self, rhs: Float) -> Float {
+        let result = self / rhs;
+        assert!(
+            !matches!(result, Err(FloatError::DivideByZero)),
+            "attempt to divide by zero"
+        );
+
+        saturate(self.is_negative() != rhs.is_negative(), result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float};
+
+    #[test]
+    fn checked() {
+        let big = tifloat!(0x50000000000000 * 10 ^ 99);
+
+        assert_eq!(
+            Float::ONE.checked_add(Float::ONE),
+            Some(tifloat!(0x20000000000000 * 10 ^ 0))
+        );
+        assert_eq!(big.checked_add(big), None);
+        assert_eq!(Float::ONE.checked_div(Float::ZERO), None);
+    }
+
+    #[test]
+    fn saturating() {
+        let big = tifloat!(0x50000000000000 * 10 ^ 99);
+
+        assert_eq!(big.saturating_add(big), Float::MAX);
+        assert_eq!((-big).saturating_sub(big), -Float::MAX);
+        assert_eq!(
+            tifloat!(0x15000000000000 * 10 ^ -99)
+                .saturating_sub(tifloat!(0x14000000000000 * 10 ^ -99)),
+            Float::ZERO
+        );
+        assert_eq!(
+            Float::ONE.saturating_sub(tifloat!(0x20000000000000 * 10 ^ 0)),
+            Float::NEG_ONE
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn saturating_div_by_zero() {
+        Float::ONE.saturating_div(Float::ZERO);
+    }
+}
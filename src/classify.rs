@@ -0,0 +1,73 @@
+use crate::Float;
+
+/// The nature of a Float, as reported by [`Float::classify`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FloatKind {
+    /// Zero, of either sign.
+    Zero,
+    /// A nonzero real number.
+    Normal,
+    /// An uncomputed sequence term, with the UNDEFINED flag set.
+    Undefined,
+    /// Half of a complex variable.
+    ComplexHalf,
+    /// Not a valid Float: the exponent is outside `-99..=99`, or the mantissa is not 14 BCD
+    /// digits with a nonzero leading digit.
+    Invalid,
+}
+
+impl Float {
+    /// Classifies this Float. The first kind that applies is reported, in the order `Invalid`,
+    /// `Undefined`, `ComplexHalf`, `Zero`, `Normal`.
+    pub fn classify(&self) -> FloatKind {
+        let denormal = !self.is_zero() && self.mantissa_digits()[0] == 0;
+
+        if self.check().is_err() || denormal {
+            FloatKind::Invalid
+        } else if self.is_undefined() {
+            FloatKind::Undefined
+        } else if self.is_complex_half() {
+            FloatKind::ComplexHalf
+        } else if self.is_zero() {
+            FloatKind::Zero
+        } else {
+            FloatKind::Normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn classify() {
+        let mut complex_half = Float::ONE;
+        complex_half.set_complex_half();
+
+        let cases = [
+            (Float::ZERO, FloatKind::Zero),
+            (Float::new_unchecked(true, -5, 0), FloatKind::Zero),
+            (tifloat!(-0x12500000000000 * 10 ^ 3), FloatKind::Normal),
+            (Float::undefined(), FloatKind::Undefined),
+            (complex_half, FloatKind::ComplexHalf),
+            (
+                Float::new_unchecked(false, 0, 0x1A000000000000),
+                FloatKind::Invalid,
+            ),
+            (
+                Float::new_unchecked(false, 0, 0x01000000000000),
+                FloatKind::Invalid,
+            ),
+            (
+                Float::new_unchecked(false, -100, 0x10000000000000),
+                FloatKind::Invalid,
+            ),
+        ];
+
+        for (float, kind) in cases {
+            assert_eq!(float.classify(), kind, "{:?}", float.to_raw_bytes());
+        }
+    }
+}
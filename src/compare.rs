@@ -0,0 +1,135 @@
+use std::cmp::Ordering;
+
+use crate::Float;
+
+impl Float {
+    /// The lesser of two Floats. If one of them is undefined, the other is returned, like
+    /// [`f64::min`] does with NaN.
+    ///
+    /// This shadows [`Ord::min`], which sorts undefined Floats after every other value.
+    pub fn min(self, other: Float) -> Float {
+        if self.is_undefined() || (!other.is_undefined() && other < self) {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The greater of two Floats. If one of them is undefined, the other is returned, like
+    /// [`f64::max`] does with NaN.
+    ///
+    /// This shadows [`Ord::max`], which sorts undefined Floats after every other value.
+    pub fn max(self, other: Float) -> Float {
+        if self.is_undefined() || (!other.is_undefined() && other > self) {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restricts this Float to the interval `[lo, hi]`. An undefined Float is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi` or either bound is undefined, like [`f64::clamp`].
+    pub fn clamp(self, lo: Float, hi: Float) -> Float {
+        assert!(
+            !lo.is_undefined() && !hi.is_undefined() && lo <= hi,
+            "invalid clamp bounds: {lo:?} > {hi:?}"
+        );
+
+        if self.is_undefined() {
+            self
+        } else if self < lo {
+            lo
+        } else if self > hi {
+            hi
+        } else {
+            self
+        }
+    }
+
+    /// Compares absolute values, without negating either Float. As with [`Ord`], undefined
+    /// Floats compare greater than every other value and equal to one another.
+    pub fn cmp_abs(&self, other: &Float) -> Ordering {
+        self.magnitude_key().cmp(&other.magnitude_key())
+    }
+
+    /// Orders by magnitude: zeros first, whatever their exponent, then nonzero values by exponent
+    /// and significand, then undefined values.
+    fn magnitude_key(&self) -> (u8, i8, u64) {
+        if self.is_undefined() {
+            (2, 0, 0)
+        } else if self.is_zero() {
+            (0, 0, 0)
+        } else {
+            (1, self.exponent(), self.significand_dec())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::{tifloat, Float};
+
+    #[test]
+    fn min_max() {
+        let a = tifloat!(-0x20000000000000 * 10 ^ 0);
+        let b = tifloat!(0x15000000000000 * 10 ^ -1);
+
+        assert_eq!(a.min(b), a);
+        assert_eq!(b.min(a), a);
+        assert_eq!(a.max(b), b);
+        assert_eq!(b.max(a), b);
+
+        assert_eq!(a.min(Float::undefined()), a);
+        assert_eq!(Float::undefined().max(b), b);
+        assert!(Float::undefined().min(Float::undefined()).is_undefined());
+    }
+
+    #[test]
+    fn clamp() {
+        let lo = tifloat!(-0x10000000000000 * 10 ^ 1);
+        let hi = tifloat!(0x10000000000000 * 10 ^ 1);
+
+        assert_eq!(Float::MAX.clamp(lo, hi), hi);
+        assert_eq!((-Float::MAX).clamp(lo, hi), lo);
+        assert_eq!(Float::PI.clamp(lo, hi), Float::PI);
+        assert_eq!(Float::PI.clamp(Float::PI, Float::PI), Float::PI);
+        assert!(Float::undefined().clamp(lo, hi).is_undefined());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid clamp bounds")]
+    fn clamp_reversed() {
+        Float::ONE.clamp(Float::ONE, Float::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid clamp bounds")]
+    fn clamp_undefined_bound() {
+        Float::ONE.clamp(Float::ZERO, Float::undefined());
+    }
+
+    #[test]
+    fn cmp_abs() {
+        let small = tifloat!(-0x15000000000000 * 10 ^ -1);
+        let large = tifloat!(0x12000000000000 * 10 ^ 0);
+
+        assert_eq!(small.cmp_abs(&large), Ordering::Less);
+        assert_eq!((-large).cmp_abs(&small), Ordering::Greater);
+        assert_eq!((-large).cmp_abs(&large), Ordering::Equal);
+        assert_eq!(Float::ZERO.cmp_abs(&Float::MIN_POSITIVE), Ordering::Less);
+        assert_eq!(
+            Float::ZERO.cmp_abs(&Float::new_unchecked(true, 5, 0)),
+            Ordering::Equal
+        );
+        assert_eq!(Float::undefined().cmp_abs(&-Float::MAX), Ordering::Greater);
+
+        let mut floats = [large, Float::ZERO, small, -Float::PI];
+        floats.sort_by(Float::cmp_abs);
+        assert_eq!(floats, [Float::ZERO, small, large, -Float::PI]);
+    }
+}
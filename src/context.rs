@@ -0,0 +1,360 @@
+use crate::{Float, FloatError, FormatOptions, RoundingMode, UnderflowMode};
+
+/// How angles are measured by trigonometric functions, corresponding to the third line of the
+/// mode screen. Grads are not offered by the TI-83 Plus family, but are by later models.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AngleMode {
+    #[default]
+    Radian,
+    Degree,
+    /// A right angle is 100 grads.
+    Grad,
+}
+
+/// Whether results may be complex, corresponding to the seventh line of the mode screen.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ComplexMode {
+    /// Operations with nonreal results fail.
+    #[default]
+    Real,
+    /// Complex results are shown as `a+bi`.
+    Rectangular,
+    /// Complex results are shown as `re^θi`.
+    Polar,
+}
+
+/// The calculator modes that affect how values are computed and displayed, so that operations
+/// can be evaluated the way a calculator in those modes would evaluate them.
+///
+/// The default context matches a calculator with default settings, which flushes underflowing
+/// results to zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Context {
+    pub angle_mode: AngleMode,
+    pub complex_mode: ComplexMode,
+    pub format_options: FormatOptions,
+    pub rounding_mode: RoundingMode,
+    pub underflow_mode: UnderflowMode,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context {
+            angle_mode: AngleMode::default(),
+            complex_mode: ComplexMode::default(),
+            format_options: FormatOptions::default(),
+            rounding_mode: RoundingMode::default(),
+            underflow_mode: UnderflowMode::FlushToZero,
+        }
+    }
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Context::default()
+    }
+
+    pub fn add(&self, lhs: Float, rhs: Float) -> Result<Float, FloatError> {
+        self.underflow_mode
+            .apply(lhs.add_with_rounding(rhs, self.rounding_mode))
+    }
+
+    pub fn sub(&self, lhs: Float, rhs: Float) -> Result<Float, FloatError> {
+        self.underflow_mode
+            .apply(lhs.sub_with_rounding(rhs, self.rounding_mode))
+    }
+
+    pub fn mul(&self, lhs: Float, rhs: Float) -> Result<Float, FloatError> {
+        self.underflow_mode
+            .apply(lhs.mul_with_rounding(rhs, self.rounding_mode))
+    }
+
+    pub fn div(&self, lhs: Float, rhs: Float) -> Result<Float, FloatError> {
+        self.underflow_mode
+            .apply(lhs.div_with_rounding(rhs, self.rounding_mode))
+    }
+
+    /// The sine of an angle in this context's [`AngleMode`].
+    pub fn sin(&self, angle: Float) -> Result<Float, FloatError> {
+        self.underflow_mode.apply(angle.sin(self.angle_mode))
+    }
+
+    /// The cosine of an angle in this context's [`AngleMode`].
+    pub fn cos(&self, angle: Float) -> Result<Float, FloatError> {
+        self.underflow_mode.apply(angle.cos(self.angle_mode))
+    }
+
+    /// The tangent of an angle in this context's [`AngleMode`].
+    pub fn tan(&self, angle: Float) -> Result<Float, FloatError> {
+        self.underflow_mode.apply(angle.tan(self.angle_mode))
+    }
+
+    /// The angle of the point `(x, y)` in this context's [`AngleMode`], with [`Float::atan2`].
+    pub fn atan2(&self, y: Float, x: Float) -> Result<Float, FloatError> {
+        self.underflow_mode.apply(y.atan2(&x, self.angle_mode))
+    }
+
+    /// The angle `angle°` in this context's [`AngleMode`], as the calculator evaluates the `°`
+    /// modifier: the angle is taken to be in degrees and converted to the mode with
+    /// [`Float::to_radians`] or [`Float::to_gradians`], rounded once.
+    ///
+    /// For a trigonometric function of a modified angle, such as `sin(180°)` in Radian mode,
+    /// passing the angle straight to [`Float::sin`] with [`AngleMode::Degree`] avoids rounding
+    /// the converted angle, so the sine is exactly zero.
+    #[doc(alias = "°")]
+    pub fn degrees(&self, angle: Float) -> Result<Float, FloatError> {
+        self.underflow_mode
+            .apply(self.interpret(angle, AngleMode::Degree))
+    }
+
+    /// The angle `angleʳ` in this context's [`AngleMode`], as [`Context::degrees`] evaluates
+    /// `angle°`.
+    #[doc(alias = "ʳ")]
+    pub fn radians(&self, angle: Float) -> Result<Float, FloatError> {
+        self.underflow_mode
+            .apply(self.interpret(angle, AngleMode::Radian))
+    }
+
+    /// Converts an angle in the given mode to this context's [`AngleMode`].
+    fn interpret(&self, angle: Float, mode: AngleMode) -> Result<Float, FloatError> {
+        match self.angle_mode {
+            AngleMode::Radian => angle.to_radians(mode),
+            AngleMode::Degree => angle.to_degrees(mode),
+            AngleMode::Grad => angle.to_gradians(mode),
+        }
+    }
+
+    /// The x-coordinate of the point at a distance of `r` from the origin and an angle of
+    /// `theta` in this context's [`AngleMode`], like `P►Rx(`. It is `r·cos(θ)`, rounded once.
+    #[doc(alias = "P►Rx(")]
+    pub fn polar_to_x(&self, r: Float, theta: Float) -> Result<Float, FloatError> {
+        self.underflow_mode
+            .apply(r.polar_x(&theta, self.angle_mode))
+    }
+
+    /// The y-coordinate of the point at a distance of `r` from the origin and an angle of
+    /// `theta` in this context's [`AngleMode`], like `P►Ry(`. It is `r·sin(θ)`, rounded once.
+    #[doc(alias = "P►Ry(")]
+    pub fn polar_to_y(&self, r: Float, theta: Float) -> Result<Float, FloatError> {
+        self.underflow_mode
+            .apply(r.polar_y(&theta, self.angle_mode))
+    }
+
+    /// The distance of the point `(x, y)` from the origin, like `R►Pr(`. It is `√(x²+y²)`,
+    /// rounded once, and only fails if the distance is too large for a Float.
+    #[doc(alias = "R►Pr(")]
+    pub fn rectangular_to_r(&self, x: Float, y: Float) -> Result<Float, FloatError> {
+        x.hypot(&y)
+    }
+
+    /// The angle of the point `(x, y)` in this context's [`AngleMode`], like `R►Pθ(`, which is
+    /// [`Context::atan2`] with its arguments in the other order, including its handling of
+    /// underflow.
+    #[doc(alias = "R►Pθ(")]
+    pub fn rectangular_to_theta(&self, x: Float, y: Float) -> Result<Float, FloatError> {
+        self.atan2(y, x)
+    }
+
+    /// Raises `base` to the power `exponent` with [`Float::pow`]. A negative base with a
+    /// non-integer exponent fails with [`FloatError::NonReal`] in every mode: in Real mode as the
+    /// calculator does, and in the complex modes because the result is a complex number.
+    pub fn pow(&self, base: Float, exponent: Float) -> Result<Float, FloatError> {
+        self.underflow_mode.apply(base.pow(&exponent))
+    }
+
+    /// Produces the string the calculator would display for `value` under this context's
+    /// [`FormatOptions`].
+    pub fn format(&self, value: Float) -> String {
+        value.format_with(&self.format_options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tifloat, DecimalMode, DisplayMode};
+
+    #[test]
+    fn arithmetic() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+        let context = Context::new();
+
+        assert_eq!(
+            context.div(two, three).unwrap(),
+            tifloat!(0x66666666666667 * 10 ^ -1)
+        );
+
+        let truncating = Context {
+            rounding_mode: RoundingMode::Truncate,
+            ..context
+        };
+        assert_eq!(
+            truncating.div(two, three).unwrap(),
+            tifloat!(0x66666666666666 * 10 ^ -1)
+        );
+
+        let a = tifloat!(0x15000000000000 * 10 ^ -99);
+        let b = tifloat!(0x14000000000000 * 10 ^ -99);
+        assert_eq!(context.sub(a, b).unwrap(), Float::ZERO);
+        assert_eq!(
+            Context {
+                underflow_mode: UnderflowMode::Error,
+                ..context
+            }
+            .sub(a, b),
+            Err(FloatError::Underflow)
+        );
+        assert_eq!(context.mul(Float::MAX, two), Err(FloatError::Overflow));
+        assert_eq!(
+            context.add(two, three).unwrap(),
+            tifloat!(0x50000000000000 * 10 ^ 0)
+        );
+    }
+
+    #[test]
+    fn trig() {
+        let right_angle = tifloat!(0x90000000000000 * 10 ^ 1);
+        let mut context = Context::new();
+
+        assert_eq!(
+            context.sin(right_angle),
+            Ok(tifloat!(0x89399666360056 * 10 ^ -1))
+        );
+
+        context.angle_mode = AngleMode::Degree;
+        assert_eq!(context.sin(right_angle), Ok(Float::ONE));
+        assert_eq!(context.cos(right_angle), Ok(Float::ZERO));
+        assert_eq!(context.tan(right_angle), Err(FloatError::Domain));
+        assert_eq!(context.atan2(-Float::ONE, Float::ZERO), Ok(-right_angle));
+
+        // a tiny angle in degrees has a sine and tangent below the smallest Float
+        let tiny = tifloat!(0x10000000000000 * 10 ^ -99);
+        assert_eq!(context.sin(tiny), Ok(Float::ZERO));
+        assert_eq!(context.tan(-tiny), Ok(Float::ZERO));
+        assert_eq!(context.cos(tiny), Ok(Float::ONE));
+        assert_eq!(context.atan2(tiny, Float::MAX), Ok(Float::ZERO));
+        context.underflow_mode = UnderflowMode::Error;
+        assert_eq!(context.sin(tiny), Err(FloatError::Underflow));
+        assert_eq!(context.tan(-tiny), Err(FloatError::Underflow));
+        assert_eq!(context.atan2(tiny, Float::MAX), Err(FloatError::Underflow));
+    }
+
+    #[test]
+    fn modifiers() {
+        let half_turn = tifloat!(0x18000000000000 * 10 ^ 2);
+        let mut context = Context::new();
+
+        assert_eq!(
+            context.degrees(half_turn),
+            Ok(tifloat!(0x31415926535898 * 10 ^ 0))
+        );
+        assert_eq!(context.radians(Float::PI), Ok(Float::PI));
+        assert_eq!(half_turn.sin(AngleMode::Degree), Ok(Float::ZERO));
+
+        context.angle_mode = AngleMode::Degree;
+        assert_eq!(context.degrees(half_turn), Ok(half_turn));
+        assert_eq!(
+            context.radians(Float::ONE),
+            Ok(tifloat!(0x57295779513082 * 10 ^ 1))
+        );
+
+        context.angle_mode = AngleMode::Grad;
+        assert_eq!(
+            context.degrees(half_turn),
+            Ok(tifloat!(0x20000000000000 * 10 ^ 2))
+        );
+
+        let tiny = tifloat!(0x10000000000000 * 10 ^ -99);
+        context.angle_mode = AngleMode::Radian;
+        assert_eq!(context.degrees(tiny), Ok(Float::ZERO));
+        context.underflow_mode = UnderflowMode::Error;
+        assert_eq!(context.degrees(tiny), Err(FloatError::Underflow));
+    }
+
+    #[test]
+    fn coordinates() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+        let four = tifloat!(0x40000000000000 * 10 ^ 0);
+        let mut context = Context::new();
+
+        assert_eq!(
+            context.polar_to_x(two, Float::ONE),
+            Ok(tifloat!(0x10806046117363 * 10 ^ 0))
+        );
+        assert_eq!(
+            context.polar_to_y(-two, Float::ONE),
+            Ok(tifloat!(-0x16829419696158 * 10 ^ 0))
+        );
+        assert_eq!(
+            context.rectangular_to_r(-three, four),
+            Ok(tifloat!(0x50000000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            context.rectangular_to_theta(-three, four),
+            Ok(tifloat!(0x22142974355882 * 10 ^ 0))
+        );
+
+        context.angle_mode = AngleMode::Degree;
+        let angle = tifloat!(0x15000000000000 * 10 ^ 2);
+        assert_eq!(
+            context.polar_to_x(two, angle),
+            Ok(-tifloat!(0x17320508075689 * 10 ^ 0))
+        );
+        assert_eq!(context.polar_to_y(two, -angle), Ok(-Float::ONE));
+        assert_eq!(
+            context.polar_to_y(two, tifloat!(0x18000000000000 * 10 ^ 2)),
+            Ok(Float::ZERO)
+        );
+        assert_eq!(
+            context.rectangular_to_theta(-Float::ONE, -Float::ONE),
+            Ok(-tifloat!(0x13500000000000 * 10 ^ 2))
+        );
+
+        let tiny = tifloat!(0x10000000000000 * 10 ^ -99);
+        let small_angle = tifloat!(0x10000000000000 * 10 ^ -1);
+        assert_eq!(context.polar_to_y(tiny, small_angle), Ok(Float::ZERO));
+        assert_eq!(
+            context.rectangular_to_theta(Float::MAX, tiny),
+            Ok(Float::ZERO)
+        );
+        context.underflow_mode = UnderflowMode::Error;
+        assert_eq!(
+            context.polar_to_y(tiny, small_angle),
+            Err(FloatError::Underflow)
+        );
+        assert_eq!(
+            context.rectangular_to_theta(Float::MAX, tiny),
+            Err(FloatError::Underflow)
+        );
+    }
+
+    #[test]
+    fn pow() {
+        let half = tifloat!(0x50000000000000 * 10 ^ -1);
+        let mut context = Context::new();
+
+        assert_eq!(context.pow(-Float::ONE, half), Err(FloatError::NonReal));
+        assert_eq!(
+            context.pow(half, tifloat!(0x40000000000000 * 10 ^ 2)),
+            Ok(Float::ZERO)
+        );
+
+        context.complex_mode = ComplexMode::Rectangular;
+        assert_eq!(context.pow(-Float::ONE, half), Err(FloatError::NonReal));
+        assert_eq!(
+            context.pow(Float::ZERO, Float::ZERO),
+            Err(FloatError::Domain)
+        );
+    }
+
+    #[test]
+    fn format() {
+        let mut context = Context::new();
+        assert_eq!(context.format(Float::PI), "3.141592654");
+
+        context.format_options = FormatOptions::new(DisplayMode::Sci, DecimalMode::Fix(2));
+        assert_eq!(context.format(Float::PI), "3.14E0");
+    }
+}
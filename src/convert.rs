@@ -0,0 +1,280 @@
+use crate::{Float, FloatError};
+
+mod bid;
+#[cfg(feature = "bigdecimal")]
+mod big_decimal;
+#[cfg(feature = "num-bigint")]
+mod big_rational;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+
+impl TryFrom<f32> for Float {
+    type Error = FloatError;
+
+    /// Converts an `f32` to the nearest Float, rounding half-up like the calculator does.
+    ///
+    /// The exact decimal expansion of the `f32` is rounded to 14 significant digits in a single
+    /// step, so there is no double rounding. Every finite `f32` is within the range of a Float,
+    /// so this only fails for infinities ([`FloatError::Overflow`]) and NaNs
+    /// ([`FloatError::NotANumber`]).
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        if value.is_nan() {
+            return Err(FloatError::NotANumber);
+        } else if value.is_infinite() {
+            return Err(FloatError::Overflow);
+        }
+
+        let bits = value.to_bits();
+        let negative = bits >> 31 != 0;
+        let biased_exponent = ((bits >> 23) & 0xFF) as i32;
+        let fraction = (bits & 0x7FFFFF) as u64;
+
+        let (significand, exponent) = if biased_exponent == 0 {
+            (fraction, -149)
+        } else {
+            (fraction | 0x800000, biased_exponent - 150)
+        };
+
+        from_binary(negative, significand, exponent)
+    }
+}
+
+impl TryFrom<f64> for Float {
+    type Error = FloatError;
+
+    /// Converts an `f64` to the nearest Float, rounding its exact decimal expansion half-up to 14
+    /// significant digits in a single step.
+    ///
+    /// Fails with [`FloatError::NotANumber`] for NaNs, with [`FloatError::Overflow`] for
+    /// infinities and values too large for a Float, and with [`FloatError::Underflow`] for nonzero
+    /// values too small for one.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value.is_nan() {
+            return Err(FloatError::NotANumber);
+        } else if value.is_infinite() {
+            return Err(FloatError::Overflow);
+        }
+
+        let bits = value.to_bits();
+        let negative = bits >> 63 != 0;
+        let biased_exponent = ((bits >> 52) & 0x7FF) as i32;
+        let fraction = bits & 0xFFFFFFFFFFFFF;
+
+        let (significand, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 0x10000000000000, biased_exponent - 1075)
+        };
+
+        from_binary(negative, significand, exponent)
+    }
+}
+
+impl From<Float> for f64 {
+    /// Converts a Float to the nearest `f64`, or to NaN if the Float is undefined.
+    ///
+    /// Every 14-digit decimal survives a round trip through an `f64`, so converting the result
+    /// back with [`Float::try_from`] produces the original value.
+    fn from(value: Float) -> Self {
+        if value.is_undefined() {
+            return f64::NAN;
+        }
+
+        let magnitude = to_binary(value.significand_dec(), value.exponent() as i32 - 13);
+
+        if value.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// Rounds `significand * 10 ^ exponent` to the nearest `f64`, and to an even significand on a
+/// tie, by computing it exactly in 32-bit limbs, least significant first. The value must be
+/// within the range of a Float, so that the result is a normal `f64`.
+fn to_binary(significand: u64, exponent: i32) -> f64 {
+    if significand == 0 {
+        return 0.0;
+    }
+
+    // leading zero limbs leave room for at least 55 significant bits above the binary point, so
+    // there is always a rounding bit after the 53 that are kept
+    let zeros = if exponent >= 0 {
+        2
+    } else {
+        (56 + 4 * exponent.unsigned_abs() as usize).div_ceil(32)
+    };
+    let mut limbs = vec![0u32; zeros];
+    limbs.extend([significand as u32, (significand >> 32) as u32]);
+
+    let mut sticky = false;
+    for _ in 0..exponent.unsigned_abs() {
+        if exponent > 0 {
+            let mut carry = 0;
+            for limb in limbs.iter_mut() {
+                let product = *limb as u64 * 10 + carry;
+                *limb = product as u32;
+                carry = product >> 32;
+            }
+
+            if carry != 0 {
+                limbs.push(carry as u32);
+            }
+        } else {
+            let mut remainder = 0;
+            for limb in limbs.iter_mut().rev() {
+                let dividend = remainder << 32 | *limb as u64;
+                *limb = (dividend / 10) as u32;
+                remainder = dividend % 10;
+            }
+
+            sticky |= remainder != 0;
+        }
+    }
+
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+
+    let bits = limbs.len() * 32 - limbs.last().unwrap().leading_zeros() as usize;
+    let bit = |index: usize| (limbs[index / 32] >> (index % 32) & 1) as u64;
+
+    let top = (bits - 54..bits)
+        .rev()
+        .fold(0, |top, index| top << 1 | bit(index));
+    sticky |= (0..bits - 54).any(|index| bit(index) != 0);
+
+    // a carry out of the 53 bits that are kept still converts exactly
+    let mut kept = top >> 1;
+    if top & 1 == 1 && (sticky || kept & 1 == 1) {
+        kept += 1;
+    }
+
+    let scale = bits as i64 - 53 - 32 * zeros as i64;
+
+    kept as f64 * f64::from_bits(((1023 + scale) as u64) << 52)
+}
+
+/// Produces the Float nearest to `significand * 2 ^ exponent` by computing its exact decimal
+/// expansion.
+fn from_binary(negative: bool, significand: u64, exponent: i32) -> Result<Float, FloatError> {
+    // least significant digit first while multiplying
+    let mut digits = significand
+        .to_string()
+        .bytes()
+        .rev()
+        .map(|byte| byte - b'0')
+        .collect::<Vec<_>>();
+
+    // 2 ^ -n = 5 ^ n * 10 ^ -n
+    let multiplier = if exponent > 0 { 2 } else { 5 };
+    for _ in 0..exponent.unsigned_abs() {
+        let mut carry = 0;
+        for digit in digits.iter_mut() {
+            let product = *digit * multiplier + carry;
+            *digit = product % 10;
+            carry = product / 10;
+        }
+
+        if carry != 0 {
+            digits.push(carry);
+        }
+    }
+
+    digits.reverse();
+    let scale = exponent.min(0) as i64;
+
+    Float::from_decimal_digits(negative, &digits, digits.len() as i64 - 1 + scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn from_f32() {
+        let cases = [
+            (0.5, tifloat!(0x50000000000000 * 10 ^ -1)),
+            (-2.5, tifloat!(-0x25000000000000 * 10 ^ 0)),
+            (0.1, tifloat!(0x10000000149012 * 10 ^ -1)),
+            (16777216.0, tifloat!(0x16777216000000 * 10 ^ 7)),
+            (f32::MAX, tifloat!(0x34028234663853 * 10 ^ 38)),
+            (f32::MIN_POSITIVE, tifloat!(0x11754943508223 * 10 ^ -38)),
+            (f32::from_bits(1), tifloat!(0x14012984643248 * 10 ^ -45)),
+            (0.0, tifloat!(0x00000000000000 * 10 ^ 0)),
+            (-0.0, tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(Float::try_from(value).ok().unwrap(), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn from_f64() {
+        let cases = [
+            (0.1, tifloat!(0x10000000000000 * 10 ^ -1)),
+            (-2.5, tifloat!(-0x25000000000000 * 10 ^ 0)),
+            (std::f64::consts::PI, tifloat!(0x31415926535898 * 10 ^ 0)),
+            (1e99, tifloat!(0x10000000000000 * 10 ^ 99)),
+            (1.5e-99, tifloat!(0x15000000000000 * 10 ^ -99)),
+            (0.0, tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(Float::try_from(value).ok().unwrap(), expected, "{value}");
+        }
+
+        for value in [f64::INFINITY, 1e100, f64::MAX] {
+            assert!(
+                matches!(Float::try_from(value), Err(FloatError::Overflow)),
+                "{value}"
+            );
+        }
+        for value in [1e-100, -f64::MIN_POSITIVE] {
+            assert!(
+                matches!(Float::try_from(value), Err(FloatError::Underflow)),
+                "{value}"
+            );
+        }
+        assert!(matches!(
+            Float::try_from(f64::NAN),
+            Err(FloatError::NotANumber)
+        ));
+    }
+
+    #[test]
+    fn to_f64() {
+        let cases = [
+            (tifloat!(0x10000000000000 * 10 ^ -1), 0.1),
+            (tifloat!(-0x12345678901234 * 10 ^ 0), -1.2345678901234),
+            (tifloat!(0x99999999999999 * 10 ^ 99), 9.9999999999999e99),
+            (tifloat!(0x00000000000000 * 10 ^ 0), 0.0),
+            (Float::MIN_POSITIVE, 1e-99),
+            (tifloat!(-0x31415926535898 * 10 ^ -42), -3.1415926535898e-42),
+            // exactly halfway between two f64s, so it rounds to the even one
+            (tifloat!(0x80085254226339 * 10 ^ 16), 8.0085254226339e16),
+        ];
+
+        for (float, expected) in cases {
+            assert_eq!(f64::from(float), expected, "{float:?}");
+            assert_eq!(Float::try_from(f64::from(float)).ok().unwrap(), float);
+        }
+
+        assert!(f64::from(Float::undefined()).is_nan());
+    }
+
+    #[test]
+    fn from_f32_non_finite() {
+        assert!(matches!(
+            Float::try_from(f32::NAN),
+            Err(FloatError::NotANumber)
+        ));
+        assert!(matches!(
+            Float::try_from(f32::INFINITY),
+            Err(FloatError::Overflow)
+        ));
+    }
+}
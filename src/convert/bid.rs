@@ -0,0 +1,249 @@
+//! IEEE 754-2008 decimal interchange formats, using the binary integer decimal (BID) encoding.
+
+use crate::{Float, FloatError};
+
+/// Parameters of a decimal interchange format.
+struct Interchange {
+    /// Total width of the encoding in bits.
+    width: u32,
+    /// Width of the exponent continuation field in bits.
+    exponent_bits: u32,
+    bias: i64,
+    /// Maximum number of digits in the coefficient.
+    precision: u32,
+}
+
+const DECIMAL64: Interchange = Interchange {
+    width: 64,
+    exponent_bits: 10,
+    bias: 398,
+    precision: 16,
+};
+
+const DECIMAL128: Interchange = Interchange {
+    width: 128,
+    exponent_bits: 14,
+    bias: 6176,
+    precision: 34,
+};
+
+impl Interchange {
+    /// Width of the coefficient field in bits when the coefficient has no implicit prefix.
+    fn coefficient_bits(&self) -> u32 {
+        self.width - 1 - self.exponent_bits
+    }
+
+    fn decode(&self, bits: u128) -> Result<Float, FloatError> {
+        let negative = bits >> (self.width - 1) & 1 != 0;
+        let coefficient_bits = self.coefficient_bits();
+        let exponent_mask = (1 << self.exponent_bits) - 1;
+
+        let (exponent, coefficient) = if bits >> (self.width - 3) & 0b11 == 0b11 {
+            match bits >> (self.width - 6) & 0b11111 {
+                0b11110 => return Err(FloatError::Overflow),
+                0b11111 => return Err(FloatError::NotANumber),
+                _ => {}
+            }
+
+            // the coefficient has an implicit 0b100 prefix
+            let low_bits = coefficient_bits - 2;
+
+            (
+                bits >> low_bits & exponent_mask,
+                4 << low_bits | bits & ((1 << low_bits) - 1),
+            )
+        } else {
+            (
+                bits >> coefficient_bits & exponent_mask,
+                bits & ((1 << coefficient_bits) - 1),
+            )
+        };
+
+        // non-canonical coefficients are interpreted as zero
+        let coefficient = if coefficient < 10u128.pow(self.precision) {
+            coefficient
+        } else {
+            0
+        };
+
+        let digits = coefficient
+            .to_string()
+            .bytes()
+            .map(|byte| byte - b'0')
+            .collect::<Vec<_>>();
+
+        Float::from_decimal_digits(
+            negative,
+            &digits,
+            digits.len() as i64 - 1 + exponent as i64 - self.bias,
+        )
+    }
+
+    fn encode(&self, value: &Float) -> Result<u128, FloatError> {
+        if value.is_undefined() {
+            return Err(FloatError::NotANumber);
+        }
+
+        let significant_figures = value.significant_figures();
+
+        let coefficient = significant_figures
+            .iter()
+            .fold(0u128, |acc, &digit| acc * 10 + digit as u128);
+
+        let exponent = if coefficient == 0 {
+            0
+        } else {
+            value.exponent() as i64 - (significant_figures.len() as i64 - 1)
+        };
+
+        Ok(((value.is_negative() as u128) << (self.width - 1))
+            | ((exponent + self.bias) as u128) << self.coefficient_bits()
+            | coefficient)
+    }
+}
+
+impl Float {
+    /// Decodes an IEEE 754 decimal64 value in the BID encoding, rounding half-up to 14
+    /// significant digits.
+    ///
+    /// Fails with [`FloatError::NotANumber`] for NaNs, with [`FloatError::Overflow`] for
+    /// infinities and values too large for a Float, and with [`FloatError::Underflow`] for nonzero
+    /// values too small for one.
+    pub fn from_decimal64(bits: u64) -> Result<Float, FloatError> {
+        DECIMAL64.decode(bits as u128)
+    }
+
+    /// Encodes this Float exactly as an IEEE 754 decimal64 value in the BID encoding.
+    ///
+    /// Fails with [`FloatError::NotANumber`] if the value is undefined.
+    pub fn to_decimal64(&self) -> Result<u64, FloatError> {
+        DECIMAL64.encode(self).map(|bits| bits as u64)
+    }
+
+    /// Decodes an IEEE 754 decimal128 value in the BID encoding, rounding half-up to 14
+    /// significant digits.
+    ///
+    /// Fails with [`FloatError::NotANumber`] for NaNs, with [`FloatError::Overflow`] for
+    /// infinities and values too large for a Float, and with [`FloatError::Underflow`] for nonzero
+    /// values too small for one.
+    pub fn from_decimal128(bits: u128) -> Result<Float, FloatError> {
+        DECIMAL128.decode(bits)
+    }
+
+    /// Encodes this Float exactly as an IEEE 754 decimal128 value in the BID encoding.
+    ///
+    /// Fails with [`FloatError::NotANumber`] if the value is undefined.
+    pub fn to_decimal128(&self) -> Result<u128, FloatError> {
+        DECIMAL128.encode(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn decimal64() {
+        let cases = [
+            (tifloat!(0x10000000000000 * 10 ^ 0), 0x31C0000000000001),
+            (tifloat!(-0x15000000000000 * 10 ^ 0), 0xB1A000000000000F),
+            (
+                tifloat!(0x31415926535898 * 10 ^ 0),
+                0x3020000000000000 | 31415926535898,
+            ),
+            (
+                tifloat!(0x99999999999999 * 10 ^ 99),
+                0x3C80000000000000 | 99999999999999,
+            ),
+            (tifloat!(0x10000000000000 * 10 ^ -99), 0x2560000000000001),
+            (tifloat!(0x00000000000000 * 10 ^ 0), 0x31C0000000000000),
+        ];
+
+        for (value, bits) in cases {
+            assert_eq!(value.to_decimal64().ok().unwrap(), bits, "{value:?}");
+            assert_eq!(Float::from_decimal64(bits).ok().unwrap(), value, "{bits:X}");
+        }
+    }
+
+    #[test]
+    fn decimal64_rounding() {
+        let cases = [
+            // 1234567890123456E0
+            (0x31C462D53C8ABAC0, tifloat!(0x12345678901235 * 10 ^ 15)),
+            // 9999999999999999E-16, with the implicit coefficient prefix
+            (0x6BF386F26FC0FFFF, tifloat!(0x10000000000000 * 10 ^ 0)),
+            // non-canonical coefficient
+            (0x6FFFFFFFFFFFFFFF, tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (bits, expected) in cases {
+            assert_eq!(
+                Float::from_decimal64(bits).ok().unwrap(),
+                expected,
+                "{bits:X}"
+            );
+        }
+    }
+
+    #[test]
+    fn decimal64_errors() {
+        assert!(matches!(
+            Float::from_decimal64(0x7C00000000000000),
+            Err(FloatError::NotANumber)
+        ));
+        assert!(matches!(
+            Float::from_decimal64(0xF800000000000000),
+            Err(FloatError::Overflow)
+        ));
+        // 1E100
+        assert!(matches!(
+            Float::from_decimal64(0x3E40000000000001),
+            Err(FloatError::Overflow)
+        ));
+        assert!(matches!(
+            Float::undefined().to_decimal64(),
+            Err(FloatError::NotANumber)
+        ));
+    }
+
+    #[test]
+    fn decimal128() {
+        let cases = [
+            (
+                tifloat!(0x10000000000000 * 10 ^ 0),
+                0x3040_0000_0000_0000_0000_0000_0000_0001,
+            ),
+            (
+                tifloat!(-0x15000000000000 * 10 ^ 0),
+                0xB03E_0000_0000_0000_0000_0000_0000_000F,
+            ),
+            (
+                tifloat!(0x00000000000000 * 10 ^ 0),
+                0x3040_0000_0000_0000_0000_0000_0000_0000,
+            ),
+        ];
+
+        for (value, bits) in cases {
+            assert_eq!(value.to_decimal128().ok().unwrap(), bits, "{value:?}");
+            assert_eq!(
+                Float::from_decimal128(bits).ok().unwrap(),
+                value,
+                "{bits:X}"
+            );
+        }
+
+        assert!(matches!(
+            Float::from_decimal128(0x7C00 << 112),
+            Err(FloatError::NotANumber)
+        ));
+        assert!(matches!(
+            Float::from_decimal128(0x7800 << 112),
+            Err(FloatError::Overflow)
+        ));
+        assert!(matches!(
+            Float::undefined().to_decimal128(),
+            Err(FloatError::NotANumber)
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::BigDecimal;
+
+use crate::{Float, FloatError};
+
+impl TryFrom<&BigDecimal> for Float {
+    type Error = FloatError;
+
+    /// Converts a `BigDecimal` to the nearest Float, rounding half-up to 14 significant digits.
+    ///
+    /// Fails with [`FloatError::Overflow`] or [`FloatError::Underflow`] if the rounded value is
+    /// outside the range of a Float.
+    fn try_from(value: &BigDecimal) -> Result<Self, Self::Error> {
+        let (integer, scale) = value.as_bigint_and_exponent();
+
+        let digits = integer.magnitude().to_radix_be(10);
+
+        Float::from_decimal_digits(
+            integer.sign() == Sign::Minus,
+            &digits,
+            digits.len() as i64 - 1 - scale,
+        )
+    }
+}
+
+impl TryFrom<BigDecimal> for Float {
+    type Error = FloatError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        Float::try_from(&value)
+    }
+}
+
+impl TryFrom<Float> for BigDecimal {
+    type Error = FloatError;
+
+    /// Converts a Float to a `BigDecimal`. Every defined Float is exactly representable.
+    ///
+    /// Fails with [`FloatError::NotANumber`] if the value is undefined.
+    fn try_from(value: Float) -> Result<Self, Self::Error> {
+        if value.is_undefined() {
+            return Err(FloatError::NotANumber);
+        }
+
+        let sign = if value.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+
+        let integer = BigInt::from_radix_be(sign, &value.digits(), 10).unwrap();
+
+        Ok(BigDecimal::new(integer, 13 - value.exponent() as i64).normalized())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn big(value: &str) -> BigDecimal {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn from_big_decimal() {
+        let cases = [
+            ("19.99", tifloat!(0x19990000000000 * 10 ^ 1)),
+            ("-0.005", tifloat!(-0x50000000000000 * 10 ^ -3)),
+            (
+                "3.14159265358979323846264338327950288",
+                tifloat!(0x31415926535898 * 10 ^ 0),
+            ),
+            ("9.99999999999995", tifloat!(0x10000000000000 * 10 ^ 1)),
+            ("1.2345E+99", tifloat!(0x12345000000000 * 10 ^ 99)),
+            ("0.000", tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(
+                Float::try_from(big(value)).ok().unwrap(),
+                expected,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn from_big_decimal_overflow() {
+        for value in ["1E100", "9.99999999999995E99"] {
+            assert!(
+                matches!(Float::try_from(big(value)), Err(FloatError::Overflow)),
+                "{value}"
+            );
+        }
+        assert!(matches!(
+            Float::try_from(big("1E-100")),
+            Err(FloatError::Underflow)
+        ));
+    }
+
+    #[test]
+    fn to_big_decimal() {
+        let cases = [
+            (tifloat!(0x19990000000000 * 10 ^ 1), "19.99"),
+            (tifloat!(-0x50000000000000 * 10 ^ -3), "-0.005"),
+            (tifloat!(0x31415926535898 * 10 ^ 0), "3.1415926535898"),
+            (tifloat!(0x99999999999999 * 10 ^ 99), "9.9999999999999E+99"),
+            (tifloat!(0x00000000000000 * 10 ^ 0), "0"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(
+                BigDecimal::try_from(value).ok().unwrap(),
+                big(expected),
+                "{value:?}"
+            );
+        }
+
+        assert!(matches!(
+            BigDecimal::try_from(Float::undefined()),
+            Err(FloatError::NotANumber)
+        ));
+    }
+}
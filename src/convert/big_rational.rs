@@ -0,0 +1,206 @@
+use num_bigint::{BigInt, Sign};
+use num_rational::BigRational;
+use num_traits::{Pow, Signed, Zero};
+
+use crate::{Float, FloatError};
+
+impl TryFrom<&BigInt> for Float {
+    type Error = FloatError;
+
+    /// Converts a `BigInt` to the nearest Float, rounding half-up to 14 significant digits.
+    ///
+    /// Fails with [`FloatError::Overflow`] if the rounded value is 10^100 or larger in magnitude.
+    fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+        let digits = value.magnitude().to_radix_be(10);
+
+        Float::from_decimal_digits(
+            value.sign() == Sign::Minus,
+            &digits,
+            digits.len() as i64 - 1,
+        )
+    }
+}
+
+impl TryFrom<BigInt> for Float {
+    type Error = FloatError;
+
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        Float::try_from(&value)
+    }
+}
+
+impl TryFrom<&BigRational> for Float {
+    type Error = FloatError;
+
+    /// Converts a `BigRational` to the nearest Float, rounding half-up to 14 significant digits.
+    ///
+    /// Fails with [`FloatError::DivideByZero`] if the denominator is zero and with
+    /// [`FloatError::Overflow`] or [`FloatError::Underflow`] if the rounded value is outside the
+    /// range of a Float.
+    fn try_from(value: &BigRational) -> Result<Self, Self::Error> {
+        if value.denom().is_zero() {
+            return Err(FloatError::DivideByZero);
+        } else if value.numer().is_zero() {
+            return Ok(Float::ZERO);
+        }
+
+        let negative = value.is_negative();
+        let numerator = value.numer().abs();
+        let denominator = value.denom().abs();
+
+        // estimate the place-value of the leading digit, then correct it
+        let mut exponent =
+            numerator.to_string().len() as i64 - denominator.to_string().len() as i64;
+        if scaled(&numerator, &denominator, -exponent) < BigInt::from(1) {
+            exponent -= 1;
+        }
+
+        // one digit past the mantissa is enough to round half-up
+        let digits = scaled(&numerator, &denominator, 14 - exponent)
+            .magnitude()
+            .to_radix_be(10);
+
+        Float::from_decimal_digits(negative, &digits, exponent)
+    }
+}
+
+impl TryFrom<BigRational> for Float {
+    type Error = FloatError;
+
+    fn try_from(value: BigRational) -> Result<Self, Self::Error> {
+        Float::try_from(&value)
+    }
+}
+
+impl TryFrom<Float> for BigRational {
+    type Error = FloatError;
+
+    /// Converts a Float to its exact value as a reduced fraction.
+    ///
+    /// Fails with [`FloatError::NotANumber`] if the value is undefined.
+    fn try_from(value: Float) -> Result<Self, Self::Error> {
+        if value.is_undefined() {
+            return Err(FloatError::NotANumber);
+        }
+
+        let sign = if value.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+
+        let mantissa = BigInt::from_radix_be(sign, &value.digits(), 10).unwrap();
+        let exponent = value.exponent() as i32 - 13;
+        let scale = BigInt::from(10).pow(exponent.unsigned_abs());
+
+        Ok(if exponent >= 0 {
+            BigRational::from_integer(mantissa * scale)
+        } else {
+            BigRational::new(mantissa, scale)
+        })
+    }
+}
+
+/// `floor(numerator / denominator * 10 ^ shift)` for positive operands.
+fn scaled(numerator: &BigInt, denominator: &BigInt, shift: i64) -> BigInt {
+    let scale = BigInt::from(10).pow(shift.unsigned_abs());
+
+    if shift >= 0 {
+        numerator * scale / denominator
+    } else {
+        numerator / (denominator * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn ratio(numerator: i64, denominator: i64) -> BigRational {
+        BigRational::new(numerator.into(), denominator.into())
+    }
+
+    #[test]
+    fn from_big_int() {
+        let cases = [
+            ("12345", tifloat!(0x12345000000000 * 10 ^ 4)),
+            (
+                "-100000000000000000000",
+                tifloat!(-0x10000000000000 * 10 ^ 20),
+            ),
+            ("123456789012345", tifloat!(0x12345678901235 * 10 ^ 14)),
+            ("0", tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (value, expected) in cases {
+            let value = value.parse::<BigInt>().unwrap();
+
+            assert_eq!(Float::try_from(&value).ok().unwrap(), expected, "{value}");
+        }
+
+        assert!(matches!(
+            Float::try_from(BigInt::from(10).pow(100u32)),
+            Err(FloatError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn from_big_rational() {
+        let cases = [
+            (ratio(1, 3), tifloat!(0x33333333333333 * 10 ^ -1)),
+            (ratio(2, 3), tifloat!(0x66666666666667 * 10 ^ -1)),
+            (ratio(-1, 7), tifloat!(-0x14285714285714 * 10 ^ -1)),
+            (ratio(100, 1), tifloat!(0x10000000000000 * 10 ^ 2)),
+            (ratio(1, 1000), tifloat!(0x10000000000000 * 10 ^ -3)),
+            (ratio(99, 100), tifloat!(0x99000000000000 * 10 ^ -1)),
+            (ratio(355, 113), tifloat!(0x31415929203540 * 10 ^ 0)),
+            (ratio(0, 5), tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(Float::try_from(&value).ok().unwrap(), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn from_big_rational_errors() {
+        assert!(matches!(
+            Float::try_from(BigRational::new_raw(1.into(), 0.into())),
+            Err(FloatError::DivideByZero)
+        ));
+        assert!(matches!(
+            Float::try_from(BigRational::new(1.into(), BigInt::from(10).pow(100u32))),
+            Err(FloatError::Underflow)
+        ));
+    }
+
+    #[test]
+    fn to_big_rational() {
+        let cases = [
+            (
+                tifloat!(0x33333333333333 * 10 ^ -1),
+                ratio(33333333333333, 100000000000000),
+            ),
+            (tifloat!(-0x12500000000000 * 10 ^ 1), ratio(-25, 2)),
+            (
+                tifloat!(0x10000000000000 * 10 ^ 15),
+                ratio(1000000000000000, 1),
+            ),
+            (tifloat!(0x00000000000000 * 10 ^ 0), ratio(0, 1)),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(
+                BigRational::try_from(value).ok().unwrap(),
+                expected,
+                "{value:?}"
+            );
+        }
+
+        assert!(matches!(
+            BigRational::try_from(Float::undefined()),
+            Err(FloatError::NotANumber)
+        ));
+    }
+}
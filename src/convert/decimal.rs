@@ -0,0 +1,162 @@
+use rust_decimal::Decimal;
+
+use crate::{Float, FloatError};
+
+/// The largest scale (number of fractional digits) a [`Decimal`] can have.
+const MAX_SCALE: i64 = 28;
+
+impl TryFrom<Decimal> for Float {
+    type Error = FloatError;
+
+    /// Converts a `Decimal` to a Float without rounding.
+    ///
+    /// Every `Decimal` is within the range of a Float, so this only fails with
+    /// [`FloatError::Inexact`] if the value has more than 14 significant digits.
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        let digits = value
+            .mantissa()
+            .unsigned_abs()
+            .to_string()
+            .bytes()
+            .map(|byte| byte - b'0')
+            .collect::<Vec<_>>();
+
+        let leading_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+        let trailing_zeros = digits.iter().rev().take_while(|&&digit| digit == 0).count();
+
+        if digits.len().saturating_sub(leading_zeros + trailing_zeros) > 14 {
+            return Err(FloatError::Inexact);
+        }
+
+        Float::from_decimal_digits(
+            value.is_sign_negative(),
+            &digits,
+            digits.len() as i64 - 1 - value.scale() as i64,
+        )
+    }
+}
+
+impl TryFrom<Float> for Decimal {
+    type Error = FloatError;
+
+    /// Converts a Float to a `Decimal` without rounding.
+    ///
+    /// Fails with [`FloatError::Overflow`] if the magnitude is too large for a `Decimal`, with
+    /// [`FloatError::Inexact`] if the value needs more than 28 fractional digits, and with
+    /// [`FloatError::NotANumber`] if it is undefined.
+    fn try_from(value: Float) -> Result<Self, Self::Error> {
+        if value.is_undefined() {
+            return Err(FloatError::NotANumber);
+        }
+
+        let significant_figures = value.significant_figures();
+
+        let mantissa = significant_figures
+            .iter()
+            .fold(0i128, |acc, &digit| acc * 10 + digit as i128);
+
+        if mantissa == 0 {
+            return Ok(Decimal::ZERO);
+        }
+
+        // place-value of the last significant digit
+        let exponent = value.exponent() as i64 - (significant_figures.len() as i64 - 1);
+
+        let decimal = if exponent >= 0 {
+            10i128
+                .checked_pow(exponent as u32)
+                .and_then(|scale| mantissa.checked_mul(scale))
+                .and_then(|mantissa| Decimal::try_from_i128_with_scale(mantissa, 0).ok())
+                .ok_or(FloatError::Overflow)?
+        } else if -exponent > MAX_SCALE {
+            return Err(FloatError::Inexact);
+        } else {
+            Decimal::from_i128_with_scale(mantissa, -exponent as u32)
+        };
+
+        Ok(if value.is_negative() {
+            -decimal
+        } else {
+            decimal
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn from_decimal() {
+        let cases = [
+            (Decimal::new(1999, 2), tifloat!(0x19990000000000 * 10 ^ 1)),
+            (Decimal::new(-5, 3), tifloat!(-0x50000000000000 * 10 ^ -3)),
+            (Decimal::new(12000, 0), tifloat!(0x12000000000000 * 10 ^ 4)),
+            (Decimal::new(1500, 3), tifloat!(0x15000000000000 * 10 ^ 0)),
+            (
+                Decimal::new(12345678901234, 28),
+                tifloat!(0x12345678901234 * 10 ^ -15),
+            ),
+            (Decimal::ZERO, tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(Float::try_from(value).ok().unwrap(), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn from_decimal_inexact() {
+        assert!(matches!(
+            Float::try_from(Decimal::MAX),
+            Err(FloatError::Inexact)
+        ));
+        assert!(matches!(
+            Float::try_from(Decimal::new(123456789012345, 2)),
+            Err(FloatError::Inexact)
+        ));
+    }
+
+    #[test]
+    fn to_decimal() {
+        let cases = [
+            (tifloat!(0x19990000000000 * 10 ^ 1), Decimal::new(1999, 2)),
+            (tifloat!(-0x50000000000000 * 10 ^ -3), Decimal::new(-5, 3)),
+            (tifloat!(0x12000000000000 * 10 ^ 4), Decimal::new(12000, 0)),
+            (
+                tifloat!(0x31415926535898 * 10 ^ 0),
+                Decimal::new(31415926535898, 13),
+            ),
+            (
+                tifloat!(0x12345678901234 * 10 ^ -15),
+                Decimal::new(12345678901234, 28),
+            ),
+            (tifloat!(0x00000000000000 * 10 ^ 0), Decimal::ZERO),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(
+                Decimal::try_from(value).ok().unwrap(),
+                expected,
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn to_decimal_errors() {
+        assert!(matches!(
+            Decimal::try_from(tifloat!(0x10000000000000 * 10 ^ 29)),
+            Err(FloatError::Overflow)
+        ));
+        assert!(matches!(
+            Decimal::try_from(tifloat!(0x12000000000000 * 10 ^ -28)),
+            Err(FloatError::Inexact)
+        ));
+        assert!(matches!(
+            Decimal::try_from(Float::undefined()),
+            Err(FloatError::NotANumber)
+        ));
+    }
+}
@@ -0,0 +1,327 @@
+use crate::float::ParseFloatError;
+use crate::{Float, FloatError};
+
+impl Float {
+    /// Splits an angle in degrees into degrees, minutes, and seconds, like the calculator's ►DMS
+    /// command. The degrees and minutes are integers, and all three parts share the sign of the
+    /// angle.
+    ///
+    /// Only the seconds are rounded, so `to_dms` is exact wherever the seconds fit in 14 digits.
+    /// All three parts of an undefined angle are undefined.
+    pub fn to_dms(&self) -> (Float, Float, Float) {
+        if self.is_undefined() {
+            return (*self, *self, *self);
+        }
+
+        let angle = Digits {
+            digits: self.digits(),
+            exponent: self.exponent() as i64,
+        };
+
+        let (degrees, fraction) = angle.split();
+        let (minutes, fraction) = fraction.times_sixty().split();
+        let seconds = fraction.times_sixty();
+
+        (
+            degrees.to_float(self.is_negative()),
+            minutes.to_float(self.is_negative()),
+            seconds.to_float(self.is_negative()),
+        )
+    }
+
+    /// Combines degrees, minutes, and seconds into an angle in degrees, as the calculator does
+    /// when evaluating `D°M'S"`. The sum is rounded once, rather than after each division.
+    pub fn from_dms(degrees: Float, minutes: Float, seconds: Float) -> Result<Float, FloatError> {
+        let terms = [(degrees, 3600), (minutes, 60), (seconds, 1)]
+            .into_iter()
+            .filter(|(part, _)| !part.is_zero())
+            .map(|(part, seconds_per_unit)| {
+                let significant_figures = part.significant_figures();
+                let value = significant_figures
+                    .iter()
+                    .fold(0i128, |acc, &digit| acc * 10 + digit as i128)
+                    * seconds_per_unit;
+
+                // place-value of the last significant figure
+                let place = part.exponent() as i64 - (significant_figures.len() as i64 - 1);
+
+                (if part.is_negative() { -value } else { value }, place)
+            })
+            .collect::<Vec<_>>();
+
+        let Some(top) = terms
+            .iter()
+            .map(|(value, place)| value.unsigned_abs().ilog10() as i64 + place)
+            .max()
+        else {
+            return Ok(Float::ZERO);
+        };
+
+        // keep as many digits of the total as fit in an i128
+        let place = terms
+            .iter()
+            .map(|&(_, place)| place)
+            .min()
+            .unwrap()
+            .max(top - 35);
+
+        let total = terms
+            .iter()
+            .map(|&(value, term_place)| {
+                let shift = term_place - place;
+                if shift >= 0 {
+                    value * 10i128.pow(shift as u32)
+                } else {
+                    // terms far below the largest one don't affect the sum
+                    10i128
+                        .checked_pow(shift.unsigned_abs() as u32)
+                        .map_or(0, |scale| value / scale)
+                }
+            })
+            .sum::<i128>();
+
+        if total == 0 {
+            return Ok(Float::ZERO);
+        }
+
+        Float::from_scaled_ratio(total < 0, total.unsigned_abs(), 3600, place)
+    }
+
+    /// Produces the string the TI-OS would display for this angle after ►DMS, for example
+    /// `54°32'30"`. The seconds are rounded to the displayed digits as in Normal Float mode. An
+    /// undefined angle is written as [`UNDEFINED`](crate::UNDEFINED).
+    pub fn to_dms_string(&self) -> String {
+        if self.is_undefined() {
+            return crate::UNDEFINED.to_string();
+        }
+
+        let (degrees, minutes, seconds) = self.to_dms();
+
+        let mut output = String::new();
+        if self.is_negative() && !self.is_zero() {
+            output.push('-');
+        }
+
+        for (part, unit) in [(degrees, '°'), (minutes, '\''), (seconds, '"')] {
+            let magnitude = if part.is_negative() { -part } else { part };
+
+            output.push_str(&magnitude.to_normal_string());
+            output.push(unit);
+        }
+
+        output
+    }
+
+    /// Parses an angle written as `D°M'S"`, such as `54°32'30"` or `-12°30'`. Trailing parts may
+    /// be omitted, and each part may have a fractional component.
+    pub fn from_dms_str(s: &str) -> Result<Float, ParseFloatError> {
+        let (negative, mut s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        if s.is_empty() {
+            return Err(ParseFloatError::InvalidString);
+        }
+
+        let mut parts = [Float::ZERO; 3];
+        for (part, unit) in parts.iter_mut().zip(['°', '\'', '"']) {
+            if s.is_empty() {
+                break;
+            }
+
+            let (digits, rest) = s.split_once(unit).ok_or(ParseFloatError::InvalidString)?;
+
+            if digits.starts_with(['-', '+']) {
+                return Err(ParseFloatError::InvalidString);
+            }
+
+            *part = digits.parse()?;
+            s = rest;
+        }
+
+        if !s.is_empty() {
+            return Err(ParseFloatError::InvalidString);
+        }
+
+        let [degrees, minutes, seconds] = parts;
+        let angle = Float::from_dms(degrees, minutes, seconds)
+            .map_err(|_| ParseFloatError::InvalidExponent)?;
+
+        Ok(if negative { -angle } else { angle })
+    }
+}
+
+/// An exact decimal value, as digits MSD first where the first digit has place-value
+/// `10 ^ exponent`.
+struct Digits {
+    digits: Vec<u8>,
+    exponent: i64,
+}
+
+impl Digits {
+    /// Splits this value into its integer and fractional parts.
+    fn split(self) -> (Digits, Digits) {
+        let integer_digits = (self.exponent + 1).clamp(0, self.digits.len() as i64) as usize;
+        let mut digits = self.digits;
+        let fraction = digits.split_off(integer_digits);
+
+        (
+            Digits {
+                digits,
+                exponent: self.exponent,
+            },
+            Digits {
+                digits: fraction,
+                exponent: self.exponent.min(-1),
+            },
+        )
+    }
+
+    fn times_sixty(mut self) -> Digits {
+        let mut carry = 0;
+        for digit in self.digits.iter_mut().rev() {
+            let product = *digit * 6 + carry;
+            *digit = product % 10;
+            carry = product / 10;
+        }
+
+        if carry != 0 {
+            self.digits.insert(0, carry);
+            self.exponent += 1;
+        }
+
+        self.exponent += 1;
+        self
+    }
+
+    fn to_float(&self, negative: bool) -> Float {
+        let float = Float::from_decimal_digits(false, &self.digits, self.exponent)
+            .expect("parts of an angle are within range");
+
+        if negative && !float.is_zero() {
+            -float
+        } else {
+            float
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn to_dms() {
+        let cases = [
+            (
+                tifloat!(0x54541666666667 * 10 ^ 1),
+                (
+                    tifloat!(0x54000000000000 * 10 ^ 1),
+                    tifloat!(0x32000000000000 * 10 ^ 1),
+                    tifloat!(0x30000000001200 * 10 ^ 1),
+                ),
+            ),
+            (
+                tifloat!(-0x12500000000000 * 10 ^ 1),
+                (
+                    tifloat!(-0x12000000000000 * 10 ^ 1),
+                    tifloat!(-0x30000000000000 * 10 ^ 1),
+                    tifloat!(0x00000000000000 * 10 ^ 0),
+                ),
+            ),
+            (
+                tifloat!(0x25000000000000 * 10 ^ -1),
+                (
+                    tifloat!(0x00000000000000 * 10 ^ 0),
+                    tifloat!(0x15000000000000 * 10 ^ 1),
+                    tifloat!(0x00000000000000 * 10 ^ 0),
+                ),
+            ),
+        ];
+
+        for (angle, expected) in cases {
+            assert_eq!(angle.to_dms(), expected, "{angle:?}");
+        }
+
+        let (degrees, minutes, seconds) = Float::undefined().to_dms();
+        assert!(degrees.is_undefined() && minutes.is_undefined() && seconds.is_undefined());
+    }
+
+    #[test]
+    fn from_dms() {
+        let cases = [
+            (
+                (Float::from(54), Float::from(32), Float::from(30)),
+                tifloat!(0x54541666666667 * 10 ^ 1),
+            ),
+            (
+                (-Float::from(12), -Float::from(30), Float::from(0)),
+                tifloat!(-0x12500000000000 * 10 ^ 1),
+            ),
+            (
+                (Float::from(1), -Float::from(60), Float::from(0)),
+                tifloat!(0x00000000000000 * 10 ^ 0),
+            ),
+            (
+                (
+                    tifloat!(0x10000000000000 * 10 ^ 20),
+                    Float::from(0),
+                    Float::from(36),
+                ),
+                tifloat!(0x10000000000000 * 10 ^ 20),
+            ),
+        ];
+
+        for ((degrees, minutes, seconds), expected) in cases {
+            assert_eq!(
+                Float::from_dms(degrees, minutes, seconds).ok().unwrap(),
+                expected,
+                "{degrees:?} {minutes:?} {seconds:?}"
+            );
+        }
+
+        assert!(matches!(
+            Float::from_dms(
+                tifloat!(0x99999999999999 * 10 ^ 99),
+                tifloat!(0x60000000000000 * 10 ^ 87),
+                Float::from(0)
+            ),
+            Err(FloatError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn dms_string() {
+        let cases = [
+            (tifloat!(0x54541666666667 * 10 ^ 1), "54°32'30\""),
+            (tifloat!(-0x12500000000000 * 10 ^ 1), "-12°30'0\""),
+            (tifloat!(0x00000000000000 * 10 ^ 0), "0°0'0\""),
+        ];
+
+        for (angle, expected) in cases {
+            assert_eq!(angle.to_dms_string(), expected, "{angle:?}");
+        }
+
+        assert_eq!(Float::undefined().to_dms_string(), crate::UNDEFINED);
+    }
+
+    #[test]
+    fn parse_dms() {
+        let cases = [
+            ("54°32'30\"", tifloat!(0x54541666666667 * 10 ^ 1)),
+            ("-12°30'", tifloat!(-0x12500000000000 * 10 ^ 1)),
+            ("90°", tifloat!(0x90000000000000 * 10 ^ 1)),
+            ("0°1.5'", tifloat!(0x25000000000000 * 10 ^ -2)),
+        ];
+
+        for (string, expected) in cases {
+            assert_eq!(Float::from_dms_str(string), Ok(expected), "{string}");
+        }
+
+        for string in ["", "54", "54°-3'", "54°32'30\"1", "°"] {
+            assert!(Float::from_dms_str(string).is_err(), "{string}");
+        }
+    }
+}
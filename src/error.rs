@@ -1,6 +1,462 @@
-#[derive(Debug, Copy, Clone)]
+use std::fmt::{Display, Formatter};
+
+use crate::{Float, ParseFloatError};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FloatError {
     Overflow,
     DivideByZero,
     InvalidMantissa,
+    /// The value being converted is not a number.
+    NotANumber,
+    /// The value cannot be represented without rounding.
+    Inexact,
+    /// The operation requires an integer, but the value has a fractional part.
+    NotAnInteger,
+    /// The result is nonzero, but smaller in magnitude than `1E-99`.
+    Underflow,
+    /// The argument is outside the domain of the function.
+    Domain,
+    /// The result is not a real number.
+    NonReal,
+}
+
+/// The errors the TI-OS shows on its ERR screen, numbered as the OS numbers them internally.
+///
+/// The OS also sets bit 7 of the number for errors that offer a `Goto` option; [`OsError::code`]
+/// leaves that bit clear. Numbers past the ones named here, such as the link and archive errors,
+/// are kept as [`OsError::Other`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OsError {
+    Overflow,
+    DivideBy0,
+    SingularMat,
+    Domain,
+    Increment,
+    Break,
+    NonrealAns,
+    Syntax,
+    DataType,
+    Argument,
+    DimMismatch,
+    InvalidDim,
+    Undefined,
+    Memory,
+    Invalid,
+    IllegalNest,
+    Bound,
+    WindowRange,
+    Zoom,
+    Label,
+    Stat,
+    Solver,
+    Singularity,
+    SignChange,
+    Iterations,
+    BadGuess,
+    StatPlot,
+    TolNotMet,
+    Reserved,
+    Mode,
+    /// An error this crate has no name for, by its number.
+    Other(u8),
+}
+
+impl OsError {
+    /// The error's number.
+    pub fn code(self) -> u8 {
+        match self {
+            OsError::Overflow => 1,
+            OsError::DivideBy0 => 2,
+            OsError::SingularMat => 3,
+            OsError::Domain => 4,
+            OsError::Increment => 5,
+            OsError::Break => 6,
+            OsError::NonrealAns => 7,
+            OsError::Syntax => 8,
+            OsError::DataType => 9,
+            OsError::Argument => 10,
+            OsError::DimMismatch => 11,
+            OsError::InvalidDim => 12,
+            OsError::Undefined => 13,
+            OsError::Memory => 14,
+            OsError::Invalid => 15,
+            OsError::IllegalNest => 16,
+            OsError::Bound => 17,
+            OsError::WindowRange => 18,
+            OsError::Zoom => 19,
+            OsError::Label => 20,
+            OsError::Stat => 21,
+            OsError::Solver => 22,
+            OsError::Singularity => 23,
+            OsError::SignChange => 24,
+            OsError::Iterations => 25,
+            OsError::BadGuess => 26,
+            OsError::StatPlot => 27,
+            OsError::TolNotMet => 28,
+            OsError::Reserved => 29,
+            OsError::Mode => 30,
+            OsError::Other(code) => code,
+        }
+    }
+
+    /// The title of the ERR screen, such as `DIVIDE BY 0`, or `ERROR` for an [`OsError::Other`].
+    pub fn name(self) -> &'static str {
+        match self {
+            OsError::Overflow => "OVERFLOW",
+            OsError::DivideBy0 => "DIVIDE BY 0",
+            OsError::SingularMat => "SINGULAR MAT",
+            OsError::Domain => "DOMAIN",
+            OsError::Increment => "INCREMENT",
+            OsError::Break => "BREAK",
+            OsError::NonrealAns => "NONREAL ANS",
+            OsError::Syntax => "SYNTAX",
+            OsError::DataType => "DATA TYPE",
+            OsError::Argument => "ARGUMENT",
+            OsError::DimMismatch => "DIM MISMATCH",
+            OsError::InvalidDim => "INVALID DIM",
+            OsError::Undefined => "UNDEFINED",
+            OsError::Memory => "MEMORY",
+            OsError::Invalid => "INVALID",
+            OsError::IllegalNest => "ILLEGAL NEST",
+            OsError::Bound => "BOUND",
+            OsError::WindowRange => "WINDOW RANGE",
+            OsError::Zoom => "ZOOM",
+            OsError::Label => "LABEL",
+            OsError::Stat => "STAT",
+            OsError::Solver => "SOLVER",
+            OsError::Singularity => "SINGULARITY",
+            OsError::SignChange => "SIGN CHANGE",
+            OsError::Iterations => "ITERATIONS",
+            OsError::BadGuess => "BAD GUESS",
+            OsError::StatPlot => "STAT PLOT",
+            OsError::TolNotMet => "TOL NOT MET",
+            OsError::Reserved => "RESERVED",
+            OsError::Mode => "MODE",
+            OsError::Other(_) => "ERROR",
+        }
+    }
+}
+
+impl TryFrom<u8> for OsError {
+    type Error = u8;
+
+    /// Accepts a number with or without the `Goto` bit, returning the number back if it is zero,
+    /// which is no error.
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        let error = match code & 0x7F {
+            0 => return Err(code),
+            1 => OsError::Overflow,
+            2 => OsError::DivideBy0,
+            3 => OsError::SingularMat,
+            4 => OsError::Domain,
+            5 => OsError::Increment,
+            6 => OsError::Break,
+            7 => OsError::NonrealAns,
+            8 => OsError::Syntax,
+            9 => OsError::DataType,
+            10 => OsError::Argument,
+            11 => OsError::DimMismatch,
+            12 => OsError::InvalidDim,
+            13 => OsError::Undefined,
+            14 => OsError::Memory,
+            15 => OsError::Invalid,
+            16 => OsError::IllegalNest,
+            17 => OsError::Bound,
+            18 => OsError::WindowRange,
+            19 => OsError::Zoom,
+            20 => OsError::Label,
+            21 => OsError::Stat,
+            22 => OsError::Solver,
+            23 => OsError::Singularity,
+            24 => OsError::SignChange,
+            25 => OsError::Iterations,
+            26 => OsError::BadGuess,
+            27 => OsError::StatPlot,
+            28 => OsError::TolNotMet,
+            29 => OsError::Reserved,
+            30 => OsError::Mode,
+            other => OsError::Other(other),
+        };
+
+        Ok(error)
+    }
+}
+
+impl Display for OsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ERR:{}", self.name())
+    }
+}
+
+impl FloatError {
+    /// The error the calculator would show, if it would show one. The OS quietly flushes
+    /// underflowing results to zero, and the remaining errors come from conversions that have no
+    /// calculator equivalent.
+    pub fn os_error(self) -> Option<OsError> {
+        match self {
+            FloatError::Overflow => Some(OsError::Overflow),
+            FloatError::DivideByZero => Some(OsError::DivideBy0),
+            FloatError::NotAnInteger | FloatError::Domain => Some(OsError::Domain),
+            FloatError::NonReal => Some(OsError::NonrealAns),
+            FloatError::NotANumber => Some(OsError::Undefined),
+            FloatError::InvalidMantissa | FloatError::Inexact | FloatError::Underflow => None,
+        }
+    }
+}
+
+/// What to do with a nonzero result smaller in magnitude than `1E-99`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum UnderflowMode {
+    /// Fail with [`FloatError::Underflow`].
+    #[default]
+    Error,
+    /// Replace the result with zero, as the TI-OS does in several contexts.
+    FlushToZero,
+}
+
+impl UnderflowMode {
+    /// Applies this policy to the result of an operation, such as `mode.apply(a * b)`.
+    pub fn apply(self, result: Result<Float, FloatError>) -> Result<Float, FloatError> {
+        match (self, result) {
+            (UnderflowMode::FlushToZero, Err(FloatError::Underflow)) => Ok(Float::ZERO),
+            (_, result) => result,
+        }
+    }
+}
+
+impl Display for FloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FloatError::Overflow => "result is too large for a float",
+            FloatError::DivideByZero => "division by zero",
+            FloatError::InvalidMantissa => "invalid mantissa",
+            FloatError::NotANumber => "value is not a number",
+            FloatError::Inexact => "value cannot be represented exactly",
+            FloatError::NotAnInteger => "value is not an integer",
+            FloatError::Underflow => "result is too small for a float",
+            FloatError::Domain => "argument is outside the domain of the function",
+            FloatError::NonReal => "result is not a real number",
+        })
+    }
+}
+
+impl std::error::Error for FloatError {}
+
+impl Display for ParseFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ParseFloatError::InvalidFlags => "invalid flags",
+            ParseFloatError::InvalidExponent => "exponent out of range",
+            ParseFloatError::InvalidMantissa => "invalid BCD mantissa",
+            ParseFloatError::InvalidString => "invalid number string",
+            ParseFloatError::InvalidLength => "too few bytes for a float",
+            ParseFloatError::Inexact => "value has more than 14 significant digits",
+        })
+    }
+}
+
+impl std::error::Error for ParseFloatError {}
+
+/// Either of the crate's errors, with whatever context is known about where it happened, for
+/// use with `?` in code that mixes parsing and arithmetic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// An arithmetic or conversion error, and the name of the operation that failed.
+    Float {
+        error: FloatError,
+        operation: Option<&'static str>,
+    },
+    /// A parse error, and the offset of the byte or character that was invalid.
+    Parse {
+        error: ParseFloatError,
+        offset: Option<usize>,
+    },
+}
+
+impl FloatError {
+    /// Attaches the name of the operation that failed, such as `"multiply"`.
+    pub fn during(self, operation: &'static str) -> Error {
+        Error::Float {
+            error: self,
+            operation: Some(operation),
+        }
+    }
+}
+
+impl ParseFloatError {
+    /// Attaches the offset of the byte or character that was invalid.
+    pub fn at(self, offset: usize) -> Error {
+        Error::Parse {
+            error: self,
+            offset: Some(offset),
+        }
+    }
+}
+
+impl From<FloatError> for Error {
+    fn from(error: FloatError) -> Self {
+        Error::Float {
+            error,
+            operation: None,
+        }
+    }
+}
+
+impl From<ParseFloatError> for Error {
+    fn from(error: ParseFloatError) -> Self {
+        Error::Parse {
+            error,
+            offset: None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Float { error, operation } => {
+                write!(f, "{error}")?;
+                if let Some(operation) = operation {
+                    write!(f, " during {operation}")?;
+                }
+            }
+            Error::Parse { error, offset } => {
+                write!(f, "{error}")?;
+                if let Some(offset) = offset {
+                    write!(f, " at offset {offset}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Error {
+    /// The error the calculator would show, if it would show one. Strings that don't parse are
+    /// syntax errors.
+    pub fn os_error(&self) -> Option<OsError> {
+        match self {
+            Error::Float { error, .. } => error.os_error(),
+            Error::Parse {
+                error: ParseFloatError::InvalidString,
+                ..
+            } => Some(OsError::Syntax),
+            Error::Parse { .. } => None,
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Float { error, .. } => Some(error),
+            Error::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn underflow_mode() {
+        let a = tifloat!(0x15000000000000 * 10 ^ -99);
+        let b = tifloat!(0x14000000000000 * 10 ^ -99);
+
+        assert!(matches!(
+            UnderflowMode::Error.apply(a - b),
+            Err(FloatError::Underflow)
+        ));
+        assert_eq!(
+            UnderflowMode::FlushToZero.apply(a - b).unwrap(),
+            Float::ZERO
+        );
+        assert_eq!(
+            UnderflowMode::FlushToZero.apply(a + b).unwrap(),
+            (a + b).unwrap()
+        );
+        assert!(matches!(
+            UnderflowMode::FlushToZero.apply(Float::MAX + Float::MAX),
+            Err(FloatError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn os_error() {
+        assert_eq!(
+            FloatError::DivideByZero.os_error(),
+            Some(OsError::DivideBy0)
+        );
+        assert_eq!(FloatError::Underflow.os_error(), None);
+        assert_eq!(OsError::DivideBy0.code(), 2);
+        assert_eq!(OsError::try_from(0x84), Ok(OsError::Domain));
+        assert_eq!(OsError::try_from(0x1F), Ok(OsError::Other(0x1F)));
+        assert_eq!(OsError::try_from(0xB3), Ok(OsError::Other(0x33)));
+        assert_eq!(OsError::try_from(0x80), Err(0x80));
+        assert_eq!(OsError::Other(0x33).code(), 0x33);
+        assert_eq!(OsError::Other(0x33).to_string(), "ERR:ERROR");
+        assert_eq!(OsError::NonrealAns.to_string(), "ERR:NONREAL ANS");
+        assert_eq!(
+            Error::from(ParseFloatError::InvalidString).os_error(),
+            Some(OsError::Syntax)
+        );
+
+        for code in 1..0x80 {
+            assert_eq!(OsError::try_from(code).unwrap().code(), code);
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(FloatError::DivideByZero.to_string(), "division by zero");
+        assert_eq!(
+            FloatError::Overflow.during("multiply").to_string(),
+            "result is too large for a float during multiply"
+        );
+        assert_eq!(
+            ParseFloatError::InvalidMantissa.at(4).to_string(),
+            "invalid BCD mantissa at offset 4"
+        );
+        assert_eq!(
+            Error::from(ParseFloatError::InvalidString).to_string(),
+            "invalid number string"
+        );
+    }
+
+    #[test]
+    fn question_mark() {
+        fn parse_and_double(s: &str) -> Result<Float, Box<dyn std::error::Error>> {
+            let float: Float = s.parse()?;
+
+            Ok((float + float)?)
+        }
+
+        fn unified(s: &str) -> Result<Float, Error> {
+            let float: Float = s.parse()?;
+
+            (float * float).map_err(|err| err.during("square"))
+        }
+
+        assert_eq!(
+            parse_and_double("1.5").unwrap(),
+            "3".parse::<Float>().unwrap()
+        );
+        assert!(parse_and_double("1.5.").is_err());
+        assert_eq!(
+            unified("1E60"),
+            Err(Error::Float {
+                error: FloatError::Overflow,
+                operation: Some("square")
+            })
+        );
+
+        let err = unified("x").unwrap_err();
+        assert!(std::error::Error::source(&err).is_some());
+    }
 }
@@ -0,0 +1,61 @@
+//! The exact-math variable types of the TI-83 Premium CE and TI-84 Plus CE-T Python Edition,
+//! which keep results such as `3/4` in symbolic form rather than as decimals.
+//!
+//! Each of these types is stored in 9-byte records whose first byte carries the variable's type
+//! ID in its low six bits. Fractions and multiples of π store their decimal value as an ordinary
+//! real after that byte, while radicals pack their terms into the remaining bytes as BCD.
+
+use crate::{Flags, Float, ParseFloatError};
+
+mod fraction;
+mod pi;
+mod radical;
+
+pub use fraction::ExactFraction;
+pub use pi::ExactPi;
+pub use radical::ExactRadical;
+
+/// The type ID of an exact fraction.
+pub const FRACTION_TYPE: u8 = 0x1B;
+
+/// The type ID of an exact radical.
+pub const RADICAL_TYPE: u8 = 0x1C;
+
+/// The type ID of an integer multiple of π.
+pub const PI_TYPE: u8 = 0x20;
+
+/// The type ID of a fractional multiple of π.
+pub const PI_FRACTION_TYPE: u8 = 0x21;
+
+/// The largest numerator or denominator of an exact value: terms are kept to the 14 digits a
+/// Float can hold exactly.
+pub const MAX_TERM: u64 = 99_999_999_999_999;
+
+/// The bits of the flags byte that hold the type ID.
+const TYPE_MASK: u8 = 0x3F;
+
+/// Writes a Float with `type_id` in the low bits of its flags byte.
+fn encode_tagged(value: Float, type_id: u8) -> [u8; 9] {
+    let mut bytes = value.to_raw_bytes();
+    bytes[0] = (bytes[0] & Flags::NEGATIVE.bits()) | type_id;
+
+    bytes
+}
+
+/// Reads a Float written by [`encode_tagged`], checking that it has type `type_id`.
+fn decode_tagged(mut bytes: [u8; 9], type_id: u8) -> Result<Float, ParseFloatError> {
+    if bytes[0] & TYPE_MASK != type_id {
+        return Err(ParseFloatError::InvalidFlags);
+    }
+
+    bytes[0] &= !TYPE_MASK;
+    Float::from_raw_bytes(bytes)
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{decode_tagged, encode_tagged, gcd, FRACTION_TYPE, MAX_TERM};
+use crate::{Float, FloatError, ParseFloatError};
+
+/// A fraction in lowest terms, as the exact-math engine keeps results such as `1/3 + 1/4`.
+///
+/// The denominator is positive, and both terms are at most [`MAX_TERM`]. Arithmetic is exact;
+/// a result whose terms would need more than 14 digits fails with [`FloatError::Inexact`], which
+/// is where the calculator falls back to a decimal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExactFraction {
+    numerator: i64,
+    denominator: u64,
+}
+
+impl ExactFraction {
+    pub const ZERO: ExactFraction = ExactFraction {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    pub const ONE: ExactFraction = ExactFraction {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Produces `numerator / denominator` in lowest terms.
+    ///
+    /// Fails with [`FloatError::DivideByZero`] if the denominator is zero, or with
+    /// [`FloatError::Inexact`] if a reduced term is larger than [`MAX_TERM`].
+    pub fn new(numerator: i64, denominator: u64) -> Result<Self, FloatError> {
+        ExactFraction::reduce(
+            numerator < 0,
+            numerator.unsigned_abs() as u128,
+            denominator as u128,
+        )
+    }
+
+    /// Produces `numerator / denominator` in lowest terms from integer Floats. A negative
+    /// denominator moves its sign to the numerator.
+    ///
+    /// Fails as [`ExactFraction::new`] does, or with [`FloatError::NotAnInteger`] if either term
+    /// has a fractional part.
+    pub fn from_floats(numerator: Float, denominator: Float) -> Result<Self, FloatError> {
+        if !numerator.is_integer() || !denominator.is_integer() {
+            return Err(FloatError::NotAnInteger);
+        }
+
+        let term = |float: Float| float.as_integer().ok_or(FloatError::Inexact);
+        let (numerator, denominator) = (term(numerator)?, term(denominator)?);
+
+        ExactFraction::reduce(
+            (numerator < 0) != (denominator < 0),
+            numerator.unsigned_abs() as u128,
+            denominator.unsigned_abs() as u128,
+        )
+    }
+
+    /// The simplest fraction equal to a Float, as [`Float::to_fraction`] finds it, or `None` if
+    /// there is none with terms of at most [`MAX_TERM`].
+    pub fn from_float(value: Float) -> Option<Self> {
+        let (numerator, denominator) = value.to_fraction(MAX_TERM)?;
+
+        ExactFraction::new(numerator, denominator).ok()
+    }
+
+    fn reduce(negative: bool, numerator: u128, denominator: u128) -> Result<Self, FloatError> {
+        if denominator == 0 {
+            return Err(FloatError::DivideByZero);
+        } else if numerator == 0 {
+            return Ok(ExactFraction::ZERO);
+        }
+
+        let divisor = gcd(numerator, denominator);
+        let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+
+        if numerator > MAX_TERM as u128 || denominator > MAX_TERM as u128 {
+            return Err(FloatError::Inexact);
+        }
+
+        Ok(ExactFraction {
+            numerator: if negative {
+                -(numerator as i64)
+            } else {
+                numerator as i64
+            },
+            denominator: denominator as u64,
+        })
+    }
+
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> u64 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// `1 / self`. Fails with [`FloatError::DivideByZero`] if this is zero.
+    pub fn recip(&self) -> Result<Self, FloatError> {
+        ExactFraction::ONE / *self
+    }
+
+    /// The Float nearest to this fraction, rounded half-up to 14 significant digits.
+    pub fn to_float(&self) -> Float {
+        Float::from_ratio(self.numerator, self.denominator).unwrap()
+    }
+
+    /// The on-calc representation: the fraction's decimal value as a real tagged with
+    /// [`FRACTION_TYPE`]. A complex exact fraction stores its real and imaginary parts as two of
+    /// these in a row.
+    pub fn to_raw_bytes(&self) -> [u8; 9] {
+        encode_tagged(self.to_float(), FRACTION_TYPE)
+    }
+
+    /// Reads the on-calc representation, recovering the fraction from its decimal value as the
+    /// calculator does when it displays one.
+    ///
+    /// Fails with [`ParseFloatError::InvalidFlags`] if the type ID isn't [`FRACTION_TYPE`], or
+    /// with [`ParseFloatError::InvalidMantissa`] if no fraction matches the value.
+    pub fn from_raw_bytes(bytes: [u8; 9]) -> Result<Self, ParseFloatError> {
+        let value = decode_tagged(bytes, FRACTION_TYPE)?;
+
+        ExactFraction::from_float(value).ok_or(ParseFloatError::InvalidMantissa)
+    }
+}
+
+impl Neg for ExactFraction {
+    type Output = ExactFraction;
+
+    fn neg(self) -> Self::Output {
+        ExactFraction {
+            numerator: -self.numerator,
+            ..self
+        }
+    }
+}
+
+impl Add for ExactFraction {
+    type Output = Result<ExactFraction, FloatError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let numerator = self.numerator as i128 * rhs.denominator as i128
+            + rhs.numerator as i128 * self.denominator as i128;
+
+        ExactFraction::reduce(
+            numerator < 0,
+            numerator.unsigned_abs(),
+            self.denominator as u128 * rhs.denominator as u128,
+        )
+    }
+}
+
+impl Sub for ExactFraction {
+    type Output = Result<ExactFraction, FloatError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for ExactFraction {
+    type Output = Result<ExactFraction, FloatError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        ExactFraction::reduce(
+            (self.numerator < 0) != (rhs.numerator < 0),
+            self.numerator.unsigned_abs() as u128 * rhs.numerator.unsigned_abs() as u128,
+            self.denominator as u128 * rhs.denominator as u128,
+        )
+    }
+}
+
+impl Div for ExactFraction {
+    type Output = Result<ExactFraction, FloatError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        ExactFraction::reduce(
+            (self.numerator < 0) != (rhs.numerator < 0),
+            self.numerator.unsigned_abs() as u128 * rhs.denominator as u128,
+            self.denominator as u128 * rhs.numerator.unsigned_abs() as u128,
+        )
+    }
+}
+
+impl PartialOrd for ExactFraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ExactFraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator as i128 * other.denominator as i128)
+            .cmp(&(other.numerator as i128 * self.denominator as i128))
+    }
+}
+
+impl From<ExactFraction> for Float {
+    fn from(value: ExactFraction) -> Self {
+        value.to_float()
+    }
+}
+
+impl Display for ExactFraction {
+    /// Writes the fraction as `n/d`, or as an integer if the denominator is one.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn fraction(numerator: i64, denominator: u64) -> ExactFraction {
+        ExactFraction::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(fraction(6, 8), fraction(3, 4));
+        assert_eq!(fraction(-6, 8).numerator(), -3);
+        assert_eq!(fraction(0, 7), ExactFraction::ZERO);
+        assert_eq!(
+            ExactFraction::from_floats(
+                tifloat!(0x30000000000000 * 10 ^ 0),
+                tifloat!(-0x60000000000000 * 10 ^ 0)
+            ),
+            Ok(fraction(-1, 2))
+        );
+
+        assert_eq!(ExactFraction::new(1, 0), Err(FloatError::DivideByZero));
+        assert_eq!(
+            ExactFraction::new(1, MAX_TERM + 1),
+            Err(FloatError::Inexact)
+        );
+        assert_eq!(
+            ExactFraction::from_floats(Float::PI, Float::ONE),
+            Err(FloatError::NotAnInteger)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let third = fraction(1, 3);
+        let quarter = fraction(1, 4);
+
+        assert_eq!(third + quarter, Ok(fraction(7, 12)));
+        assert_eq!(third - quarter, Ok(fraction(1, 12)));
+        assert_eq!(third * -quarter, Ok(fraction(-1, 12)));
+        assert_eq!(third / quarter, Ok(fraction(4, 3)));
+        assert_eq!(((third + third).unwrap() + third), Ok(ExactFraction::ONE));
+        assert_eq!(ExactFraction::ZERO.recip(), Err(FloatError::DivideByZero));
+
+        let tiny = fraction(1, MAX_TERM);
+        assert_eq!(tiny * tiny, Err(FloatError::Inexact));
+
+        assert!(quarter < third && -third < quarter);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            fraction(-1, 3).to_float(),
+            tifloat!(-0x33333333333333 * 10 ^ -1)
+        );
+        assert_eq!(
+            ExactFraction::from_float(tifloat!(0x66666666666667 * 10 ^ -1)),
+            Some(fraction(2, 3))
+        );
+        assert_eq!(
+            ExactFraction::from_float(tifloat!(0x25000000000000 * 10 ^ 0)),
+            Some(fraction(5, 2))
+        );
+
+        assert_eq!(fraction(-7, 12).to_string(), "-7/12");
+        assert_eq!(fraction(4, 2).to_string(), "2");
+    }
+
+    #[test]
+    fn raw_bytes() {
+        let bytes = fraction(-3, 4).to_raw_bytes();
+
+        assert_eq!(bytes, [0x9B, 0x7F, 0x75, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(ExactFraction::from_raw_bytes(bytes), Ok(fraction(-3, 4)));
+        assert_eq!(
+            ExactFraction::from_raw_bytes(Float::ONE.to_raw_bytes()),
+            Err(ParseFloatError::InvalidFlags)
+        );
+    }
+}
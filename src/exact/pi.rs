@@ -0,0 +1,250 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{decode_tagged, encode_tagged, ExactFraction, PI_FRACTION_TYPE, PI_TYPE, TYPE_MASK};
+use crate::{Float, FloatError, ParseFloatError};
+
+/// π to 22 decimal places, scaled to an integer.
+const PI_SCALED: u128 = 31_415_926_535_897_932_384_626;
+
+/// The number of decimal places in [`PI_SCALED`].
+const PI_SCALE: i64 = 22;
+
+/// A rational multiple of π, such as `3π/4`, as the exact-math engine keeps the results of
+/// trigonometry in radians.
+///
+/// Arithmetic is exact where the result is still a multiple of π: sums and differences, and
+/// products and quotients with an [`ExactFraction`]. Dividing two multiples of π gives an
+/// [`ExactFraction`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExactPi {
+    coefficient: ExactFraction,
+}
+
+impl ExactPi {
+    pub const ZERO: ExactPi = ExactPi {
+        coefficient: ExactFraction::ZERO,
+    };
+
+    pub const PI: ExactPi = ExactPi {
+        coefficient: ExactFraction::ONE,
+    };
+
+    /// Produces `numerator * π / denominator`, failing as [`ExactFraction::new`] does.
+    pub fn new(numerator: i64, denominator: u64) -> Result<Self, FloatError> {
+        Ok(ExactPi::from_coefficient(ExactFraction::new(
+            numerator,
+            denominator,
+        )?))
+    }
+
+    /// Produces `coefficient * π`.
+    pub fn from_coefficient(coefficient: ExactFraction) -> Self {
+        ExactPi { coefficient }
+    }
+
+    /// The multiple of π this value is.
+    pub fn coefficient(&self) -> ExactFraction {
+        self.coefficient
+    }
+
+    /// The type ID this value is saved with: [`PI_TYPE`] for an integer multiple of π, and
+    /// [`PI_FRACTION_TYPE`] otherwise.
+    pub fn type_id(&self) -> u8 {
+        if self.coefficient.is_integer() {
+            PI_TYPE
+        } else {
+            PI_FRACTION_TYPE
+        }
+    }
+
+    /// The Float nearest to this value, rounded half-up to 14 significant digits from π to 22
+    /// places. Fails with [`FloatError::Overflow`] if the value is too large for a Float.
+    pub fn to_float(&self) -> Result<Float, FloatError> {
+        let numerator = self.coefficient.numerator();
+        if numerator == 0 {
+            return Ok(Float::ZERO);
+        }
+
+        Float::from_scaled_ratio(
+            numerator < 0,
+            numerator.unsigned_abs() as u128 * PI_SCALED,
+            self.coefficient.denominator() as u128,
+            -PI_SCALE,
+        )
+    }
+
+    /// The on-calc representation: the decimal value as a real tagged with [`ExactPi::type_id`].
+    /// A complex value stores its real and imaginary parts as two of these in a row.
+    ///
+    /// Fails with [`FloatError::Overflow`] if the value is too large for a Float.
+    pub fn to_raw_bytes(&self) -> Result<[u8; 9], FloatError> {
+        Ok(encode_tagged(self.to_float()?, self.type_id()))
+    }
+
+    /// Reads the on-calc representation, recovering the multiple of π from its decimal value.
+    ///
+    /// Fails with [`ParseFloatError::InvalidFlags`] if the type ID is neither [`PI_TYPE`] nor
+    /// [`PI_FRACTION_TYPE`], or with [`ParseFloatError::InvalidMantissa`] if the value isn't
+    /// close to a simple multiple of π.
+    pub fn from_raw_bytes(bytes: [u8; 9]) -> Result<Self, ParseFloatError> {
+        let type_id = bytes[0] & TYPE_MASK;
+        if type_id != PI_TYPE && type_id != PI_FRACTION_TYPE {
+            return Err(ParseFloatError::InvalidFlags);
+        }
+
+        let value = decode_tagged(bytes, type_id)?;
+        let coefficient = (value / Float::PI)
+            .ok()
+            .and_then(ExactFraction::from_float)
+            .ok_or(ParseFloatError::InvalidMantissa)?;
+
+        Ok(ExactPi::from_coefficient(coefficient))
+    }
+}
+
+impl Neg for ExactPi {
+    type Output = ExactPi;
+
+    fn neg(self) -> Self::Output {
+        ExactPi::from_coefficient(-self.coefficient)
+    }
+}
+
+impl Add for ExactPi {
+    type Output = Result<ExactPi, FloatError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Ok(ExactPi::from_coefficient(
+            (self.coefficient + rhs.coefficient)?,
+        ))
+    }
+}
+
+impl Sub for ExactPi {
+    type Output = Result<ExactPi, FloatError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Ok(ExactPi::from_coefficient(
+            (self.coefficient - rhs.coefficient)?,
+        ))
+    }
+}
+
+impl Mul<ExactFraction> for ExactPi {
+    type Output = Result<ExactPi, FloatError>;
+
+    fn mul(self, rhs: ExactFraction) -> Self::Output {
+        Ok(ExactPi::from_coefficient((self.coefficient * rhs)?))
+    }
+}
+
+impl Div<ExactFraction> for ExactPi {
+    type Output = Result<ExactPi, FloatError>;
+
+    fn div(self, rhs: ExactFraction) -> Self::Output {
+        Ok(ExactPi::from_coefficient((self.coefficient / rhs)?))
+    }
+}
+
+impl Div for ExactPi {
+    type Output = Result<ExactFraction, FloatError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.coefficient / rhs.coefficient
+    }
+}
+
+impl PartialOrd for ExactPi {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ExactPi {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.coefficient.cmp(&other.coefficient)
+    }
+}
+
+impl Display for ExactPi {
+    /// Writes the value as the calculator displays it, such as `3π/4`, `-π`, or `0`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let numerator = self.coefficient.numerator();
+        let denominator = self.coefficient.denominator();
+
+        match numerator {
+            0 => return f.write_str("0"),
+            1 => f.write_str("π")?,
+            -1 => f.write_str("-π")?,
+            numerator => write!(f, "{numerator}π")?,
+        }
+
+        if denominator != 1 {
+            write!(f, "/{denominator}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn pi(numerator: i64, denominator: u64) -> ExactPi {
+        ExactPi::new(numerator, denominator).unwrap()
+    }
+
+    #[test]
+    fn arithmetic() {
+        let half = ExactFraction::new(1, 2).unwrap();
+
+        assert_eq!(pi(1, 2) + pi(1, 4), Ok(pi(3, 4)));
+        assert_eq!(pi(1, 2) - ExactPi::PI, Ok(pi(-1, 2)));
+        assert_eq!(ExactPi::PI * half, Ok(pi(1, 2)));
+        assert_eq!(pi(3, 4) / half, Ok(pi(3, 2)));
+        assert_eq!(pi(3, 4) / pi(1, 4), Ok(ExactFraction::new(3, 1).unwrap()));
+        assert_eq!(pi(1, 3) / ExactPi::ZERO, Err(FloatError::DivideByZero));
+        assert!(-ExactPi::PI < ExactPi::ZERO);
+    }
+
+    #[test]
+    fn to_float() {
+        assert_eq!(ExactPi::PI.to_float(), Ok(Float::PI));
+        assert_eq!(pi(3, 4).to_float(), Ok(tifloat!(0x23561944901923 * 10 ^ 0)));
+        assert_eq!(
+            pi(-1, 7).to_float(),
+            Ok(tifloat!(-0x44879895051283 * 10 ^ -1))
+        );
+        assert_eq!(ExactPi::ZERO.to_float(), Ok(Float::ZERO));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(pi(3, 4).to_string(), "3π/4");
+        assert_eq!(pi(-1, 1).to_string(), "-π");
+        assert_eq!(pi(2, 1).to_string(), "2π");
+        assert_eq!(pi(1, 6).to_string(), "π/6");
+        assert_eq!(ExactPi::ZERO.to_string(), "0");
+    }
+
+    #[test]
+    fn raw_bytes() {
+        let bytes = pi(3, 4).to_raw_bytes().unwrap();
+
+        assert_eq!(bytes[0], PI_FRACTION_TYPE);
+        assert_eq!(ExactPi::from_raw_bytes(bytes), Ok(pi(3, 4)));
+
+        let bytes = pi(-2, 1).to_raw_bytes().unwrap();
+        assert_eq!(bytes[0], 0x80 | PI_TYPE);
+        assert_eq!(ExactPi::from_raw_bytes(bytes), Ok(pi(-2, 1)));
+
+        assert_eq!(
+            ExactPi::from_raw_bytes(ExactFraction::ONE.to_raw_bytes()),
+            Err(ParseFloatError::InvalidFlags)
+        );
+    }
+}
@@ -0,0 +1,356 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::Neg;
+
+use super::{gcd, RADICAL_TYPE, TYPE_MASK};
+use crate::{Float, FloatError, ParseFloatError};
+
+/// The largest term of an exact radical, which stores each term in three BCD digits.
+const MAX_RADICAL_TERM: u64 = 999;
+
+/// A sum of two square roots over a denominator, `(a√b + c√d) / e`, as the exact-math engine
+/// keeps results such as `√8 / 2`.
+///
+/// Radicals are always simplified: each radicand is square-free, like radicands are combined,
+/// the terms and denominator share no common factor, and the denominator is positive. An integer
+/// term has radicand 1 and comes first, and an unused term has coefficient and radicand zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExactRadical {
+    a: i16,
+    b: u16,
+    c: i16,
+    d: u16,
+    e: u16,
+}
+
+impl ExactRadical {
+    pub const ZERO: ExactRadical = ExactRadical {
+        a: 0,
+        b: 0,
+        c: 0,
+        d: 0,
+        e: 1,
+    };
+
+    /// Produces `(a√b + c√d) / e`, simplified.
+    ///
+    /// Fails with [`FloatError::DivideByZero`] if `e` is zero, or with [`FloatError::Inexact`] if
+    /// a simplified term is larger than 999, the most the on-calc representation can hold.
+    pub fn new(a: i16, b: u16, c: i16, d: u16, e: u16) -> Result<Self, FloatError> {
+        if e == 0 {
+            return Err(FloatError::DivideByZero);
+        }
+
+        let mut terms = [
+            simplify_root(a as i64, b as u64),
+            simplify_root(c as i64, d as u64),
+        ];
+
+        if terms[0].1 == terms[1].1 {
+            terms = [(terms[0].0 + terms[1].0, terms[0].1), (0, 0)];
+        }
+
+        for term in &mut terms {
+            if term.0 == 0 {
+                *term = (0, 0);
+            }
+        }
+
+        if terms[0].0 == 0 || (terms[1].0 != 0 && terms[1].1 < terms[0].1) {
+            terms.swap(0, 1);
+        }
+
+        if terms[0].0 == 0 {
+            return Ok(ExactRadical::ZERO);
+        }
+
+        let divisor = [terms[0].0, terms[1].0]
+            .into_iter()
+            .fold(e as u128, |divisor, coefficient| {
+                gcd(divisor, coefficient.unsigned_abs() as u128)
+            }) as i64;
+        let [(a, b), (c, d)] =
+            terms.map(|(coefficient, radicand)| (coefficient / divisor, radicand));
+        let e = e as i64 / divisor;
+
+        if [a.unsigned_abs(), b, c.unsigned_abs(), d, e as u64]
+            .iter()
+            .any(|&term| term > MAX_RADICAL_TERM)
+        {
+            return Err(FloatError::Inexact);
+        }
+
+        Ok(ExactRadical {
+            a: a as i16,
+            b: b as u16,
+            c: c as i16,
+            d: d as u16,
+            e: e as u16,
+        })
+    }
+
+    /// The coefficient and radicand of each term, `[(a, b), (c, d)]`.
+    pub fn terms(&self) -> [(i16, u16); 2] {
+        [(self.a, self.b), (self.c, self.d)]
+    }
+
+    pub fn denominator(&self) -> u16 {
+        self.e
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.a == 0
+    }
+
+    /// The Float nearest to this radical, rounded half-up to 14 significant digits.
+    pub fn to_float(&self) -> Float {
+        if self.is_zero() {
+            return Float::ZERO;
+        }
+
+        // a√b as the root of a²b, so both terms can be scaled to the same integer precision
+        let squares = [self.a, self.c].map(|coefficient| coefficient.unsigned_abs() as u128);
+        let squares = [
+            squares[0] * squares[0] * self.b as u128,
+            squares[1] * squares[1] * self.d as u128,
+        ];
+
+        // scale the larger square to about 37 digits, leaving each root with about 19
+        let scale = (37 - squares[0].max(squares[1]).ilog10()) / 2;
+        let root = |square: u128| (square * 10u128.pow(2 * scale)).isqrt();
+        let sum = root(squares[0]) + root(squares[1]);
+
+        let negative = self.a < 0;
+        let result = if (self.a < 0) == (self.c < 0) || self.c == 0 {
+            Float::from_scaled_ratio(negative, sum, self.e as u128, -(scale as i64))
+        } else {
+            // a difference of roots is computed as the difference of their squares over their sum,
+            // which doesn't cancel
+            let difference = squares[0].abs_diff(squares[1]);
+
+            Float::from_scaled_ratio(
+                negative == (squares[0] > squares[1]),
+                difference,
+                self.e as u128 * sum,
+                scale as i64,
+            )
+        };
+
+        // the terms are at most 999√999 / 1, and the difference of two distinct terms is at
+        // least 1 / (2 * 999√999), so the result is always in range
+        result.unwrap()
+    }
+
+    /// The on-calc representation. The first byte holds [`RADICAL_TYPE`], and the next 16
+    /// nibbles hold the signs of the terms, then `e`, `|a|`, `b`, `|c|`, and `d` as three BCD
+    /// digits each. The sign nibble has bit 0 set if `a` is negative and bit 1 set if `c` is.
+    pub fn to_raw_bytes(&self) -> [u8; 9] {
+        let signs = (self.a < 0) as u64 | ((self.c < 0) as u64) << 1;
+        let nibbles = [
+            self.e as u64,
+            self.a.unsigned_abs() as u64,
+            self.b as u64,
+            self.c.unsigned_abs() as u64,
+            self.d as u64,
+        ]
+        .into_iter()
+        .fold(signs, |nibbles, term| {
+            let bcd = (term / 100) << 8 | (term / 10 % 10) << 4 | (term % 10);
+            nibbles << 12 | bcd
+        });
+
+        let mut bytes = [0u8; 9];
+        bytes[0] = RADICAL_TYPE;
+        bytes[1..].copy_from_slice(&nibbles.to_be_bytes());
+
+        bytes
+    }
+
+    /// Reads the on-calc representation, simplifying it if it isn't already.
+    ///
+    /// Fails with [`ParseFloatError::InvalidFlags`] if the type ID isn't [`RADICAL_TYPE`] or the
+    /// sign nibble is out of range, or with [`ParseFloatError::InvalidMantissa`] if a term isn't
+    /// valid BCD or the denominator is zero.
+    pub fn from_raw_bytes(bytes: [u8; 9]) -> Result<Self, ParseFloatError> {
+        if bytes[0] & TYPE_MASK != RADICAL_TYPE || bytes[1] >> 4 > 3 {
+            return Err(ParseFloatError::InvalidFlags);
+        }
+
+        let nibbles = u64::from_be_bytes(bytes[1..].try_into().unwrap());
+        let signs = nibbles >> 60;
+
+        let mut terms = [0u16; 5];
+        for (index, term) in terms.iter_mut().enumerate() {
+            let bcd = nibbles >> (48 - 12 * index) & 0xFFF;
+            let digits = [bcd >> 8, bcd >> 4 & 0xF, bcd & 0xF];
+            if digits.iter().any(|&digit| digit > 9) {
+                return Err(ParseFloatError::InvalidMantissa);
+            }
+
+            *term = (digits[0] * 100 + digits[1] * 10 + digits[2]) as u16;
+        }
+
+        let [e, a, b, c, d] = terms;
+        let sign = |bit: u64| if signs >> bit & 1 != 0 { -1 } else { 1 };
+
+        ExactRadical::new(sign(0) * a as i16, b, sign(1) * c as i16, d, e)
+            .map_err(|_| ParseFloatError::InvalidMantissa)
+    }
+}
+
+/// Moves square factors out of a radicand, producing `(coefficient, radicand)` with a square-free
+/// radicand. A zero radicand zeroes the coefficient.
+fn simplify_root(mut coefficient: i64, mut radicand: u64) -> (i64, u64) {
+    if radicand == 0 {
+        return (0, 0);
+    }
+
+    let mut factor = 2;
+    while factor * factor <= radicand {
+        while radicand.is_multiple_of(factor * factor) {
+            radicand /= factor * factor;
+            coefficient *= factor as i64;
+        }
+
+        factor += 1;
+    }
+
+    (coefficient, radicand)
+}
+
+impl Neg for ExactRadical {
+    type Output = ExactRadical;
+
+    fn neg(self) -> Self::Output {
+        ExactRadical {
+            a: -self.a,
+            c: -self.c,
+            ..self
+        }
+    }
+}
+
+impl From<ExactRadical> for Float {
+    fn from(value: ExactRadical) -> Self {
+        value.to_float()
+    }
+}
+
+impl Display for ExactRadical {
+    /// Writes the radical as the calculator displays it, such as `(1+√5)/2`, `-√2/2`, or `3`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+
+        let term = |coefficient: i16, radicand: u16| match (coefficient, radicand) {
+            (coefficient, 1) => coefficient.to_string(),
+            (1, radicand) => format!("√{radicand}"),
+            (-1, radicand) => format!("-√{radicand}"),
+            (coefficient, radicand) => format!("{coefficient}√{radicand}"),
+        };
+
+        let mut numerator = term(self.a, self.b);
+        if self.c > 0 {
+            numerator = format!("{numerator}+{}", term(self.c, self.d));
+        } else if self.c < 0 {
+            numerator = format!("{numerator}{}", term(self.c, self.d));
+        }
+
+        match (self.e, self.c) {
+            (1, _) => f.write_str(&numerator),
+            (e, 0) => write!(f, "{numerator}/{e}"),
+            (e, _) => write!(f, "({numerator})/{e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn radical(a: i16, b: u16, c: i16, d: u16, e: u16) -> ExactRadical {
+        ExactRadical::new(a, b, c, d, e).unwrap()
+    }
+
+    #[test]
+    fn simplification() {
+        assert_eq!(radical(1, 12, 0, 0, 1).terms(), [(2, 3), (0, 0)]);
+        assert_eq!(radical(2, 8, 2, 2, 4), radical(3, 2, 0, 0, 2));
+        assert_eq!(radical(1, 2, 3, 1, 1).terms(), [(3, 1), (1, 2)]);
+        assert_eq!(radical(0, 5, -2, 3, 4).terms(), [(-1, 3), (0, 0)]);
+        assert_eq!(radical(1, 8, -2, 2, 7), ExactRadical::ZERO);
+        assert_eq!(radical(4, 9, 0, 0, 6).to_string(), "2");
+
+        assert_eq!(
+            ExactRadical::new(1, 2, 0, 0, 0),
+            Err(FloatError::DivideByZero)
+        );
+        assert_eq!(
+            ExactRadical::new(1000, 2, 0, 0, 1),
+            Err(FloatError::Inexact)
+        );
+    }
+
+    #[test]
+    fn to_float() {
+        assert_eq!(
+            radical(1, 2, 0, 0, 1).to_float(),
+            tifloat!(0x14142135623731 * 10 ^ 0)
+        );
+        assert_eq!(
+            radical(1, 1, 1, 5, 2).to_float(),
+            tifloat!(0x16180339887499 * 10 ^ 0)
+        );
+        assert_eq!(
+            radical(140, 1, -99, 2, 1).to_float(),
+            tifloat!(-0x71426749364098 * 10 ^ -3)
+        );
+        assert_eq!(
+            radical(-1, 2, 1, 3, 5).to_float(),
+            tifloat!(0x63567449039156 * 10 ^ -2)
+        );
+        assert_eq!(
+            (-radical(999, 998, 0, 0, 1)).to_float(),
+            tifloat!(-0x31559546859865 * 10 ^ 4)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(radical(1, 1, 1, 5, 2).to_string(), "(1+√5)/2");
+        assert_eq!(radical(-1, 2, 0, 0, 2).to_string(), "-√2/2");
+        assert_eq!(radical(2, 1, -3, 7, 1).to_string(), "2-3√7");
+        assert_eq!(ExactRadical::ZERO.to_string(), "0");
+    }
+
+    #[test]
+    fn raw_bytes() {
+        let value = radical(-12, 2, 5, 3, 7);
+        let bytes = value.to_raw_bytes();
+
+        assert_eq!(
+            bytes,
+            [0x1C, 0x10, 0x07, 0x01, 0x20, 0x02, 0x00, 0x50, 0x03]
+        );
+        assert_eq!(ExactRadical::from_raw_bytes(bytes), Ok(value));
+
+        // unsimplified input is simplified
+        let bytes = [0x1C, 0x00, 0x01, 0x00, 0x10, 0x08, 0x00, 0x00, 0x00];
+        assert_eq!(
+            ExactRadical::from_raw_bytes(bytes),
+            Ok(radical(2, 2, 0, 0, 1))
+        );
+
+        let mut invalid = bytes;
+        invalid[4] = 0x1A;
+        assert_eq!(
+            ExactRadical::from_raw_bytes(invalid),
+            Err(ParseFloatError::InvalidMantissa)
+        );
+        assert_eq!(
+            ExactRadical::from_raw_bytes(Float::ONE.to_raw_bytes()),
+            Err(ParseFloatError::InvalidFlags)
+        );
+    }
+}
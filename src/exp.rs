@@ -0,0 +1,148 @@
+use crate::fixed::Fixed;
+use crate::{Float, FloatError};
+
+impl Float {
+    /// The exponential `e^self`, computed to more than 30 digits and rounded half-up to 14, so
+    /// that [`Float::ln`] recovers the argument to within a unit in the last place.
+    ///
+    /// Fails with [`FloatError::Overflow`] if the result is at least `1E100`, and with
+    /// [`FloatError::Underflow`] if it is nonzero but smaller than `1E-99`. The exponential of
+    /// an undefined Float is undefined.
+    pub fn exp(&self) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        match Fixed::from_float(self) {
+            Some(exponent) => exponent.exp(),
+            None if self.is_negative() => Err(FloatError::Underflow),
+            None => Err(FloatError::Overflow),
+        }
+    }
+
+    /// The decimal antilog `10^self`. An integer power is exact, since it only moves the
+    /// exponent, and any other power is computed to more than 30 digits and rounded half-up to
+    /// 14.
+    ///
+    /// Fails with [`FloatError::Overflow`] if the result is at least `1E100`, and with
+    /// [`FloatError::Underflow`] if it is nonzero but smaller than `1E-99`. The antilog of an
+    /// undefined Float is undefined.
+    #[doc(alias = "10^(")]
+    pub fn exp10(&self) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_integer() {
+            // any power beyond a thousand is as far out of range as it needs to be
+            let power = match self.as_integer() {
+                Some(power) => power.clamp(-1000, 1000) as i32,
+                None if self.is_negative() => -1000,
+                None => 1000,
+            };
+
+            return Float::ONE.mul_pow10(power);
+        }
+
+        match Fixed::from_float(self) {
+            Some(power) => power.exp10(),
+            None if self.is_negative() => Err(FloatError::Underflow),
+            None => Err(FloatError::Overflow),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn exp() {
+        assert_eq!(Float::ZERO.exp(), Ok(Float::ONE));
+        assert_eq!(Float::ONE.exp(), Ok(tifloat!(0x27182818284590 * 10 ^ 0)));
+        assert_eq!(
+            tifloat!(-0x50000000000000 * 10 ^ -1).exp(),
+            Ok(tifloat!(0x60653065971263 * 10 ^ -1))
+        );
+        assert_eq!(
+            tifloat!(0x23000000000000 * 10 ^ 2).exp(),
+            Ok(tifloat!(0x77220184999838 * 10 ^ 99))
+        );
+        assert_eq!(
+            tifloat!(-0x22700000000000 * 10 ^ 2).exp(),
+            Ok(tifloat!(0x26010734011100 * 10 ^ -99))
+        );
+        assert_eq!(tifloat!(0x10000000000000 * 10 ^ -20).exp(), Ok(Float::ONE));
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ -13).exp(),
+            Ok(tifloat!(0x10000000000001 * 10 ^ 0))
+        );
+        assert!(Float::undefined().exp().unwrap().is_undefined());
+
+        assert_eq!(
+            tifloat!(0x23100000000000 * 10 ^ 2).exp(),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            tifloat!(-0x23100000000000 * 10 ^ 2).exp(),
+            Err(FloatError::Underflow)
+        );
+        assert_eq!(Float::MAX.exp(), Err(FloatError::Overflow));
+        assert_eq!((-Float::MAX).exp(), Err(FloatError::Underflow));
+    }
+
+    #[test]
+    fn exp10() {
+        assert_eq!(Float::ZERO.exp10(), Ok(Float::ONE));
+        assert_eq!(
+            tifloat!(0x42000000000000 * 10 ^ 1).exp10(),
+            Ok(tifloat!(0x10000000000000 * 10 ^ 42))
+        );
+        assert_eq!(
+            tifloat!(-0x99000000000000 * 10 ^ 1).exp10(),
+            Ok(Float::MIN_POSITIVE)
+        );
+        assert_eq!(
+            tifloat!(0x50000000000000 * 10 ^ -1).exp10(),
+            Ok(tifloat!(0x31622776601684 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x30102999566398 * 10 ^ -1).exp10(),
+            Ok(tifloat!(0x20000000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(-0x12500000000000 * 10 ^ 0).exp10(),
+            Ok(tifloat!(0x56234132519035 * 10 ^ -2))
+        );
+        assert_eq!(
+            tifloat!(0x99999999999999 * 10 ^ 1).exp10(),
+            Ok(tifloat!(0x99999999999770 * 10 ^ 99))
+        );
+        assert!(Float::undefined().exp10().unwrap().is_undefined());
+
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 2).exp10(),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            tifloat!(-0x10000000000000 * 10 ^ 2).exp10(),
+            Err(FloatError::Underflow)
+        );
+        assert_eq!(
+            tifloat!(-0x99500000000000 * 10 ^ 1).exp10(),
+            Err(FloatError::Underflow)
+        );
+        assert_eq!(Float::MAX.exp10(), Err(FloatError::Overflow));
+        assert_eq!((-Float::MAX).exp10(), Err(FloatError::Underflow));
+    }
+
+    #[test]
+    fn ln_round_trip() {
+        for value in [
+            tifloat!(0x12345678901234 * 10 ^ 1),
+            tifloat!(-0x98765432109876 * 10 ^ 0),
+            tifloat!(0x22222222222222 * 10 ^ 2),
+            Float::PI,
+        ] {
+            assert_eq!(value.exp().unwrap().ln(), Ok(value));
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{Float, FloatError};
+
+/// Digits kept by the sum accumulator. Sums of 14-digit terms only lose digits once the terms'
+/// exponents are more than 22 apart.
+const SUM_DIGITS: u32 = 36;
+
+/// Digits kept by the product accumulator, chosen so that a 14-digit factor still fits in a u128.
+const PRODUCT_DIGITS: u32 = 24;
+
+/// An extended-precision decimal, `coefficient * 10 ^ exponent`, used to accumulate sums and
+/// products with a single rounding at the end. The exponent is unbounded in practice, so
+/// intermediate results may leave the Float range as long as the final result does not.
+///
+/// An undefined operand makes the whole result undefined.
+#[derive(Copy, Clone)]
+pub(crate) struct Extended {
+    coefficient: i128,
+    exponent: i64,
+    undefined: bool,
+}
+
+impl Extended {
+    pub(crate) fn new(float: &Float) -> Self {
+        let magnitude = float.significand_dec() as i128;
+
+        Extended {
+            coefficient: if float.is_negative() {
+                -magnitude
+            } else {
+                magnitude
+            },
+            exponent: float.exponent() as i64 - 13,
+            undefined: float.is_undefined(),
+        }
+    }
+
+    /// An integer of up to 19 digits, such as any `i64` or `u64`.
+    pub(crate) fn from_integer(value: i128) -> Self {
+        Extended {
+            coefficient: value,
+            exponent: 0,
+            undefined: false,
+        }
+    }
+
+    fn digit_count(&self) -> u32 {
+        self.coefficient
+            .unsigned_abs()
+            .checked_ilog10()
+            .map_or(0, |log| log + 1)
+    }
+
+    /// Drops the lowest `places` digits, rounding half away from zero.
+    fn shift_right(self, places: u32) -> Self {
+        let magnitude = self.coefficient.unsigned_abs();
+        let shifted = match 10u128.checked_pow(places) {
+            Some(divisor) => (magnitude + divisor / 2) / divisor,
+            None => 0,
+        };
+
+        Extended {
+            coefficient: if self.coefficient < 0 {
+                -(shifted as i128)
+            } else {
+                shifted as i128
+            },
+            exponent: self.exponent + places as i64,
+            ..self
+        }
+    }
+
+    /// Drops digits until at most `digits` remain.
+    fn truncate_to(self, digits: u32) -> Self {
+        let excess = self.digit_count().saturating_sub(digits);
+        let shifted = self.shift_right(excess);
+
+        // rounding 999...9|5 carries into a new digit
+        if shifted.digit_count() > digits {
+            shifted.shift_right(1)
+        } else {
+            shifted
+        }
+    }
+
+    pub(crate) fn add(self, rhs: Extended) -> Self {
+        if self.undefined {
+            return self;
+        } else if rhs.undefined || self.coefficient == 0 {
+            return rhs;
+        } else if rhs.coefficient == 0 {
+            return self;
+        }
+
+        // align to the smaller exponent, unless that would push either side past the digit limit
+        let top = (self.exponent + self.digit_count() as i64)
+            .max(rhs.exponent + rhs.digit_count() as i64);
+        let exponent = self.exponent.min(rhs.exponent).max(top - SUM_DIGITS as i64);
+
+        let align = |value: Extended| {
+            if value.exponent >= exponent {
+                value.coefficient * 10i128.pow((value.exponent - exponent) as u32)
+            } else {
+                value
+                    .shift_right((exponent - value.exponent) as u32)
+                    .coefficient
+            }
+        };
+
+        Extended {
+            coefficient: align(self) + align(rhs),
+            exponent,
+            undefined: false,
+        }
+        .truncate_to(SUM_DIGITS)
+    }
+
+    pub(crate) fn mul(self, rhs: Extended) -> Self {
+        if self.undefined {
+            return self;
+        } else if rhs.undefined {
+            return rhs;
+        }
+
+        let magnitude = self.coefficient.unsigned_abs() * rhs.coefficient.unsigned_abs();
+        let negative = (self.coefficient < 0) != (rhs.coefficient < 0);
+
+        // the product fits in a u128 while the coefficients total at most 38 digits (24 + 14 for an
+        // accumulated product, 19 + 14 for an integer operand), but not always in an i128
+        let excess =
+            (magnitude.checked_ilog10().map_or(0, |log| log + 1)).saturating_sub(PRODUCT_DIGITS);
+        let divisor = 10u128.pow(excess);
+        let magnitude = (magnitude + divisor / 2) / divisor;
+
+        Extended {
+            coefficient: if negative {
+                -(magnitude as i128)
+            } else {
+                magnitude as i128
+            },
+            exponent: self.exponent + rhs.exponent + excess as i64,
+            undefined: false,
+        }
+        .truncate_to(PRODUCT_DIGITS)
+    }
+
+    /// `-1`, `0`, or `1`, according to the sign of the value. Undefined values are `0`.
+    pub(crate) fn signum(&self) -> i128 {
+        if self.undefined {
+            0
+        } else {
+            self.coefficient.signum()
+        }
+    }
+
+    pub(crate) fn to_float(self) -> Result<Float, FloatError> {
+        if self.undefined {
+            return Ok(Float::undefined());
+        }
+
+        Float::from_scaled_integer(
+            self.coefficient < 0,
+            self.coefficient.unsigned_abs(),
+            self.exponent,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn alignment() {
+        let sum = Extended::new(&tifloat!(0x10000000000000 * 10 ^ 20))
+            .add(Extended::new(&tifloat!(0x10000000000000 * 10 ^ -10)));
+
+        assert_eq!(sum.coefficient, 10i128.pow(35) + 10i128.pow(5));
+        assert_eq!(sum.exponent, -15);
+
+        // the small term survives until the large ones cancel
+        let sum = Extended::new(&tifloat!(0x10000000000000 * 10 ^ 20))
+            .add(Extended::from_integer(1))
+            .add(Extended::new(&tifloat!(-0x10000000000000 * 10 ^ 20)));
+
+        assert_eq!(sum.to_float().ok().unwrap(), Float::ONE);
+    }
+
+    #[test]
+    fn carries() {
+        let rounded = Extended::from_integer(999_995).truncate_to(5);
+        assert_eq!((rounded.coefficient, rounded.exponent), (10_000, 2));
+
+        let rounded = Extended::from_integer(-999_995).truncate_to(5);
+        assert_eq!((rounded.coefficient, rounded.exponent), (-10_000, 2));
+
+        let sum = Extended::new(&tifloat!(0x99999999999999 * 10 ^ 0))
+            .add(Extended::new(&tifloat!(0x50000000000000 * 10 ^ -14)));
+        assert_eq!(
+            sum.to_float().ok().unwrap(),
+            tifloat!(0x10000000000000 * 10 ^ 1)
+        );
+
+        let product = Extended::from_integer(9_999_999_999_999_999_999)
+            .mul(Extended::new(&tifloat!(0x10000000000000 * 10 ^ -99)));
+        assert_eq!(
+            product.to_float().ok().unwrap(),
+            tifloat!(0x10000000000000 * 10 ^ -80)
+        );
+    }
+
+    #[test]
+    fn signs() {
+        let x = Extended::new(&tifloat!(-0x25000000000000 * 10 ^ 0));
+
+        assert_eq!(
+            x.mul(Extended::from_integer(-4)).to_float().ok().unwrap(),
+            tifloat!(0x10000000000000 * 10 ^ 1)
+        );
+        assert_eq!(
+            x.mul(Extended::from_integer(4)).to_float().ok().unwrap(),
+            tifloat!(-0x10000000000000 * 10 ^ 1)
+        );
+        assert_eq!(
+            Extended::from_integer(2)
+                .add(Extended::from_integer(-3))
+                .to_float()
+                .ok()
+                .unwrap(),
+            tifloat!(-0x10000000000000 * 10 ^ 0)
+        );
+        assert_eq!(Extended::from_integer(-15).shift_right(1).coefficient, -2);
+        assert_eq!(x.signum(), -1);
+        assert_eq!(x.add(x.mul(Extended::from_integer(-1))).signum(), 0);
+    }
+
+    #[test]
+    fn undefined() {
+        let undefined = Extended::new(&Float::undefined());
+
+        assert!(Extended::from_integer(1)
+            .add(undefined)
+            .to_float()
+            .ok()
+            .unwrap()
+            .is_undefined());
+        assert!(undefined
+            .mul(Extended::from_integer(0))
+            .to_float()
+            .ok()
+            .unwrap()
+            .is_undefined());
+        assert_eq!(undefined.signum(), 0);
+    }
+}
@@ -0,0 +1,420 @@
+use std::cmp::Ordering;
+
+use crate::{tifloat, Float, FloatError};
+
+/// `√π`, the factorial of `-.5`, to 40 digits.
+const SQRT_PI: [u8; 40] = [
+    1, 7, 7, 2, 4, 5, 3, 8, 5, 0, 9, 0, 5, 5, 1, 6, 0, 2, 7, 2, 9, 8, 1, 6, 7, 4, 8, 3, 3, 4, 1, 1,
+    4, 5, 1, 8, 2, 7, 9, 8,
+];
+
+/// An unsigned integer as base-`10 ^ 9` limbs, least significant first, for factorials and
+/// combinatorics that are exact until they are rounded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Natural(Vec<u64>);
+
+impl Natural {
+    const BASE: u64 = 1_000_000_000;
+
+    fn from_digits(digits: &[u8]) -> Self {
+        let mut natural = Natural(vec![0]);
+        for &digit in digits {
+            natural.mul_add(10, digit as u64);
+        }
+
+        natural
+    }
+
+    /// A nonnegative integer Float.
+    fn from_integer(value: &Float) -> Self {
+        if value.is_zero() {
+            return Natural(vec![0]);
+        }
+
+        let digits = value
+            .mantissa_digits()
+            .into_iter()
+            .chain(std::iter::repeat(0))
+            .take(value.exponent() as usize + 1)
+            .collect::<Vec<_>>();
+
+        Natural::from_digits(&digits)
+    }
+
+    /// `self * factor + addend`, for a factor and addend less than `10 ^ 9`.
+    fn mul_add(&mut self, factor: u64, addend: u64) {
+        let mut carry = addend;
+        for limb in &mut self.0 {
+            let value = *limb * factor + carry;
+            *limb = value % Natural::BASE;
+            carry = value / Natural::BASE;
+        }
+
+        if carry != 0 {
+            self.0.push(carry);
+        }
+    }
+
+    fn mul(&self, rhs: &Natural) -> Natural {
+        let mut product = vec![0; self.0.len() + rhs.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in rhs.0.iter().enumerate() {
+                let value = product[i + j] + a * b + carry;
+                product[i + j] = value % Natural::BASE;
+                carry = value / Natural::BASE;
+            }
+
+            product[i + rhs.0.len()] = carry;
+        }
+
+        Natural(product).normalized()
+    }
+
+    /// `self - rhs`, for `rhs` no larger than `self`.
+    fn sub(&self, rhs: &Natural) -> Natural {
+        let mut borrow = 0;
+        let difference = self
+            .0
+            .iter()
+            .zip(rhs.0.iter().chain(std::iter::repeat(&0)))
+            .map(|(&a, &b)| {
+                let (value, next) = match a.checked_sub(b + borrow) {
+                    Some(value) => (value, 0),
+                    None => (a + Natural::BASE - b - borrow, 1),
+                };
+
+                borrow = next;
+                value
+            })
+            .collect();
+
+        Natural(difference).normalized()
+    }
+
+    /// `self / divisor`, for a divisor less than `10 ^ 9` that divides it exactly.
+    fn div_exact(&self, divisor: u64) -> Natural {
+        let mut remainder = 0;
+        let mut quotient = self.0.clone();
+        for limb in quotient.iter_mut().rev() {
+            let value = remainder * Natural::BASE + *limb;
+            *limb = value / divisor;
+            remainder = value % divisor;
+        }
+
+        Natural(quotient).normalized()
+    }
+
+    /// This value as a `u64`, if it fits.
+    fn to_u64(&self) -> Option<u64> {
+        self.0.iter().rev().try_fold(0u64, |acc, &limb| {
+            acc.checked_mul(Natural::BASE)?.checked_add(limb)
+        })
+    }
+
+    /// Whether this value is at least `10 ^ 108`, far too large for a Float.
+    fn is_huge(&self) -> bool {
+        self.0.len() > 12
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.0.len() > 1 && self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+
+        self
+    }
+
+    /// The Float nearest to `self * 10 ^ scale`, rounded half-up to 14 significant digits.
+    fn to_float(&self, scale: i64) -> Result<Float, FloatError> {
+        let mut limbs = self.0.iter().rev();
+        let mut digits = limbs.next().unwrap().to_string();
+        for limb in limbs {
+            digits += &format!("{limb:09}");
+        }
+
+        let digits = digits.bytes().map(|byte| byte - b'0').collect::<Vec<_>>();
+
+        Float::from_decimal_digits(false, &digits, digits.len() as i64 - 1 + scale)
+    }
+}
+
+impl PartialOrd for Natural {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Natural {
+    /// Compares normalized values, which have no leading zero limbs.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl Float {
+    /// The factorial, like `x!`, correctly rounded half-up to 14 significant digits.
+    ///
+    /// As on the calculator, it is defined for the integers from `0` to `69` and for the odd
+    /// multiples of `.5` from `-.5` to `69.5`, which extend it as `x! = Γ(x+1)`, so that `.5!` is
+    /// `√π/2`. Any argument above `69.5` fails with [`FloatError::Overflow`], and any other
+    /// argument with [`FloatError::Domain`]. The factorial of an undefined Float is undefined.
+    #[doc(alias = "!")]
+    pub fn factorial(&self) -> Result<Float, FloatError> {
+        let half = tifloat!(0x50000000000000 * 10 ^ -1);
+
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        } else if *self > tifloat!(0x69500000000000 * 10 ^ 1) {
+            return Err(FloatError::Overflow);
+        } else if *self < -half {
+            return Err(FloatError::Domain);
+        }
+
+        // the argument is at most 69.5 and at least -.5, so its integer part fits easily
+        let whole = self.trunc().as_integer().unwrap();
+        let fraction = self.fract();
+
+        if fraction.is_zero() && whole >= 0 {
+            let mut product = Natural(vec![1]);
+            for factor in 2..=whole as u64 {
+                product.mul_add(factor, 0);
+            }
+
+            product.to_float(0)
+        } else if fraction == half || (fraction == -half && whole == 0) {
+            // (m + 1/2)! = √π * 1/2 * 3/2 * ... * (2m + 1)/2, where each half is five tenths
+            let last = if self.is_negative() { -1 } else { whole };
+            let mut product = Natural::from_digits(&SQRT_PI);
+            for m in 0..=last {
+                product.mul_add(5 * (2 * m as u64 + 1), 0);
+            }
+
+            product.to_float(-(SQRT_PI.len() as i64 - 1) - (last + 1))
+        } else {
+            Err(FloatError::Domain)
+        }
+    }
+
+    /// The number of ways to choose `r` of `self` items in order, like `nPr`, exact while it
+    /// fits in 14 digits and correctly rounded half-up beyond that. It is zero if `r` is greater
+    /// than `self`.
+    ///
+    /// Fails with [`FloatError::Domain`] unless both values are nonnegative integers, and with
+    /// [`FloatError::Overflow`] if the result is `1E100` or more. If either value is undefined,
+    /// the result is undefined.
+    #[doc(alias = "nPr")]
+    pub fn permutations(&self, r: &Float) -> Result<Float, FloatError> {
+        let Some((n, r)) = combinatoric_arguments(self, r)? else {
+            return Ok(Float::undefined());
+        };
+
+        if r > n {
+            return Ok(Float::ZERO);
+        }
+
+        // the product is at least r!, so at least 70 factors overflow
+        let r = match r.to_u64() {
+            Some(r @ ..70) => r,
+            _ => return Err(FloatError::Overflow),
+        };
+
+        let mut product = Natural(vec![1]);
+        for k in 0..r {
+            product = product.mul(&n.sub(&Natural(vec![k])));
+        }
+
+        product.to_float(0)
+    }
+
+    /// The number of ways to choose `r` of `self` items, like `nCr`, exact while it fits in 14
+    /// digits and correctly rounded half-up beyond that. It is zero if `r` is greater than
+    /// `self`.
+    ///
+    /// Fails with [`FloatError::Domain`] unless both values are nonnegative integers, and with
+    /// [`FloatError::Overflow`] if the result is `1E100` or more. If either value is undefined,
+    /// the result is undefined.
+    #[doc(alias = "nCr")]
+    pub fn combinations(&self, r: &Float) -> Result<Float, FloatError> {
+        let Some((n, r)) = combinatoric_arguments(self, r)? else {
+            return Ok(Float::undefined());
+        };
+
+        if r > n {
+            return Ok(Float::ZERO);
+        }
+
+        // choosing r is choosing the n - r to leave out, and with r at most n / 2, each factor
+        // (n - k) / (k + 1) is at least one and the early ones at least two, so the result
+        // overflows long before r reaches 2 ^ 64
+        let rest = n.sub(&r);
+        let r = match r.min(rest).to_u64() {
+            Some(r) => r,
+            None => return Err(FloatError::Overflow),
+        };
+
+        // C(n, k + 1) = C(n, k) * (n - k) / (k + 1), exactly
+        let mut combinations = Natural(vec![1]);
+        for k in 0..r {
+            combinations = combinations.mul(&n.sub(&Natural(vec![k]))).div_exact(k + 1);
+
+            if combinations.is_huge() {
+                return Err(FloatError::Overflow);
+            }
+        }
+
+        combinations.to_float(0)
+    }
+}
+
+/// Checks the arguments of `nPr` and `nCr`, returning `None` if either is undefined.
+fn combinatoric_arguments(n: &Float, r: &Float) -> Result<Option<(Natural, Natural)>, FloatError> {
+    if n.is_undefined() || r.is_undefined() {
+        return Ok(None);
+    } else if !n.is_integer() || !r.is_integer() || n.is_negative() || r.is_negative() {
+        return Err(FloatError::Domain);
+    }
+
+    Ok(Some((Natural::from_integer(n), Natural::from_integer(r))))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn factorial() {
+        let half = tifloat!(0x50000000000000 * 10 ^ -1);
+
+        let cases = [
+            (Float::ZERO, Float::ONE),
+            (Float::ONE, Float::ONE),
+            (
+                tifloat!(0x10000000000000 * 10 ^ 1),
+                tifloat!(0x36288000000000 * 10 ^ 6),
+            ),
+            (
+                tifloat!(0x25000000000000 * 10 ^ 1),
+                tifloat!(0x15511210043331 * 10 ^ 25),
+            ),
+            (
+                tifloat!(0x69000000000000 * 10 ^ 1),
+                tifloat!(0x17112245242814 * 10 ^ 98),
+            ),
+            (-half, tifloat!(0x17724538509055 * 10 ^ 0)),
+            (half, tifloat!(0x88622692545276 * 10 ^ -1)),
+            (
+                tifloat!(0x25000000000000 * 10 ^ 0),
+                tifloat!(0x33233509704478 * 10 ^ 0),
+            ),
+            (
+                tifloat!(0x69500000000000 * 10 ^ 1),
+                tifloat!(0x14291588283032 * 10 ^ 99),
+            ),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(value.factorial(), Ok(expected), "{value:?}!");
+        }
+
+        assert!(Float::undefined().factorial().unwrap().is_undefined());
+
+        for value in [
+            tifloat!(0x70000000000000 * 10 ^ 1),
+            tifloat!(0x69600000000000 * 10 ^ 1),
+            Float::MAX,
+        ] {
+            assert_eq!(value.factorial(), Err(FloatError::Overflow), "{value:?}!");
+        }
+
+        for value in [
+            -Float::ONE,
+            tifloat!(-0x15000000000000 * 10 ^ 0),
+            tifloat!(0x25000000000000 * 10 ^ -1),
+            tifloat!(0x10000000000001 * 10 ^ 0),
+            -Float::MAX,
+        ] {
+            assert_eq!(value.factorial(), Err(FloatError::Domain), "{value:?}!");
+        }
+    }
+
+    #[test]
+    fn permutations_and_combinations() {
+        let integer = |value: u64| Float::from_scaled_integer(false, value as u128, 0).unwrap();
+
+        let cases = [
+            // n, r, nPr, nCr
+            (integer(5), integer(2), integer(20), integer(10)),
+            (
+                integer(52),
+                integer(5),
+                integer(311_875_200),
+                integer(2_598_960),
+            ),
+            (integer(7), integer(0), Float::ONE, Float::ONE),
+            (integer(7), integer(7), integer(5040), Float::ONE),
+            (integer(3), integer(5), Float::ZERO, Float::ZERO),
+            (Float::ZERO, Float::ZERO, Float::ONE, Float::ONE),
+            (
+                integer(100),
+                integer(50),
+                tifloat!(0x30685187562550 * 10 ^ 93),
+                tifloat!(0x10089134454556 * 10 ^ 29),
+            ),
+            (
+                integer(40),
+                integer(20),
+                tifloat!(0x33536709678636 * 10 ^ 29),
+                integer(137_846_528_820),
+            ),
+            (
+                Float::MAX_SAFE_INTEGER,
+                integer(2),
+                tifloat!(0x99999999999997 * 10 ^ 27),
+                tifloat!(0x49999999999999 * 10 ^ 27),
+            ),
+        ];
+
+        for (n, r, permutations, combinations) in cases {
+            assert_eq!(n.permutations(&r), Ok(permutations), "{n:?} nPr {r:?}");
+            assert_eq!(n.combinations(&r), Ok(combinations), "{n:?} nCr {r:?}");
+        }
+
+        let huge = tifloat!(0x10000000000000 * 10 ^ 50);
+        assert_eq!(huge.permutations(&Float::ONE), Ok(huge));
+        assert_eq!(huge.combinations(&huge), Ok(Float::ONE));
+        assert_eq!(
+            huge.combinations(&integer(2)),
+            Ok(tifloat!(0x50000000000000 * 10 ^ 99))
+        );
+        assert_eq!(huge.permutations(&integer(2)), Err(FloatError::Overflow));
+        assert_eq!(
+            integer(100).permutations(&integer(70)),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            integer(1000).combinations(&integer(500)),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            huge.combinations(&Float::MAX_SAFE_INTEGER),
+            Err(FloatError::Overflow)
+        );
+        assert!(Float::undefined()
+            .combinations(&Float::ONE)
+            .unwrap()
+            .is_undefined());
+
+        for (n, r) in [
+            (-integer(5), integer(2)),
+            (integer(5), -Float::ONE),
+            (tifloat!(0x55000000000000 * 10 ^ 0), integer(2)),
+        ] {
+            assert_eq!(n.permutations(&r), Err(FloatError::Domain));
+            assert_eq!(n.combinations(&r), Err(FloatError::Domain));
+        }
+    }
+}
@@ -0,0 +1,372 @@
+use std::ops::{Add, Neg, Sub};
+
+use crate::uint::U256;
+use crate::{Float, FloatError, RoundingMode};
+
+/// Decimal places of a [`Fixed`] value.
+pub(crate) const PLACES: u32 = 34;
+
+/// `10 ^ 34`, the fixed-point representation of one.
+const SCALE: u128 = 10u128.pow(PLACES);
+
+/// `ln 2`, scaled by `10 ^ 38`.
+const LN_2: u128 = 69_314_718_055_994_530_941_723_212_145_817_656_808;
+
+/// `ln 10`, scaled by `10 ^ 37`.
+const LN_10: u128 = 23_025_850_929_940_456_840_179_914_546_843_642_076;
+
+/// A signed fixed-point number with 34 decimal places, stored scaled by `10 ^ 34`, used to evaluate logarithms and
+/// exponentials with a single rounding to 14 digits at the end. Magnitudes stay below about
+/// `17000`, which is plenty for the natural log of any Float.
+///
+/// Every operation rounds half-up to the last place, so results carry an error of a few units in
+/// the 34th place, twenty digits below what a Float keeps.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Fixed(pub(crate) i128);
+
+impl Fixed {
+    pub(crate) const ZERO: Fixed = Fixed(0);
+
+    pub(crate) const ONE: Fixed = Fixed(SCALE as i128);
+
+    fn from_magnitude(negative: bool, magnitude: u128) -> Option<Fixed> {
+        let value = i128::try_from(magnitude).ok()?;
+
+        Some(Fixed(if negative { -value } else { value }))
+    }
+
+    /// `constant * 10 ^ -places * multiple`, rounded to a Fixed. Multiples of `ln 2` and `ln 10`
+    /// start from extra digits, so that they stay accurate to the last place.
+    fn multiple_of(constant: u128, places: u32, multiple: i64) -> Fixed {
+        let (quotient, remainder) = U256::mul(constant, multiple.unsigned_abs() as u128)
+            .div_rem(10u128.pow(places - PLACES));
+        let magnitude =
+            quotient.to_u128().unwrap() + (remainder * 2 >= 10u128.pow(places - PLACES)) as u128;
+
+        Fixed::from_magnitude(multiple < 0, magnitude).unwrap()
+    }
+
+    pub(crate) fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// The product, or `None` if it is too large for a Fixed.
+    pub(crate) fn mul(self, rhs: Fixed) -> Option<Fixed> {
+        let (quotient, remainder) =
+            U256::mul(self.0.unsigned_abs(), rhs.0.unsigned_abs()).div_rem(SCALE);
+        let magnitude = quotient
+            .to_u128()?
+            .checked_add((remainder * 2 >= SCALE) as u128)?;
+
+        Fixed::from_magnitude(self.is_negative() != rhs.is_negative(), magnitude)
+    }
+
+    /// The quotient by a nonzero Fixed, or `None` if it is too large for a Fixed.
+    pub(crate) fn div(self, rhs: Fixed) -> Option<Fixed> {
+        let divisor = rhs.0.unsigned_abs();
+        let (quotient, remainder) = U256::mul(self.0.unsigned_abs(), SCALE).div_rem(divisor);
+        let magnitude = quotient
+            .to_u128()?
+            .checked_add((remainder >= divisor - remainder) as u128)?;
+
+        Fixed::from_magnitude(self.is_negative() != rhs.is_negative(), magnitude)
+    }
+
+    /// The product with a defined Float, or `None` if it is too large for a Fixed.
+    pub(crate) fn mul_float(self, rhs: &Float) -> Option<Fixed> {
+        let mut product = U256::mul(self.0.unsigned_abs(), rhs.significand_dec() as u128);
+        let exponent = rhs.exponent() as i64 - 13;
+
+        for _ in 0..exponent {
+            product = product.checked_mul(10)?;
+        }
+
+        // the product has at most 53 digits, so two divisions drop all that can be dropped
+        let mut places = (-exponent).clamp(0, 76) as u32;
+        let mut rounding = 0;
+        while places > 0 {
+            let step = places.min(38);
+            let divisor = 10u128.pow(step);
+            let (quotient, remainder) = product.div_rem(divisor);
+
+            product = quotient;
+            rounding = (remainder >= divisor - remainder) as u128;
+            places -= step;
+        }
+
+        let magnitude = product.to_u128()?.checked_add(rounding)?;
+
+        Fixed::from_magnitude(self.is_negative() != rhs.is_negative(), magnitude)
+    }
+
+    /// The quotient by a nonzero Fixed, rounded half-up to a Float. Unlike [`Fixed::div`], it
+    /// can't overflow a Fixed, so it suits quotients of logs.
+    pub(crate) fn div_to_float(self, rhs: Fixed) -> Result<Float, FloatError> {
+        ratio_to_float(
+            self.is_negative() != rhs.is_negative(),
+            self.0.unsigned_abs(),
+            rhs.0.unsigned_abs(),
+            0,
+        )
+    }
+
+    /// The quotient by a nonzero, defined Float, or `None` if it is too large for a Fixed.
+    pub(crate) fn div_float(self, rhs: &Float) -> Option<Fixed> {
+        let significand = rhs.significand_dec() as u128;
+        let exponent = rhs.exponent() as i64 - 13;
+
+        // self / (significand * 10 ^ exponent), keeping the digits of a power of ten below one
+        let mut dividend = U256::new(self.0.unsigned_abs());
+        for _ in exponent..0 {
+            dividend = dividend.checked_mul(10)?;
+        }
+
+        let (quotient, remainder) = dividend.div_rem(significand);
+        let mut magnitude = quotient.to_u128()? + (remainder >= significand - remainder) as u128;
+        if exponent > 0 {
+            magnitude = match 10u128.checked_pow(exponent as u32) {
+                Some(power) => RoundingMode::HalfUp.divide(magnitude, power),
+                None => 0,
+            };
+        }
+
+        Fixed::from_magnitude(self.is_negative() != rhs.is_negative(), magnitude)
+    }
+
+    /// The natural log of a positive, defined Float.
+    pub(crate) fn ln(value: &Float) -> Fixed {
+        Fixed::ln_significand(value) + Fixed::multiple_of(LN_10, 37, value.exponent() as i64)
+    }
+
+    /// The base-10 log of a positive, defined Float. Its integer part comes from the exponent
+    /// exactly, so powers of ten have exact logs.
+    pub(crate) fn log10(value: &Float) -> Fixed {
+        let ln_10 = Fixed::multiple_of(LN_10, 37, 1);
+
+        Fixed::ln_significand(value).div(ln_10).unwrap()
+            + Fixed(value.exponent() as i128 * SCALE as i128)
+    }
+
+    /// The natural log of the significand of a positive, defined Float, in `[0, ln 10)`.
+    fn ln_significand(value: &Float) -> Fixed {
+        let mut significand = Fixed(value.significand_dec() as i128 * 10i128.pow(PLACES - 13));
+
+        // halving into [0.75, 1.5) is exact, since the significand has 21 trailing zeros
+        let mut halvings = 0;
+        while significand.0 >= 3 * SCALE as i128 / 2 {
+            significand.0 /= 2;
+            halvings += 1;
+        }
+
+        // ln r = 2 atanh((r - 1) / (r + 1)), where |(r - 1) / (r + 1)| <= 0.2
+        let z = (significand - Fixed::ONE)
+            .div(significand + Fixed::ONE)
+            .unwrap();
+        let z_squared = z.mul(z).unwrap();
+
+        let mut power = z;
+        let mut sum = Fixed::ZERO;
+        for n in (1..).step_by(2) {
+            let term = Fixed(power.0 / n);
+            if term == Fixed::ZERO {
+                break;
+            }
+
+            sum = sum + term;
+            power = power.mul(z_squared).unwrap();
+        }
+
+        sum + sum + Fixed::multiple_of(LN_2, 38, halvings)
+    }
+
+    /// A defined Float, rounded half-up to 34 places, or `None` if it is too large for a Fixed.
+    pub(crate) fn from_float(value: &Float) -> Option<Fixed> {
+        let significand = value.significand_dec() as u128;
+        let places = value.exponent() as i32 - 13 + PLACES as i32;
+
+        let magnitude = if places >= 0 {
+            significand.checked_mul(10u128.checked_pow(places as u32)?)?
+        } else {
+            match 10u128.checked_pow(-places as u32) {
+                Some(power) => RoundingMode::HalfUp.divide(significand, power),
+                None => 0,
+            }
+        };
+
+        Fixed::from_magnitude(value.is_negative(), magnitude)
+    }
+
+    /// The value rounded half-up to a Float.
+    pub(crate) fn to_float(self) -> Result<Float, FloatError> {
+        Float::from_scaled_integer(self.is_negative(), self.0.unsigned_abs(), -(PLACES as i64))
+    }
+
+    /// The exponential, rounded half-up to a Float. Fails with [`FloatError::Overflow`] or
+    /// [`FloatError::Underflow`] if it is out of range.
+    pub(crate) fn exp(self) -> Result<Float, FloatError> {
+        // exp(self) = exp(r) * 10 ^ k, with r in about [0, ln 10)
+        let k = self.0.div_euclid(Fixed::multiple_of(LN_10, 37, 1).0) as i64;
+
+        Fixed::exp_reduced(self - Fixed::multiple_of(LN_10, 37, k), k)
+    }
+
+    /// `10 ^ self`, rounded half-up to a Float. Fails with [`FloatError::Overflow`] or
+    /// [`FloatError::Underflow`] if it is out of range.
+    pub(crate) fn exp10(self) -> Result<Float, FloatError> {
+        // 10 ^ self = exp(r) * 10 ^ k, with r = ln 10 * (self - k) in [0, ln 10)
+        let k = self.0.div_euclid(SCALE as i128) as i64;
+        let r = Fixed(self.0.rem_euclid(SCALE as i128))
+            .mul(Fixed::multiple_of(LN_10, 37, 1))
+            .unwrap();
+
+        Fixed::exp_reduced(r, k)
+    }
+
+    /// `exp(r) * 10 ^ k`, rounded half-up to a Float, for `r` in about `[0, ln 10)`.
+    fn exp_reduced(r: Fixed, k: i64) -> Result<Float, FloatError> {
+        if k > 99 {
+            return Err(FloatError::Overflow);
+        } else if k < -100 {
+            return Err(FloatError::Underflow);
+        }
+
+        // exp(r) = exp(s) * 2 ^ j, with s in about [0, ln 2)
+        let j = r.0.div_euclid(Fixed::multiple_of(LN_2, 38, 1).0) as i64;
+        let s = r - Fixed::multiple_of(LN_2, 38, j);
+
+        let mut term = Fixed::ONE;
+        let mut sum = Fixed::ONE;
+        for n in 1.. {
+            term = Fixed(term.mul(s).unwrap().0 / n);
+            if term == Fixed::ZERO {
+                break;
+            }
+
+            sum = sum + term;
+        }
+
+        let mantissa = if j >= 0 { sum.0 << j } else { sum.0 >> -j };
+
+        Float::from_scaled_integer(false, mantissa as u128, k - PLACES as i64)
+    }
+}
+
+/// `dividend / divisor * 10 ^ scale`, for a nonzero divisor, rounded half-up to a Float.
+pub(crate) fn ratio_to_float(
+    negative: bool,
+    dividend: u128,
+    divisor: u128,
+    scale: i64,
+) -> Result<Float, FloatError> {
+    let digits = |value: u128| value.checked_ilog10().map_or(0, |log| log + 1);
+
+    // scale the dividend for a quotient of at least 32 digits, which rounds correctly to 14
+    let places = (32 + digits(divisor)).saturating_sub(digits(dividend));
+    let scaled = (0..places).try_fold(U256::new(dividend), |value, _| value.checked_mul(10));
+    let quotient = scaled.unwrap().div_rem(divisor).0.to_u128().unwrap();
+
+    Float::from_scaled_integer(negative, quotient, scale - places as i64)
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Self::Output {
+        Fixed(-self.0)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Fixed(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Fixed(self.0 - rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn integer(value: i128) -> Fixed {
+        Fixed(value * SCALE as i128)
+    }
+
+    #[test]
+    fn ln_and_exp() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+
+        assert_eq!(Fixed::ln(&Float::ONE), Fixed::ZERO);
+        assert_eq!(Fixed::ln(&two), Fixed::multiple_of(LN_2, 38, 1));
+        assert_eq!(
+            Fixed::ln(&tifloat!(0x10000000000000 * 10 ^ -3)),
+            Fixed::multiple_of(LN_10, 37, -3)
+        );
+
+        assert_eq!(Fixed::ZERO.exp(), Ok(Float::ONE));
+        assert_eq!(Fixed::ONE.exp(), Ok(tifloat!(0x27182818284590 * 10 ^ 0)));
+        assert_eq!(Fixed::ln(&two).exp(), Ok(two));
+        assert_eq!(
+            (-Fixed::ONE).exp(),
+            Ok(tifloat!(0x36787944117144 * 10 ^ -1))
+        );
+
+        for value in [
+            Float::MAX,
+            Float::MIN_POSITIVE,
+            Float::PI,
+            tifloat!(-0x12345678901234 * 10 ^ -50),
+        ] {
+            let value = value.abs();
+            assert_eq!(Fixed::ln(&value).exp(), Ok(value));
+        }
+
+        assert_eq!(integer(231).exp(), Err(FloatError::Overflow));
+        assert_eq!(integer(-231).exp(), Err(FloatError::Underflow));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let three = integer(3);
+
+        assert_eq!(Fixed::ONE.div(three), Some(Fixed(SCALE as i128 / 3)));
+        assert_eq!(
+            integer(2).div(three),
+            Some(Fixed(2 * SCALE as i128 / 3 + 1))
+        );
+        assert_eq!(
+            integer(2).div_float(&tifloat!(0x40000000000000 * 10 ^ 2)),
+            Some(Fixed(5 * 10i128.pow(PLACES - 3)))
+        );
+        assert_eq!(
+            Fixed::ONE.div_float(&tifloat!(-0x50000000000000 * 10 ^ -2)),
+            Some(integer(-20))
+        );
+        assert_eq!(Fixed::ONE.div_float(&Float::MIN_POSITIVE), None);
+        assert_eq!(Fixed::ONE.div_float(&Float::MAX), Some(Fixed::ZERO));
+        assert_eq!(integer(10000).mul(integer(10000)), None);
+
+        assert_eq!(
+            integer(-3).mul_float(&tifloat!(0x25000000000000 * 10 ^ 0)),
+            Some(Fixed(-75 * 10i128.pow(PLACES - 1)))
+        );
+        assert_eq!(
+            Fixed(15).mul_float(&tifloat!(0x10000000000000 * 10 ^ -1)),
+            Some(Fixed(2))
+        );
+        assert_eq!(
+            integer(2).mul_float(&Float::MIN_POSITIVE),
+            Some(Fixed::ZERO)
+        );
+        assert_eq!(Fixed::ONE.mul_float(&Float::MAX), None);
+    }
+}
@@ -2,29 +2,35 @@ use crate::mantissa::Mantissa;
 use std::fmt::{Debug, Formatter};
 use std::{
     cmp::Ordering,
-    ops::{Add, Div, Mul, Neg, Sub},
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
 use bitflags::bitflags;
 
-use crate::FloatError;
+use crate::{FloatError, RoundingMode};
 
 #[macro_export]
 macro_rules! tifloat {
-    (-$mantissa:literal * 10 ^ $exponent:literal) => {{
-        let float = Float::new_unchecked(true, $exponent, $mantissa);
+    (-$mantissa:literal * 10 ^ $exponent:literal) => {
+        const { $crate::Float::new_or_panic(true, $exponent, $mantissa) }
+    };
 
-        float
-    }};
+    ($mantissa:literal * 10 ^ $exponent:literal) => {
+        const { $crate::Float::new_or_panic(false, $exponent, $mantissa) }
+    };
 
-    ($mantissa:literal * 10 ^ $exponent:literal) => {{
-        let float = Float::new_unchecked(false, $exponent, $mantissa);
+    (-$value:literal) => {
+        const { $crate::Float::from_literal_or_panic(true, stringify!($value)) }
+    };
 
-        float
-    }};
+    ($value:literal) => {
+        const { $crate::Float::from_literal_or_panic(false, stringify!($value)) }
+    };
 }
 
 bitflags! {
+    /// The first byte of a Float's byte representation.
     #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct Flags: u8 {
         /// If this bit is set, the number is undefined (used for initial sequence values)
@@ -39,13 +45,25 @@ bitflags! {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::enum_variant_names)]
 pub enum ParseFloatError {
     InvalidFlags,
     InvalidExponent,
     InvalidMantissa,
+    /// The string is not a decimal number.
+    InvalidString,
+    /// There are fewer than 9 bytes to read.
+    InvalidLength,
+    /// The value has more significant digits than a Float can hold.
+    Inexact,
 }
 
-#[derive(PartialEq, Eq, Copy, Clone)]
+/// A TI-83/84 series real number.
+///
+/// Floats compare by value: `IDK` and `COMPLEX_HALF` are ignored, every zero is equal, and
+/// undefined Floats equal one another. Use [`Float::bitwise_eq`] to compare representations.
+#[derive(Copy, Clone)]
 #[repr(C)]
 pub struct Float {
     flags: Flags,
@@ -58,17 +76,56 @@ impl Float {
     const EXPONENT_MAX: u8 = Float::EXPONENT_NORM + 99;
     const EXPONENT_MIN: u8 = Float::EXPONENT_NORM - 99;
 
-    fn measure(&self) -> u128 {
-        ((!self.is_negative() as u128) << 127)
-            | ((self.exponent as u128) << 56)
-            | self.mantissa.bits() as u128
+    pub const ZERO: Float = Float::new_unchecked(false, 0, 0);
+    pub const ONE: Float = Float::new_unchecked(false, 0, 0x10000000000000);
+    pub const NEG_ONE: Float = Float::new_unchecked(true, 0, 0x10000000000000);
+    /// π rounded to 14 digits.
+    pub const PI: Float = Float::new_unchecked(false, 0, 0x31415926535898);
+    /// Euler's number rounded to 14 digits.
+    pub const E: Float = Float::new_unchecked(false, 0, 0x27182818284590);
+    /// The largest finite Float, `9.9999999999999E99`.
+    pub const MAX: Float = Float::new_unchecked(false, 99, 0x99999999999999);
+    /// The smallest positive normalized Float, `1E-99`.
+    pub const MIN_POSITIVE: Float = Float::new_unchecked(false, -99, 0x10000000000000);
+    /// The largest integer `n` such that every integer from `-n` to `n` is exact,
+    /// `99999999999999`.
+    pub const MAX_SAFE_INTEGER: Float = Float::new_unchecked(false, 13, 0x99999999999999);
+
+    /// A key that orders Floats by value. For normalized mantissas, the exponent and mantissa
+    /// bits order the magnitude, and negating the key for negative values reverses that order.
+    /// Every zero has the same key, whatever its sign and exponent.
+    fn measure(&self) -> i128 {
+        if self.is_zero() {
+            return 0;
+        }
+
+        let magnitude = ((self.exponent as i128) << 56) | self.mantissa.bits() as i128;
+
+        if self.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
     }
 
     /// Intended for use with the tifloat! macro
-    pub fn new(negative: bool, exponent: i8, mantissa: u64) -> Result<Self, FloatError> {
+    pub const fn new(negative: bool, exponent: i8, mantissa: u64) -> Result<Self, FloatError> {
         Self::new_unchecked(negative, exponent, mantissa).check()
     }
 
+    /// Like [`Float::new`], but panics if the Float is invalid. In a `const` context (such as the
+    /// expansion of `tifloat!`), this becomes a compile-time error.
+    #[doc(hidden)]
+    pub const fn new_or_panic(negative: bool, exponent: i8, mantissa: u64) -> Self {
+        match Self::new(negative, exponent, mantissa) {
+            Ok(float) => float,
+            Err(FloatError::Overflow | FloatError::Underflow) => {
+                panic!("tifloat! exponent must be in -99..=99")
+            }
+            Err(_) => panic!("tifloat! mantissa must be 14 packed-BCD digits"),
+        }
+    }
+
     pub const fn new_unchecked(negative: bool, exponent: i8, mantissa: u64) -> Self {
         Float {
             flags: if negative {
@@ -81,6 +138,23 @@ impl Float {
         }
     }
 
+    /// The value of a sequence before it is first computed, which has the UNDEFINED flag set.
+    ///
+    /// Arithmetic with an undefined operand produces an undefined result, even where it would
+    /// otherwise fail, much like a NaN.
+    pub const fn undefined() -> Self {
+        Float {
+            flags: Flags::UNDEFINED,
+            exponent: Self::EXPONENT_NORM,
+            mantissa: Mantissa::from_unchecked(0),
+        }
+    }
+
+    /// This Float with its flags byte replaced, including the sign.
+    pub(crate) const fn with_flags(self, flags: Flags) -> Self {
+        Float { flags, ..self }
+    }
+
     /// Convenience method to produce the appropriate packed-BCD mantissa from a
     /// sequence of decimal digits, read from left to right (MSD = `digits[0]`).
     pub fn mantissa_from(digits: &[u8]) -> u64 {
@@ -94,19 +168,90 @@ impl Float {
             .sum::<u64>();
 
         if digits.len() >= 15 && digits[14] >= 5 {
-            (Mantissa::from(dec).unwrap() + Mantissa::ULP).bits()
+            (<Mantissa>::from(dec).unwrap() + <Mantissa>::ULP).bits()
         } else {
             dec
         }
     }
 
+    /// Produces the Float nearest to a sequence of decimal digits (MSD = `digits[0]`), where the
+    /// first digit has place-value `10 ^ exponent`. Leading zeros are skipped and the digits are
+    /// rounded half-up to 14 significant digits.
+    pub(crate) fn from_decimal_digits(
+        negative: bool,
+        digits: &[u8],
+        exponent: i64,
+    ) -> Result<Self, FloatError> {
+        let leading_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+        let digits = &digits[leading_zeros..];
+        let mut exponent = exponent - leading_zeros as i64;
+
+        if digits.is_empty() {
+            return Ok(Float::ZERO);
+        }
+
+        // rounding 99999999999999|5 carries into a new digit
+        let carries =
+            digits.len() > 14 && digits[14] >= 5 && digits[..14].iter().all(|&digit| digit == 9);
+
+        let mantissa = if carries {
+            exponent += 1;
+
+            <Mantissa>::ONE.bits()
+        } else {
+            Float::mantissa_from(&digits[..digits.len().min(15)])
+        };
+
+        if exponent > 99 {
+            return Err(FloatError::Overflow);
+        } else if exponent < -99 {
+            return Err(FloatError::Underflow);
+        }
+
+        Float::new(negative, exponent as i8, mantissa)
+    }
+
+    /// Produces the Float nearest to `value * 10 ^ exponent`, rounded half-up to 14 significant
+    /// digits.
+    pub(crate) fn from_scaled_integer(
+        negative: bool,
+        value: u128,
+        exponent: i64,
+    ) -> Result<Self, FloatError> {
+        let digits = value
+            .to_string()
+            .bytes()
+            .map(|digit| digit - b'0')
+            .collect::<Vec<_>>();
+
+        Float::from_decimal_digits(negative, &digits, exponent + digits.len() as i64 - 1)
+    }
+
     /// Given a Float, produces byte representation (flags at index zero).
     pub fn to_raw_bytes(&self) -> [u8; 9] {
-        let mut result = vec![self.flags.bits(), self.exponent];
+        let mut result = [0u8; 9];
+        self.write_raw_bytes(&mut result);
 
-        result.extend(&self.mantissa.bits().to_be_bytes()[1..=7]);
+        result
+    }
 
-        result.try_into().unwrap()
+    /// Writes the byte representation (flags at index zero) into `out`.
+    pub fn write_raw_bytes(&self, out: &mut [u8; 9]) {
+        out[0] = self.flags.bits();
+        out[1] = self.exponent;
+        out[2..].copy_from_slice(&self.mantissa.bits().to_be_bytes()[1..=7]);
+    }
+
+    /// Given a slice beginning with the byte representation (flags at index zero), produces a
+    /// Float. Bytes after the first 9 are ignored.
+    pub fn from_raw_slice(bytes: &[u8]) -> Result<Self, ParseFloatError> {
+        let bytes = bytes
+            .get(..9)
+            .ok_or(ParseFloatError::InvalidLength)?
+            .try_into()
+            .unwrap();
+
+        Float::from_raw_bytes(bytes)
     }
 
     /// Given the byte representation (flags at index zero), produces a Float.
@@ -114,14 +259,14 @@ impl Float {
         let flags = Flags::from_bits(bytes[0]).ok_or(ParseFloatError::InvalidFlags)?;
         let exponent = bytes[1];
 
-        if !(Float::EXPONENT_MIN..Float::EXPONENT_MAX).contains(&exponent) {
+        if !(Float::EXPONENT_MIN..=Float::EXPONENT_MAX).contains(&exponent) {
             return Err(ParseFloatError::InvalidExponent);
         }
 
         let mut arr = [0u8; 8];
         arr.copy_from_slice(&bytes[1..]);
 
-        let mantissa = Mantissa::from(u64::from_be_bytes(arr) & Mantissa::MASK)
+        let mantissa = <Mantissa>::from(u64::from_be_bytes(arr) & <Mantissa>::MASK as u64)
             .ok_or(ParseFloatError::InvalidMantissa)?;
 
         Ok(Float {
@@ -131,24 +276,81 @@ impl Float {
         })
     }
 
+    /// Packs this Float into the low 72 bits of an integer, in the same order as
+    /// [`Float::to_raw_bytes`]:
+    ///
+    /// | bits    | field                       |
+    /// |---------|-----------------------------|
+    /// | 64..72  | flags                       |
+    /// | 56..64  | exponent, biased by `0x80`  |
+    /// | 0..56   | packed-BCD mantissa         |
+    ///
+    /// The remaining high bits are zero.
+    pub fn to_bits(&self) -> u128 {
+        ((self.flags.bits() as u128) << 64)
+            | ((self.exponent as u128) << 56)
+            | self.mantissa.bits() as u128
+    }
+
+    /// Unpacks a Float from the layout described in [`Float::to_bits`]. Any bits set above bit 72
+    /// are treated as invalid flags.
+    pub fn from_bits(bits: u128) -> Result<Self, ParseFloatError> {
+        if bits >> 72 != 0 {
+            return Err(ParseFloatError::InvalidFlags);
+        }
+
+        Float::from_raw_bytes(bits.to_be_bytes()[7..].try_into().unwrap())
+    }
+
     /// Check the validity of this Float.
-    pub fn check(self) -> Result<Self, FloatError> {
-        if (Self::EXPONENT_MIN..=Self::EXPONENT_MAX).contains(&self.exponent) {
+    pub const fn check(self) -> Result<Self, FloatError> {
+        if Self::EXPONENT_MIN <= self.exponent && self.exponent <= Self::EXPONENT_MAX {
             if self.mantissa.check() {
                 Ok(self)
             } else {
                 Err(FloatError::InvalidMantissa)
             }
+        } else if self.exponent < Self::EXPONENT_MIN {
+            Err(FloatError::Underflow)
         } else {
             Err(FloatError::Overflow)
         }
     }
-    
-    pub fn shift(&self, delta: i8) -> Float {
-        Float {
-            flags: self.flags,
-            mantissa: self.mantissa,
-            exponent: (self.exponent as i8).wrapping_add(delta) as u8
+
+    /// The unbiased exponent, widened so that exponent arithmetic can neither wrap nor panic.
+    fn wide_exponent(&self) -> i16 {
+        self.exponent as i16 - Float::EXPONENT_NORM as i16
+    }
+
+    /// Assembles the result of an operation from a widened exponent, failing if it is outside
+    /// `-99..=99`.
+    fn from_wide(flags: Flags, exponent: i16, mantissa: Mantissa) -> Result<Float, FloatError> {
+        if exponent > 99 {
+            Err(FloatError::Overflow)
+        } else if exponent < -99 {
+            Err(FloatError::Underflow)
+        } else {
+            Ok(Float {
+                flags,
+                exponent: (exponent + Float::EXPONENT_NORM as i16) as u8,
+                mantissa,
+            })
+        }
+    }
+
+    /// This Float with its exponent replaced, failing if the new one is outside `-99..=99`.
+    pub(crate) fn with_exponent(self, exponent: i16) -> Result<Float, FloatError> {
+        Float::from_wide(self.flags, exponent, self.mantissa)
+    }
+
+    /// Multiplies by `10 ^ delta` exactly, by adjusting the exponent. Fails with
+    /// [`FloatError::Overflow`] or [`FloatError::Underflow`] if the exponent leaves `-99..=99`.
+    /// Zero and undefined Floats are returned unchanged.
+    pub fn shift(&self, delta: i8) -> Result<Float, FloatError> {
+        if self.is_zero() || self.is_undefined() {
+            Ok(*self)
+        } else {
+            self.with_exponent(self.wide_exponent() + delta as i16)
         }
     }
 }
@@ -158,12 +360,46 @@ impl Float {
         self.flags.contains(Flags::NEGATIVE)
     }
 
+    /// Whether this Float is zero, whatever its sign and exponent. The arithmetic operators return
+    /// the canonical zero, [`Float::ZERO`], whenever the result is zero.
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    /// Whether the two Floats have identical byte representations, including every flag and
+    /// the sign and exponent of zeros.
+    pub fn bitwise_eq(&self, other: &Float) -> bool {
+        self.flags == other.flags
+            && self.exponent == other.exponent
+            && self.mantissa == other.mantissa
+    }
+
+    /// Whether this Float has the UNDEFINED flag set, as an uncomputed sequence term does.
+    pub fn is_undefined(&self) -> bool {
+        self.flags.contains(Flags::UNDEFINED)
+    }
+
+    #[deprecated(note = "use `set_complex_half`")]
     pub fn mark_complex_half(&mut self) {
-        self.flags &= Flags::COMPLEX_HALF;
+        self.set_complex_half();
     }
 
+    /// Marks this Float as half of a complex variable by setting bits 2 and 3 of the flags and
+    /// clearing bit 1. The sign and other flags are kept.
+    pub fn set_complex_half(&mut self) {
+        self.flags.insert(Flags::COMPLEX_HALF);
+        self.flags.remove(Flags::UNDEFINED);
+    }
+
+    /// Clears bits 2 and 3 of the flags, so this Float is no longer half of a complex variable.
+    pub fn clear_complex_half(&mut self) {
+        self.flags.remove(Flags::COMPLEX_HALF);
+    }
+
+    /// Whether this Float is half of a complex variable: bits 2 and 3 of the flags are set and
+    /// bit 1 is clear.
     pub fn is_complex_half(&self) -> bool {
-        self.flags.contains(Flags::COMPLEX_HALF)
+        self.flags.contains(Flags::COMPLEX_HALF) && !self.flags.contains(Flags::UNDEFINED)
     }
 
     /// All 14 digits of the mantissa, in order from greatest to least place-value.
@@ -184,20 +420,59 @@ impl Float {
         digits
     }
 
+    /// The power of ten by which the first digit of the mantissa is multiplied.
     pub fn exponent(&self) -> i8 {
         (self.exponent as i8).wrapping_add(Float::EXPONENT_NORM as i8)
     }
+
+    /// All 14 digits of the mantissa, in order from greatest to least place-value.
+    pub fn mantissa_digits(&self) -> [u8; 14] {
+        self.mantissa.digits().try_into().unwrap()
+    }
+
+    /// The mantissa as a 14-digit decimal integer, ex. `31415926535898` for π.
+    pub fn significand_dec(&self) -> u64 {
+        self.mantissa.to_dec() as u64
+    }
+
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+}
+
+impl PartialEq for Float {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Float {}
+
+/// Hashes the value compared by `Eq`, so Floats that differ only in ignored flags or in the sign
+/// and exponent of zero hash alike.
+impl Hash for Float {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // every undefined Float is equal, whatever else its bytes hold
+        self.is_undefined().hash(state);
+        if !self.is_undefined() {
+            self.measure().hash(state);
+        }
+    }
 }
 
 impl PartialOrd for Float {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.measure().partial_cmp(&other.measure())
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Float {
+    /// Orders by value, with undefined Floats equal to one another and after every number.
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        match (self.is_undefined(), other.is_undefined()) {
+            (false, false) => self.measure().cmp(&other.measure()),
+            (undefined, other_undefined) => undefined.cmp(&other_undefined),
+        }
     }
 }
 
@@ -205,6 +480,10 @@ impl Neg for Float {
     type Output = Float;
 
     fn neg(self) -> Float {
+        if self.is_zero() {
+            return self;
+        }
+
         Float {
             flags: self.flags ^ Flags::NEGATIVE,
             ..self
@@ -212,63 +491,132 @@ impl Neg for Float {
     }
 }
 
-impl Add<Float> for Float {
-    type Output = Result<Float, FloatError>;
+/// # Arithmetic with explicit rounding
+///
+/// The operators round half-up, as the TI-OS does. Each operation rounds once: the smaller operand
+/// of a sum keeps guard digits as it is aligned to the larger, so the exact sum is rounded.
+///
+/// Every result is rounded to 14 digits, so unlike the calculator, which keeps guard digits
+/// between the steps of an expression, `1/3*3` is `.99999999999999`. Chains of
+/// [`GuardedFloat`](crate::GuardedFloat)s keep those digits.
+impl Float {
+    /// Adds two floats, rounding the result according to `mode`.
+    pub fn add_with_rounding(self, rhs: Float, mode: RoundingMode) -> Result<Float, FloatError> {
+        if self.is_undefined() || rhs.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() && rhs.is_zero() {
+            return Ok(Float::ZERO);
+        } else if self.is_zero() {
+            return Ok(rhs);
+        } else if rhs.is_zero() {
+            return Ok(self);
+        }
+
+        trace!(crate::trace::Step::Operation {
+            name: "add",
+            lhs: self,
+            rhs,
+        });
 
-    fn add(self, rhs: Float) -> Self::Output {
         let (a, b) = if self.exponent < rhs.exponent {
             (rhs, self)
         } else {
             (self, rhs)
         };
 
-        let b_mantissa = b.mantissa.shr(a.exponent - b.exponent);
-        let mut exponent = a.exponent;
+        let Some((mantissa, shift, borrowed)) = a.mantissa.rounded_sum(
+            b.mantissa,
+            (a.exponent - b.exponent) as u32,
+            a.is_negative() != b.is_negative(),
+            mode,
+        ) else {
+            return Ok(Float::ZERO);
+        };
 
-        if a.is_negative() == b.is_negative() {
-            // add mantissas
-            let (mut mantissa, overflow) = a.mantissa.overflowing_add(b_mantissa);
+        let exponent = a.wide_exponent() + shift as i16;
+        trace!(crate::trace::Step::Normalize {
+            shift: -shift as i8,
+            exponent,
+        });
 
-            if overflow {
-                exponent += 1;
+        let flags = if borrowed {
+            a.flags ^ Flags::NEGATIVE
+        } else {
+            a.flags
+        };
 
-                mantissa = mantissa.shr(1) + Mantissa::ONE;
-            }
+        Float::from_wide(flags, exponent, mantissa)
+    }
 
-            if exponent > Float::EXPONENT_MAX {
-                Err(FloatError::Overflow)
-            } else {
-                Ok(Float {
-                    flags: a.flags,
-                    exponent,
-                    mantissa,
-                })
-            }
-        } else {
-            // subtract mantissas
-            let (mut mantissa, overflow) = a.mantissa.overflowing_sub(b_mantissa);
+    /// Subtracts two floats, rounding the result according to `mode`.
+    pub fn sub_with_rounding(self, rhs: Float, mode: RoundingMode) -> Result<Float, FloatError> {
+        self.add_with_rounding(-rhs, mode)
+    }
 
-            let mut flags = a.flags;
-            if overflow {
-                flags ^= Flags::NEGATIVE;
-            }
+    /// Multiplies two floats, rounding the result according to `mode`.
+    pub fn mul_with_rounding(self, rhs: Float, mode: RoundingMode) -> Result<Float, FloatError> {
+        if self.is_undefined() || rhs.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() || rhs.is_zero() {
+            return Ok(Float::ZERO);
+        }
 
-            while mantissa.msd() == 0 {
-                exponent -= 1;
+        trace!(crate::trace::Step::Operation {
+            name: "multiply",
+            lhs: self,
+            rhs,
+        });
 
-                mantissa = mantissa.shl(1);
-            }
+        let exponent = self.wide_exponent() + rhs.wide_exponent();
 
-            if exponent < Float::EXPONENT_MIN {
-                Err(FloatError::Overflow)
-            } else {
-                Ok(Float {
-                    flags,
-                    exponent,
-                    mantissa,
-                })
-            }
+        let (mantissa, shift) = self.mantissa.overflowing_mul(rhs.mantissa, mode);
+        let exponent = exponent + shift as i16;
+        trace!(crate::trace::Step::Normalize {
+            shift: -(shift as i8),
+            exponent,
+        });
+
+        let flags = self.flags ^ (rhs.flags & Flags::NEGATIVE);
+
+        Float::from_wide(flags, exponent, mantissa)
+    }
+
+    /// Divides two floats, rounding the result according to `mode`.
+    pub fn div_with_rounding(self, rhs: Float, mode: RoundingMode) -> Result<Float, FloatError> {
+        if self.is_undefined() || rhs.is_undefined() {
+            return Ok(Float::undefined());
+        } else if rhs.is_zero() {
+            return Err(FloatError::DivideByZero);
+        } else if self.is_zero() {
+            return Ok(Float::ZERO);
         }
+
+        trace!(crate::trace::Step::Operation {
+            name: "divide",
+            lhs: self,
+            rhs,
+        });
+
+        // the quotient is at least one if the dividend's mantissa is at least the divisor's, and
+        // one place lower otherwise
+        let (mantissa, shift) = self.mantissa.overflowing_div(rhs.mantissa, mode);
+        let exponent = self.wide_exponent() - rhs.wide_exponent() - 1 + shift as i16;
+        trace!(crate::trace::Step::Normalize {
+            shift: 1 - shift as i8,
+            exponent,
+        });
+
+        let flags = self.flags ^ (rhs.flags & Flags::NEGATIVE);
+
+        Float::from_wide(flags, exponent, mantissa)
+    }
+}
+
+impl Add<Float> for Float {
+    type Output = Result<Float, FloatError>;
+
+    fn add(self, rhs: Float) -> Self::Output {
+        self.add_with_rounding(rhs, RoundingMode::HalfUp)
     }
 }
 
@@ -276,7 +624,7 @@ impl Sub<Float> for Float {
     type Output = Result<Float, FloatError>;
 
     fn sub(self, rhs: Float) -> Self::Output {
-        self + -rhs
+        self.sub_with_rounding(rhs, RoundingMode::HalfUp)
     }
 }
 
@@ -284,73 +632,136 @@ impl Mul for Float {
     type Output = Result<Float, FloatError>;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let mut exponent = self.exponent + rhs.exponent - Float::EXPONENT_NORM;
+        self.mul_with_rounding(rhs, RoundingMode::HalfUp)
+    }
+}
 
-        let (mut mantissa, shift) = self.mantissa.overflowing_mul(rhs.mantissa);
+impl Div for Float {
+    type Output = Result<Float, FloatError>;
 
-        if shift {
-            exponent += 1;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_with_rounding(rhs, RoundingMode::HalfUp)
+    }
+}
 
-            mantissa = mantissa.shr(1);
+/// Implements an assignment operator in terms of the corresponding `Result`-returning operator.
+///
+/// On a `Float`, the assignment panics if the operation fails, much like integer overflow does in
+/// debug builds. On a `Result<Float, FloatError>`, the error is stored instead, and an existing
+/// error is kept, so a whole accumulation can be checked once at the end.
+macro_rules! assign_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl $trait for Float {
+            fn $method(&mut self, rhs: Float) {
+                *self = match *self $op rhs {
+                    Ok(result) => result,
+                    Err(err) => panic!(
+                        "attempt to {} with {err:?}",
+                        stringify!($method).trim_end_matches("_assign")
+                    ),
+                };
+            }
         }
 
-        if !(Float::EXPONENT_MIN..Float::EXPONENT_MAX).contains(&exponent) {
-            Err(FloatError::Overflow)
-        } else {
-            Ok(Float {
-                flags: self.flags ^ (rhs.flags & Flags::NEGATIVE),
-                exponent,
-                mantissa,
-            })
+        impl $trait<Float> for Result<Float, FloatError> {
+            fn $method(&mut self, rhs: Float) {
+                if let Ok(float) = *self {
+                    *self = float $op rhs;
+                }
+            }
         }
+    };
+}
+
+assign_op!(AddAssign, add_assign, +);
+assign_op!(SubAssign, sub_assign, -);
+assign_op!(MulAssign, mul_assign, *);
+assign_op!(DivAssign, div_assign, /);
+
+impl Neg for &Float {
+    type Output = Float;
+
+    fn neg(self) -> Float {
+        -*self
     }
 }
 
-impl Div for Float {
-    type Output = Result<Float, FloatError>;
+/// Implements a binary operator for every combination of `Float` and `&Float` operands in terms
+/// of the by-value implementation.
+macro_rules! ref_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait<&Float> for Float {
+            type Output = Result<Float, FloatError>;
 
-    fn div(self, rhs: Self) -> Self::Output {
-        let exponent = self.exponent - rhs.exponent + Float::EXPONENT_NORM;
+            fn $method(self, rhs: &Float) -> Self::Output {
+                self.$method(*rhs)
+            }
+        }
 
-        let (mut mantissa, needs_norm) = self.mantissa.overflowing_div(rhs.mantissa);
+        impl $trait<Float> for &Float {
+            type Output = Result<Float, FloatError>;
 
-        if needs_norm {
-            mantissa = mantissa.shr(1);
+            fn $method(self, rhs: Float) -> Self::Output {
+                (*self).$method(rhs)
+            }
         }
 
-        if !(Float::EXPONENT_MIN..Float::EXPONENT_MAX).contains(&exponent) {
-            Err(FloatError::Overflow)
-        } else {
-            Ok(Float {
-                flags: self.flags,
-                exponent,
-                mantissa,
-            })
+        impl $trait<&Float> for &Float {
+            type Output = Result<Float, FloatError>;
+
+            fn $method(self, rhs: &Float) -> Self::Output {
+                (*self).$method(*rhs)
+            }
         }
-    }
+    };
 }
 
+ref_op!(Add, add);
+ref_op!(Sub, sub);
+ref_op!(Mul, mul);
+ref_op!(Div, div);
+
+pub(crate) use ref_op;
+
+/// Shows the sign, all 14 digits, the exponent, and any flags, such as
+/// `Float(-3.1415926535898e0, flags: NEGATIVE)`. The digits are shown as stored, even if they are
+/// invalid.
 impl Debug for Float {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.flags.contains(Flags::NEGATIVE) {
+        f.write_str("Float(")?;
+        if self.is_negative() {
             f.write_str("-")?;
         }
 
-        f.write_str(&format!(
-            "0x{} * 10 ^ {}",
-            self.mantissa.to_dec().to_string(),
-            self.exponent()
-        ))
+        let digits = self.mantissa.digits();
+        write!(f, "{}.", digits[0])?;
+        for digit in &digits[1..] {
+            write!(f, "{digit}")?;
+        }
+        write!(f, "e{}", self.exponent())?;
+
+        if !self.flags.is_empty() {
+            f.write_str(", flags: ")?;
+            bitflags::parser::to_writer(&self.flags, &mut *f)?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+impl Default for Float {
+    fn default() -> Self {
+        Float::ZERO
     }
 }
 
 impl From<u64> for Float {
     fn from(value: u64) -> Self {
         if value == 0 {
-            Float::new_unchecked(false, 0, 0)
+            Float::ZERO
         } else {
             let exp = value.ilog10() as i8;
-            let mantissa = Mantissa::from_dec_normalized(value);
+            let mantissa = <Mantissa>::from_dec_normalized(value as u128);
 
             Float::new_unchecked(false, exp, mantissa.0.bits())
         }
@@ -387,6 +798,7 @@ mod tests {
             (&neg_large, &neg_large, tifloat!(-0x10000000000000 * 10 ^ 6)),
             (&large, &neg_small, tifloat!(0x45000000000000 * 10 ^ 5)),
             (&neg_large, &small, tifloat!(-0x45000000000000 * 10 ^ 5)),
+            (&large, &tifloat!(-0x10000000000000 * 10 ^ -20), large),
         ];
 
         for combination in combinations {
@@ -397,6 +809,346 @@ mod tests {
         }
     }
 
+    #[test]
+    fn assign_ops() {
+        let mut float = tifloat!(0x50000000000000 * 10 ^ 4);
+        float += tifloat!(0x50000000000000 * 10 ^ 5);
+        assert_eq!(float, tifloat!(0x55000000000000 * 10 ^ 5));
+
+        float -= tifloat!(0x50000000000000 * 10 ^ 4);
+        assert_eq!(float, tifloat!(0x50000000000000 * 10 ^ 5));
+
+        let mut result = Ok(tifloat!(0x50000000000000 * 10 ^ 99));
+        result += tifloat!(0x50000000000000 * 10 ^ 99);
+        assert!(matches!(result, Err(FloatError::Overflow)));
+
+        result -= tifloat!(0x50000000000000 * 10 ^ 99);
+        assert!(matches!(result, Err(FloatError::Overflow)));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with Overflow")]
+    fn assign_op_overflow() {
+        let mut float = tifloat!(0x50000000000000 * 10 ^ 99);
+        float += tifloat!(0x50000000000000 * 10 ^ 99);
+    }
+
+    #[test]
+    fn zero() {
+        let float = tifloat!(0x50000000000000 * 10 ^ -50);
+
+        assert!(Float::ZERO.is_zero());
+        assert!(!float.is_zero());
+        assert_eq!((float - float).unwrap(), Float::ZERO);
+        assert_eq!((Float::ZERO + float).unwrap(), float);
+        assert_eq!((float + Float::ZERO).unwrap(), float);
+        assert_eq!((Float::ZERO * float).unwrap(), Float::ZERO);
+        assert_eq!((Float::ZERO / float).unwrap(), Float::ZERO);
+        assert_eq!(-Float::ZERO, Float::ZERO);
+    }
+
+    #[test]
+    fn extreme_exponents() {
+        let big = tifloat!(0x50000000000000 * 10 ^ 99);
+        let small = tifloat!(0x50000000000000 * 10 ^ -99);
+
+        assert!(matches!(big * big, Err(FloatError::Overflow)));
+        assert!(matches!(small * small, Err(FloatError::Underflow)));
+        assert!(matches!(big / small, Err(FloatError::Overflow)));
+        assert!(matches!(small / big, Err(FloatError::Underflow)));
+        assert_eq!(
+            (big * tifloat!(0x10000000000000 * 10 ^ -99)).unwrap(),
+            tifloat!(0x50000000000000 * 10 ^ 0)
+        );
+        assert_eq!((Float::MAX / Float::ONE).unwrap(), Float::MAX);
+        assert_eq!(
+            Float::from_raw_bytes(Float::MAX.to_raw_bytes()),
+            Ok(Float::MAX)
+        );
+    }
+
+    #[test]
+    fn shift() {
+        let float = tifloat!(-0x12500000000000 * 10 ^ 98);
+
+        assert_eq!(float.shift(-100), Ok(tifloat!(-0x12500000000000 * 10 ^ -2)));
+        assert_eq!(float.shift(1), Ok(tifloat!(-0x12500000000000 * 10 ^ 99)));
+        assert_eq!(float.shift(2), Err(FloatError::Overflow));
+        assert_eq!(Float::MIN_POSITIVE.shift(-1), Err(FloatError::Underflow));
+        assert_eq!(Float::ZERO.shift(i8::MAX), Ok(Float::ZERO));
+    }
+
+    #[test]
+    fn rounding_modes() {
+        let one = Float::ONE;
+        let half_ulp = tifloat!(0x50000000000000 * 10 ^ -14);
+
+        assert_eq!(
+            one.add_with_rounding(half_ulp, RoundingMode::HalfUp)
+                .unwrap(),
+            tifloat!(0x10000000000001 * 10 ^ 0)
+        );
+        assert_eq!(
+            one.add_with_rounding(half_ulp, RoundingMode::HalfEven)
+                .unwrap(),
+            one
+        );
+        assert_eq!(
+            one.sub_with_rounding(
+                -tifloat!(0x90000000000000 * 10 ^ -14),
+                RoundingMode::Truncate
+            )
+            .unwrap(),
+            one
+        );
+
+        // the smaller operand is rounded only once the difference is known
+        let tiny = tifloat!(0x10000000000000 * 10 ^ -20);
+        let below_one = tifloat!(0x99999999999999 * 10 ^ -1);
+        assert_eq!(
+            one.sub_with_rounding(tiny, RoundingMode::Truncate),
+            Ok(below_one)
+        );
+        assert_eq!(one.sub_with_rounding(tiny, RoundingMode::HalfUp), Ok(one));
+        for mode in [
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+            RoundingMode::Truncate,
+        ] {
+            assert_eq!(
+                one.sub_with_rounding(below_one, mode),
+                Ok(tifloat!(0x10000000000000 * 10 ^ -14))
+            );
+        }
+
+        let a = tifloat!(0x25000000000000 * 10 ^ 0);
+        let b = tifloat!(0x10000000000001 * 10 ^ 0);
+
+        assert_eq!((a * b).unwrap(), tifloat!(0x25000000000003 * 10 ^ 0));
+        assert_eq!(
+            a.mul_with_rounding(b, RoundingMode::HalfEven).unwrap(),
+            tifloat!(0x25000000000002 * 10 ^ 0)
+        );
+
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+
+        assert_eq!((two / three).unwrap(), tifloat!(0x66666666666667 * 10 ^ -1));
+        assert_eq!(
+            two.div_with_rounding(three, RoundingMode::Truncate)
+                .unwrap(),
+            tifloat!(0x66666666666666 * 10 ^ -1)
+        );
+    }
+
+    #[test]
+    fn mul_div() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+
+        assert_eq!((two * three).unwrap(), tifloat!(0x60000000000000 * 10 ^ 0));
+        assert_eq!(
+            (-two * three).unwrap(),
+            tifloat!(-0x60000000000000 * 10 ^ 0)
+        );
+        assert_eq!((three / two).unwrap(), tifloat!(0x15000000000000 * 10 ^ 0));
+        assert_eq!(
+            (two / -three).unwrap(),
+            tifloat!(-0x66666666666667 * 10 ^ -1)
+        );
+        assert_eq!(
+            (Float::ONE / three).unwrap(),
+            tifloat!(0x33333333333333 * 10 ^ -1)
+        );
+    }
+
+    #[test]
+    fn div_sign_and_exponent() {
+        let six = tifloat!(0x60000000000000 * 10 ^ 0);
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+
+        // the quotient takes the sign of both operands
+        for (a, b, quotient) in [
+            (six, three, two),
+            (-six, three, -two),
+            (six, -three, -two),
+            (-six, -three, two),
+        ] {
+            assert_eq!(a / b, Ok(quotient), "{a:?} / {b:?}");
+        }
+
+        // a smaller dividend mantissa puts the quotient one place lower
+        assert_eq!(three / six, Ok(tifloat!(0x50000000000000 * 10 ^ -1)));
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 5) / tifloat!(-0x40000000000000 * 10 ^ -3),
+            Ok(tifloat!(-0x25000000000000 * 10 ^ 7))
+        );
+
+        // quotients at the ends of the exponent range
+        assert_eq!(
+            tifloat!(0x50000000000000 * 10 ^ 99) / tifloat!(0x50000000000000 * 10 ^ 0),
+            Ok(tifloat!(0x10000000000000 * 10 ^ 99))
+        );
+        assert_eq!(
+            tifloat!(0x50000000000000 * 10 ^ 99) / tifloat!(0x50000000000000 * 10 ^ -1),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            Float::MIN_POSITIVE / tifloat!(-0x10000000000000 * 10 ^ 0),
+            Ok(-Float::MIN_POSITIVE)
+        );
+        assert_eq!(
+            Float::MIN_POSITIVE / tifloat!(0x20000000000000 * 10 ^ 0),
+            Err(FloatError::Underflow)
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        // ascending, covering every combination of signs and of exponents above and below zero
+        let floats = [
+            -Float::MAX,
+            tifloat!(-0x10000000000000 * 10 ^ 1),
+            tifloat!(-0x20000000000000 * 10 ^ 0),
+            Float::NEG_ONE,
+            tifloat!(-0x50000000000000 * 10 ^ -1),
+            -Float::MIN_POSITIVE,
+            Float::ZERO,
+            Float::MIN_POSITIVE,
+            tifloat!(0x50000000000000 * 10 ^ -1),
+            Float::ONE,
+            tifloat!(0x20000000000000 * 10 ^ 0),
+            tifloat!(0x10000000000000 * 10 ^ 1),
+            Float::MAX,
+        ];
+
+        for (i, a) in floats.iter().enumerate() {
+            for (j, b) in floats.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "{a:?} {b:?}");
+            }
+        }
+
+        let mut shuffled = floats;
+        shuffled.reverse();
+        shuffled.swap(2, 9);
+        shuffled.sort();
+        assert_eq!(shuffled, floats);
+    }
+
+    #[test]
+    fn ordering_zeros() {
+        let negative_zero = Float::new_unchecked(true, 0, 0);
+        let small_zero = Float::new_unchecked(false, -99, 0);
+
+        assert_eq!(negative_zero.cmp(&Float::ZERO), Ordering::Equal);
+        assert_eq!(small_zero.cmp(&Float::ZERO), Ordering::Equal);
+        assert!(small_zero < Float::MIN_POSITIVE);
+        assert!(negative_zero > -Float::MIN_POSITIVE);
+    }
+
+    #[test]
+    fn complex_half() {
+        let mut float =
+            tifloat!(-0x12500000000000 * 10 ^ 3).with_flags(Flags::NEGATIVE | Flags::IDK);
+        assert!(!float.is_complex_half());
+
+        float.set_complex_half();
+        assert!(float.is_complex_half());
+        assert_eq!(float.to_raw_bytes()[0], 0xCC);
+
+        float.clear_complex_half();
+        assert!(!float.is_complex_half());
+        assert_eq!(float.to_raw_bytes()[0], 0xC0);
+
+        let mut undefined = Float::undefined();
+        assert!(!undefined
+            .with_flags(Flags::UNDEFINED | Flags::COMPLEX_HALF)
+            .is_complex_half());
+
+        undefined.set_complex_half();
+        assert!(undefined.is_complex_half());
+        assert_eq!(undefined.to_raw_bytes()[0], 0x0C);
+    }
+
+    #[test]
+    fn value_equality() {
+        let float = tifloat!(-0x12500000000000 * 10 ^ 3);
+        let flagged = float.with_flags(Flags::NEGATIVE | Flags::IDK | Flags::COMPLEX_HALF);
+        let negative_zero = Float::new_unchecked(true, 0, 0);
+
+        assert_eq!(float, flagged);
+        assert!(!float.bitwise_eq(&flagged));
+        assert_eq!(negative_zero, Float::ZERO);
+        assert!(!negative_zero.bitwise_eq(&Float::ZERO));
+        assert_ne!(float, -float);
+
+        let flagged_undefined =
+            Float::from_raw_bytes([0x02, 0x80, 0x10, 0, 0, 0, 0, 0, 0]).unwrap();
+        assert_eq!(Float::undefined(), Float::undefined());
+        assert_eq!(Float::undefined(), flagged_undefined);
+        assert_eq!(Float::undefined().cmp(&-flagged_undefined), Ordering::Equal);
+        assert_ne!(Float::undefined(), Float::ZERO);
+        assert!(Float::undefined() > Float::MAX);
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let float = tifloat!(0x12500000000000 * 10 ^ 3);
+        let set = HashSet::from([float, Float::ZERO, Float::undefined()]);
+
+        assert!(set.contains(&float.with_flags(Flags::IDK)));
+        assert!(set.contains(&Float::new_unchecked(true, -5, 0)));
+        assert!(set.contains(&Float::undefined()));
+        assert!(
+            set.contains(&Float::from_raw_bytes([0x02, 0x85, 0x12, 0x50, 0, 0, 0, 0, 0]).unwrap())
+        );
+        assert!(!set.contains(&-float));
+    }
+
+    #[test]
+    fn undefined() {
+        let undefined = Float::undefined();
+
+        assert!(undefined.is_undefined());
+        assert!(!Float::ZERO.is_undefined());
+        assert!((undefined + Float::ONE).unwrap().is_undefined());
+        assert!((Float::ONE - undefined).unwrap().is_undefined());
+        assert!((undefined * Float::ZERO).unwrap().is_undefined());
+        assert!((Float::ONE / undefined).unwrap().is_undefined());
+        assert!((undefined / Float::ZERO).unwrap().is_undefined());
+    }
+
+    #[test]
+    fn div_by_zero() {
+        assert!(matches!(
+            Float::ONE / Float::ZERO,
+            Err(FloatError::DivideByZero)
+        ));
+        assert!(matches!(
+            Float::ZERO / -Float::ZERO,
+            Err(FloatError::DivideByZero)
+        ));
+    }
+
+    #[test]
+    fn ref_ops() {
+        fn difference<'a, T>(a: &'a T, b: &'a T) -> Result<Float, FloatError>
+        where
+            &'a T: Sub<Output = Result<Float, FloatError>>,
+        {
+            a - b
+        }
+
+        let a = tifloat!(0x50000000000000 * 10 ^ 5);
+        let b = tifloat!(0x50000000000000 * 10 ^ 4);
+
+        assert_eq!(difference(&a, &b).unwrap(), (a - b).unwrap());
+        assert_eq!(-&a, -a);
+    }
+
     #[test]
     fn raw_bytes() {
         let float = tifloat!(-0x55000000000000 * 10 ^ 5);
@@ -405,6 +1157,31 @@ mod tests {
 
         assert_eq!(float.to_raw_bytes(), repr);
         assert_eq!(Float::from_raw_bytes(repr).ok().unwrap(), float);
+
+        let mut buffer = [0xFF; 12];
+        float.write_raw_bytes((&mut buffer[2..11]).try_into().unwrap());
+        assert_eq!(buffer[2..11], repr);
+        assert_eq!(Float::from_raw_slice(&buffer[2..]), Ok(float));
+        assert_eq!(
+            Float::from_raw_slice(&buffer[4..]),
+            Err(ParseFloatError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn bits() {
+        let float = tifloat!(-0x55000000000000 * 10 ^ 5);
+
+        assert_eq!(float.to_bits(), 0x808555000000000000);
+        assert_eq!(Float::from_bits(0x808555000000000000), Ok(float));
+        assert_eq!(
+            Float::from_bits(1 << 72 | 0x808555000000000000),
+            Err(ParseFloatError::InvalidFlags)
+        );
+        assert_eq!(
+            Float::from_bits(0x00805A000000000000),
+            Err(ParseFloatError::InvalidMantissa)
+        );
     }
 
     #[test]
@@ -426,17 +1203,70 @@ mod tests {
     #[test]
     fn debug() {
         assert_eq!(
-            "0x50000000000000 * 10 ^ 5",
+            "Float(5.0000000000000e5)",
             format!("{:?}", tifloat!(0x50000000000000 * 10 ^ 5))
         );
         assert_eq!(
-            "-0x50000000000000 * 10 ^ 5",
-            format!("{:?}", tifloat!(-0x50000000000000 * 10 ^ 5))
+            "Float(-3.1415926535898e0, flags: NEGATIVE)",
+            format!("{:?}", -Float::PI)
+        );
+        assert_eq!(
+            "Float(5.0000000000000e-5, flags: IDK)",
+            format!(
+                "{:?}",
+                tifloat!(0x50000000000000 * 10 ^ -5).with_flags(Flags::IDK)
+            )
+        );
+        assert_eq!(
+            "Float(0.0000000000000e0, flags: UNDEFINED)",
+            format!("{:?}", Float::undefined())
+        );
+        assert_eq!(
+            "Float(-1.0000000000000e0, flags: NEGATIVE | 0x1)",
+            format!(
+                "{:?}",
+                Float::NEG_ONE.with_flags(Flags::from_bits_retain(0x81))
+            )
         );
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Float::default(), Float::ZERO);
+        assert_eq!(Float::ONE, Float::from(1));
+        assert_eq!(Float::NEG_ONE, -Float::ONE);
+        assert_eq!("3.1415926535898".parse(), Ok(Float::PI));
+        assert_eq!("2.718281828459".parse(), Ok(Float::E));
+        assert_eq!("9.9999999999999E99".parse(), Ok(Float::MAX));
+        assert_eq!("1E-99".parse(), Ok(Float::MIN_POSITIVE));
+
+        for constant in [Float::MAX, Float::MIN_POSITIVE] {
+            assert!(constant.check().is_ok(), "{constant:?}");
+        }
+    }
+
+    #[test]
+    fn const_macro() {
+        const HALF: Float = tifloat!(0x50000000000000 * 10 ^ -1);
+
+        assert_eq!(HALF, Float::new(false, -1, 0x50000000000000).ok().unwrap());
+        assert!(matches!(
+            Float::new(false, 0, 0x5A000000000000),
+            Err(FloatError::InvalidMantissa)
+        ));
+    }
+
+    #[test]
+    fn accessors() {
+        let float = tifloat!(-0x31415926535898 * 10 ^ -5);
+
+        assert_eq!(float.exponent(), -5);
         assert_eq!(
-            "0x50000000000000 * 10 ^ -5",
-            format!("{:?}", tifloat!(0x50000000000000 * 10 ^ -5))
+            float.mantissa_digits(),
+            [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 8]
         );
+        assert_eq!(float.significand_dec(), 31415926535898);
+        assert_eq!(float.flags(), Flags::NEGATIVE);
     }
 
     #[test]
@@ -0,0 +1,628 @@
+use std::fmt::{Display, Formatter};
+
+use crate::mantissa::Mantissa;
+use crate::Float;
+
+/// The number of digits the TI-OS shows for a number.
+pub const DISPLAY_DIGITS: usize = 10;
+
+/// In Normal mode, numbers with an exponent smaller than this are displayed in scientific
+/// notation (`.001` is displayed as-is but `.0001` is displayed as `1E-4`).
+pub const NORMAL_MIN_EXPONENT: i16 = -3;
+
+/// In Normal mode, numbers with an exponent larger than this are displayed in scientific
+/// notation (`9999999999` is displayed as-is but `10000000000` is displayed as `1E10`).
+pub const NORMAL_MAX_EXPONENT: i16 = DISPLAY_DIGITS as i16 - 1;
+
+/// The largest number of decimal places supported by the Fix modes.
+pub const MAX_FIX_DIGITS: u8 = 9;
+
+/// How an undefined Float is written, since it has no digits to show. It parses back to an
+/// undefined Float.
+pub const UNDEFINED: &str = "undefined";
+
+/// How the exponent of a number is displayed, corresponding to the first line of the mode screen.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum DisplayMode {
+    /// Positional notation, switching to scientific notation for very large or small numbers.
+    #[default]
+    Normal,
+    /// Scientific notation, with one digit before the decimal point.
+    Sci,
+    /// Engineering notation, with the exponent a multiple of three.
+    Eng,
+}
+
+/// How many decimal places are displayed, corresponding to the second line of the mode screen.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum DecimalMode {
+    /// As many decimal places as fit in the displayed digits, without trailing zeros.
+    #[default]
+    Float,
+    /// A fixed number of decimal places (at most [`MAX_FIX_DIGITS`]), padded with trailing zeros.
+    Fix(u8),
+}
+
+impl DecimalMode {
+    fn fixed_digits(&self) -> Option<usize> {
+        match self {
+            DecimalMode::Float => None,
+            DecimalMode::Fix(digits) => Some((*digits).min(MAX_FIX_DIGITS) as usize),
+        }
+    }
+}
+
+/// Every setting which affects how the TI-OS displays a number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub display_mode: DisplayMode,
+    pub decimal_mode: DecimalMode,
+    /// The character written between the integer and fractional parts.
+    pub decimal_separator: char,
+    /// If present, the character written between each group of three integer digits, ex.
+    /// `1 234 567`.
+    pub group_separator: Option<char>,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            display_mode: DisplayMode::Normal,
+            decimal_mode: DecimalMode::Float,
+            decimal_separator: '.',
+            group_separator: None,
+        }
+    }
+}
+
+impl FormatOptions {
+    pub fn new(display_mode: DisplayMode, decimal_mode: DecimalMode) -> Self {
+        FormatOptions {
+            display_mode,
+            decimal_mode,
+            ..Default::default()
+        }
+    }
+}
+
+/// Digits of a Float after rounding for display, MSD first. The exponent is the place-value of
+/// the first digit, and may differ from the exponent of the original Float if rounding carried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RoundedDigits {
+    pub digits: Vec<u8>,
+    pub exponent: i16,
+}
+
+impl RoundedDigits {
+    /// Round the mantissa of `float` half-up to `count` significant digits. Trailing zeros are
+    /// removed.
+    ///
+    /// A nonpositive `count` rounds to a place-value above the MSD, which produces either zero or
+    /// a single `1` digit.
+    pub fn new(float: &Float, count: isize) -> Self {
+        let mut digits = float.digits();
+        let mut exponent = float.exponent() as i16;
+
+        if float.is_zero() {
+            return RoundedDigits::zero();
+        }
+
+        let (count, round_up) = if count < 0 {
+            (0, false)
+        } else {
+            let count = (count as usize).min(digits.len());
+            (count, digits.get(count).is_some_and(|&digit| digit >= 5))
+        };
+        digits.truncate(count);
+
+        if round_up {
+            let mut index = count;
+            loop {
+                if index == 0 {
+                    digits.insert(0, 1);
+                    digits.truncate(count.max(1));
+                    exponent += 1;
+                    break;
+                }
+
+                index -= 1;
+                if digits[index] == 9 {
+                    digits[index] = 0;
+                } else {
+                    digits[index] += 1;
+                    break;
+                }
+            }
+        }
+
+        match digits.iter().rposition(|&x| x != 0) {
+            Some(index) => {
+                digits.truncate(index + 1);
+
+                RoundedDigits { digits, exponent }
+            }
+            None => RoundedDigits::zero(),
+        }
+    }
+
+    fn zero() -> Self {
+        RoundedDigits {
+            digits: vec![0],
+            exponent: 0,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits == [0]
+    }
+
+    /// Round `float` to the digits shown in Normal mode, if it would be shown in Normal mode
+    /// (rather than automatically switching to scientific notation).
+    pub fn normal(float: &Float, fixed_digits: Option<usize>) -> Option<Self> {
+        let exponent = float.exponent() as i16;
+
+        if float.is_zero() {
+            return Some(RoundedDigits::zero());
+        }
+
+        // numbers just below the minimum exponent may round up into the displayable range.
+        if !(NORMAL_MIN_EXPONENT - 1..=NORMAL_MAX_EXPONENT).contains(&exponent) {
+            return None;
+        }
+
+        // Normal mode shows a fixed number of positions, so leading zeros after the decimal
+        // point take up space that would otherwise be used for significant digits.
+        let available = DISPLAY_DIGITS as isize + exponent.min(-1) as isize + 1;
+        let count = match fixed_digits {
+            Some(fixed_digits) => available.min(exponent as isize + 1 + fixed_digits as isize),
+            None => available,
+        };
+        let rounded = RoundedDigits::new(float, count);
+
+        // Fix modes switch to scientific notation rather than displaying a nonzero number as zero.
+        if !rounded.is_zero()
+            && (NORMAL_MIN_EXPONENT..=NORMAL_MAX_EXPONENT).contains(&rounded.exponent)
+        {
+            Some(rounded)
+        } else {
+            None
+        }
+    }
+
+    /// Render the digits positionally, ex. `123.45` or `.00123`, with at least `fraction_digits`
+    /// digits after the decimal point.
+    pub fn write_positional(&self, output: &mut String, fraction_digits: usize, separator: char) {
+        if self.is_zero() {
+            output.push('0');
+            if fraction_digits > 0 {
+                output.push(separator);
+                output.extend(std::iter::repeat_n('0', fraction_digits));
+            }
+        } else if self.exponent < 0 {
+            let leading_zeros = (-self.exponent - 1) as usize;
+
+            output.push(separator);
+            output.extend(std::iter::repeat_n('0', leading_zeros));
+            self.write_digits(output, 0..self.digits.len());
+            output.extend(std::iter::repeat_n(
+                '0',
+                fraction_digits.saturating_sub(leading_zeros + self.digits.len()),
+            ));
+        } else {
+            let integer_length = self.exponent as usize + 1;
+            let written_integer_digits = integer_length.min(self.digits.len());
+
+            self.write_digits(output, 0..written_integer_digits);
+            output.extend(std::iter::repeat_n(
+                '0',
+                integer_length - written_integer_digits,
+            ));
+
+            let written_fraction_digits = self.digits.len() - written_integer_digits;
+            if written_fraction_digits.max(fraction_digits) > 0 {
+                output.push(separator);
+                self.write_digits(output, written_integer_digits..self.digits.len());
+                output.extend(std::iter::repeat_n(
+                    '0',
+                    fraction_digits.saturating_sub(written_fraction_digits),
+                ));
+            }
+        }
+    }
+
+    /// Render the digits in scientific notation, ex. `1.2345E-4`, with at least
+    /// `fraction_digits` digits after the decimal point. In engineering notation, the exponent is
+    /// chosen to be a multiple of three.
+    pub fn write_scientific(
+        &self,
+        output: &mut String,
+        engineering: bool,
+        fraction_digits: usize,
+        separator: char,
+    ) {
+        let exponent = if engineering {
+            self.exponent.div_euclid(3) * 3
+        } else {
+            self.exponent
+        };
+
+        RoundedDigits {
+            digits: self.digits.clone(),
+            exponent: self.exponent - exponent,
+        }
+        .write_positional(output, fraction_digits, separator);
+
+        output.push('E');
+        output.push_str(&exponent.to_string());
+    }
+
+    fn write_digits(&self, output: &mut String, range: std::ops::Range<usize>) {
+        output.extend(
+            self.digits[range]
+                .iter()
+                .map(|&digit| (b'0' + digit) as char),
+        );
+    }
+}
+
+/// Insert `separator` between each group of three digits in the integer part of `positional`.
+fn group_digits(positional: &str, separator: char) -> String {
+    let integer_length = positional
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(positional.len());
+    let (integer, rest) = positional.split_at(integer_length);
+
+    let mut output = String::new();
+    for (index, digit) in integer.chars().enumerate() {
+        if index != 0 && (integer_length - index) % 3 == 0 {
+            output.push(separator);
+        }
+
+        output.push(digit);
+    }
+    output.push_str(rest);
+
+    output
+}
+
+impl Float {
+    /// Whether the TI-OS would automatically switch to scientific notation when displaying this
+    /// number in Normal mode.
+    ///
+    /// This happens for numbers which are at least `1E10` or smaller than `.001` after rounding
+    /// to the displayed digits.
+    pub fn normal_mode_uses_sci(&self) -> bool {
+        RoundedDigits::normal(self, None).is_none()
+    }
+
+    /// Produces the string the TI-OS would display for this number in Normal Float mode.
+    ///
+    /// ASCII `-` and `E` are used in place of the calculator's negation sign and `ᴇ`.
+    pub fn to_normal_string(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    /// Produces the string the TI-OS would display for this number under the provided modes.
+    ///
+    /// ASCII `-` and `E` are used in place of the calculator's negation sign and `ᴇ`. An undefined
+    /// Float is written as [`UNDEFINED`].
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        if self.is_undefined() {
+            return UNDEFINED.to_string();
+        }
+
+        let mut output = String::new();
+
+        if self.is_negative() && !self.is_zero() {
+            output.push('-');
+        }
+
+        let fixed_digits = options.decimal_mode.fixed_digits();
+        let fraction_digits = fixed_digits.unwrap_or(0);
+        let separator = options.decimal_separator;
+
+        if options.display_mode == DisplayMode::Normal {
+            if let Some(rounded) = RoundedDigits::normal(self, fixed_digits) {
+                // trailing zeros can't push the number past the displayed digits
+                let integer_digits = rounded.exponent.max(0) as usize + 1;
+                let fraction_digits =
+                    fraction_digits.min(DISPLAY_DIGITS.saturating_sub(integer_digits));
+
+                let mut positional = String::new();
+                rounded.write_positional(&mut positional, fraction_digits, separator);
+
+                match options.group_separator {
+                    Some(group_separator) => {
+                        output.push_str(&group_digits(&positional, group_separator))
+                    }
+                    None => output.push_str(&positional),
+                }
+
+                return output;
+            }
+        }
+
+        let engineering = options.display_mode == DisplayMode::Eng;
+        let rounded = match fixed_digits {
+            None => RoundedDigits::new(self, DISPLAY_DIGITS as isize),
+            Some(fixed_digits) => {
+                let integer_digits = if engineering {
+                    (self.exponent() as isize).rem_euclid(3) + 1
+                } else {
+                    1
+                };
+
+                RoundedDigits::new(self, integer_digits + fixed_digits as isize)
+            }
+        };
+
+        if rounded.is_zero() {
+            rounded.write_positional(&mut output, fraction_digits, separator);
+        } else {
+            rounded.write_scientific(&mut output, engineering, fraction_digits, separator);
+        }
+
+        output
+    }
+
+    /// Produces all 14 digits of the mantissa in scientific notation, including trailing zeros,
+    /// for example `-3.1415926535898E0` or `1.0000000000000E-5`. An undefined Float is written as
+    /// [`UNDEFINED`].
+    pub fn to_full_precision_string(&self) -> String {
+        if self.is_undefined() {
+            return UNDEFINED.to_string();
+        }
+
+        let rounded = RoundedDigits::new(self, <Mantissa>::DIGITS as isize);
+        let mut output = String::new();
+
+        if self.is_negative() && !rounded.is_zero() {
+            output.push('-');
+        }
+
+        rounded.write_scientific(&mut output, false, <Mantissa>::DIGITS - 1, '.');
+
+        output
+    }
+
+    /// Produces the shortest string which parses back to exactly this number, for example
+    /// `0.125`, `-3E50`, or `1.2345E-7`.
+    ///
+    /// Unlike the calculator's display, no digits are rounded away and numbers less than one are
+    /// written with a leading zero, so the output is also a valid JSON number. The exception is an
+    /// undefined Float, which is written as [`UNDEFINED`] and parses back to an undefined Float.
+    pub fn to_shortest_string(&self) -> String {
+        if self.is_undefined() {
+            return UNDEFINED.to_string();
+        }
+
+        let rounded = RoundedDigits::new(self, <Mantissa>::DIGITS as isize);
+
+        let mut positional = String::new();
+        let mut scientific = String::new();
+
+        if self.is_negative() && !rounded.is_zero() {
+            positional.push('-');
+            scientific.push('-');
+        }
+
+        if rounded.exponent < 0 {
+            positional.push('0');
+        }
+        rounded.write_positional(&mut positional, 0, '.');
+
+        if rounded.is_zero() {
+            return positional;
+        }
+        rounded.write_scientific(&mut scientific, false, 0, '.');
+
+        if scientific.len() < positional.len() {
+            scientific
+        } else {
+            positional
+        }
+    }
+}
+
+/// Displays the number as in Normal Float mode.
+///
+/// A precision (ex. `{:.4}`) selects the corresponding Fix mode, clamped to [`MAX_FIX_DIGITS`]
+/// decimal places. Width, fill, alignment, `+`, and `0` flags behave as they do for the primitive
+/// numeric types.
+impl Display for Float {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_undefined() {
+            return f.pad(UNDEFINED);
+        }
+
+        let options = match f.precision() {
+            Some(precision) => FormatOptions::new(
+                DisplayMode::Normal,
+                DecimalMode::Fix(precision.min(MAX_FIX_DIGITS as usize) as u8),
+            ),
+            None => FormatOptions::default(),
+        };
+
+        let formatted = self.format_with(&options);
+
+        match formatted.strip_prefix('-') {
+            Some(magnitude) => f.pad_integral(false, "", magnitude),
+            None => f.pad_integral(true, "", &formatted),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn normal_mode() {
+        let cases = [
+            (tifloat!(0x12345000000000 * 10 ^ 4), "12345"),
+            (tifloat!(-0x12345000000000 * 10 ^ 4), "-12345"),
+            (tifloat!(0x10000000000000 * 10 ^ 0), "1"),
+            (tifloat!(0x50000000000000 * 10 ^ -1), ".5"),
+            (tifloat!(0x31415926535898 * 10 ^ 0), "3.141592654"),
+            (tifloat!(0x33333333333333 * 10 ^ -3), ".0033333333"),
+            (tifloat!(0x10000000000000 * 10 ^ -3), ".001"),
+            (tifloat!(0x12345678912345 * 10 ^ 9), "1234567891"),
+            (tifloat!(0x99999999999900 * 10 ^ 8), "1000000000"),
+            (tifloat!(0x00000000000000 * 10 ^ 0), "0"),
+        ];
+
+        for (float, expected) in cases {
+            assert_eq!(float.to_normal_string(), expected);
+            assert!(!float.normal_mode_uses_sci());
+        }
+    }
+
+    #[test]
+    fn normal_mode_auto_sci() {
+        let cases = [
+            (tifloat!(0x10000000000000 * 10 ^ 10), "1E10"),
+            (tifloat!(0x12345678901234 * 10 ^ 12), "1.23456789E12"),
+            (tifloat!(-0x10000000000000 * 10 ^ -4), "-1E-4"),
+            (tifloat!(0x12345000000000 * 10 ^ -4), "1.2345E-4"),
+            (tifloat!(0x99999999999900 * 10 ^ 9), "1E10"),
+            (tifloat!(0x10000000000000 * 10 ^ -99), "1E-99"),
+        ];
+
+        for (float, expected) in cases {
+            assert_eq!(float.to_normal_string(), expected);
+            assert!(float.normal_mode_uses_sci());
+        }
+    }
+
+    #[test]
+    fn rounding_into_normal_range() {
+        assert_eq!(
+            tifloat!(0x99999999999900 * 10 ^ -4).to_normal_string(),
+            ".001"
+        );
+    }
+
+    #[test]
+    fn format_with() {
+        use DecimalMode::Fix;
+        use DisplayMode::*;
+
+        let value = tifloat!(0x12345678901234 * 10 ^ 4);
+        let small = tifloat!(0x10000000000000 * 10 ^ -3);
+        let zero = tifloat!(0x00000000000000 * 10 ^ 0);
+
+        let cases = [
+            (value, Normal, Fix(2), "12345.68"),
+            (value, Normal, Fix(0), "12346"),
+            (value, Normal, Fix(9), "12345.67890"),
+            (value, Sci, DecimalMode::Float, "1.23456789E4"),
+            (value, Sci, Fix(2), "1.23E4"),
+            (value, Eng, DecimalMode::Float, "12.3456789E3"),
+            (value, Eng, Fix(3), "12.346E3"),
+            (-value, Eng, Fix(0), "-12E3"),
+            (small, Normal, Fix(2), "1.00E-3"),
+            (small, Normal, Fix(3), ".001"),
+            (small, Normal, Fix(4), ".0010"),
+            (small, Eng, DecimalMode::Float, "1E-3"),
+            (tifloat!(0x99996000000000 * 10 ^ 2), Eng, Fix(1), "1.0E3"),
+            (tifloat!(0x50000000000000 * 10 ^ -1), Normal, Fix(2), ".50"),
+            (zero, Normal, Fix(2), "0.00"),
+            (Float::undefined(), Sci, Fix(2), "undefined"),
+            (zero, Sci, DecimalMode::Float, "0"),
+        ];
+
+        for (float, display_mode, decimal_mode, expected) in cases {
+            let options = FormatOptions::new(display_mode, decimal_mode);
+
+            assert_eq!(float.format_with(&options), expected);
+        }
+    }
+
+    #[test]
+    fn decimal_separator() {
+        let options = FormatOptions {
+            decimal_separator: ',',
+            ..Default::default()
+        };
+
+        assert_eq!(
+            tifloat!(0x31415926535898 * 10 ^ 0).format_with(&options),
+            "3,141592654"
+        );
+    }
+
+    #[test]
+    fn shortest_string() {
+        let cases = [
+            (tifloat!(0x12500000000000 * 10 ^ -1), "0.125"),
+            (tifloat!(-0x30000000000000 * 10 ^ 50), "-3E50"),
+            (tifloat!(0x12345000000000 * 10 ^ -7), "1.2345E-7"),
+            (tifloat!(0x12345000000000 * 10 ^ -3), "0.0012345"),
+            (tifloat!(0x10000000000000 * 10 ^ 2), "100"),
+            (tifloat!(0x10000000000000 * 10 ^ 3), "1E3"),
+            (tifloat!(0x31415926535898 * 10 ^ 0), "3.1415926535898"),
+            (tifloat!(0x00000000000000 * 10 ^ 0), "0"),
+            (Float::undefined(), "undefined"),
+        ];
+
+        for (float, expected) in cases {
+            assert_eq!(float.to_shortest_string(), expected);
+            assert_eq!(expected.parse::<Float>(), Ok(float));
+        }
+    }
+
+    #[test]
+    fn display_flags() {
+        let pi = tifloat!(0x31415926535898 * 10 ^ 0);
+        let value = tifloat!(-0x12345678901234 * 10 ^ 1);
+
+        assert_eq!(format!("{pi}"), "3.141592654");
+        assert_eq!(format!("{pi:>12.4}"), "      3.1416");
+        assert_eq!(format!("{pi:<12.4}|"), "3.1416      |");
+        assert_eq!(format!("{pi:*^10.2}"), "***3.14***");
+        assert_eq!(format!("{pi:+}"), "+3.141592654");
+        assert_eq!(format!("{pi:.20}"), "3.141592654");
+        assert_eq!(format!("{value:09.2}"), "-00012.35");
+        assert_eq!(format!("{value:>9.1}"), "    -12.3");
+        assert_eq!(format!("{:>+10}", Float::undefined()), " undefined");
+    }
+
+    #[test]
+    fn group_separator() {
+        let options = FormatOptions {
+            decimal_separator: ',',
+            group_separator: Some(' '),
+            ..Default::default()
+        };
+
+        let cases = [
+            (tifloat!(0x12345678900000 * 10 ^ 6), "1 234 567,89"),
+            (tifloat!(-0x12345678900000 * 10 ^ 6), "-1 234 567,89"),
+            (tifloat!(0x12345600000000 * 10 ^ 5), "123 456"),
+            (tifloat!(0x12340000000000 * 10 ^ 0), "1,234"),
+            (tifloat!(0x12345000000000 * 10 ^ -3), ",0012345"),
+            (tifloat!(0x12345000000000 * 10 ^ 13), "1,2345E13"),
+        ];
+
+        for (float, expected) in cases {
+            assert_eq!(float.format_with(&options), expected);
+        }
+    }
+
+    #[test]
+    fn full_precision_string() {
+        let cases = [
+            (tifloat!(-0x31415926535898 * 10 ^ 0), "-3.1415926535898E0"),
+            (tifloat!(0x10000000000000 * 10 ^ -5), "1.0000000000000E-5"),
+            (tifloat!(0x99999999999999 * 10 ^ 99), "9.9999999999999E99"),
+            (tifloat!(0x00000000000000 * 10 ^ 0), "0.0000000000000E0"),
+            (Float::undefined(), "undefined"),
+        ];
+
+        for (float, expected) in cases {
+            assert_eq!(float.to_full_precision_string(), expected);
+            assert_eq!(expected.parse::<Float>(), Ok(float));
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! Approximations of the `FormReal` and `FormEReal` ROM calls, which convert OP1 to a string in
+//! the calculator's character set for display in a field of limited width.
+//!
+//! The output layout follows the documented behavior of these calls: numbers are shown
+//! positionally when they fit in the field, and otherwise in scientific notation with as many
+//! mantissa digits as the field allows. This is not a bit-exact emulation. The rules have not
+//! been checked against ROM execution, and the output may differ from the OS's where rounding
+//! changes the layout, such as for values near `.0005` or values that round to a power of ten.
+
+use bitflags::bitflags;
+
+use crate::format::{DecimalMode, DisplayMode, FormatOptions, RoundedDigits, DISPLAY_DIGITS};
+use crate::mantissa::Mantissa;
+use crate::Float;
+
+bitflags! {
+    /// The bits of `(iy+fmtFlags)` which affect number formatting.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct FmtFlags: u8 {
+        /// `fmtExponent`: if set, the number is displayed with an exponent (Sci or Eng mode).
+        const EXPONENT = 0x01;
+        /// `fmtEng`: if set along with `fmtExponent`, the exponent is a multiple of three.
+        const ENG = 0x02;
+    }
+}
+
+/// The value of `fmtDigits` in Float mode. Values `0` through `9` select Fix mode.
+pub const FMT_DIGITS_FLOAT: u8 = 0xFF;
+
+/// The negation sign (`⁻`) in the calculator's character set, used for negative numbers and
+/// negative exponents.
+pub const L_NEG_SIGN: u8 = 0x1A;
+
+/// The exponent marker (`ᴇ`) in the calculator's character set.
+pub const L_EXPONENT: u8 = 0x1B;
+
+impl FormatOptions {
+    /// Determine the display modes selected by the OS's `fmtFlags` and `fmtDigits` bytes.
+    pub fn from_mode_bytes(fmt_flags: FmtFlags, fmt_digits: u8) -> Self {
+        let display_mode = if !fmt_flags.contains(FmtFlags::EXPONENT) {
+            DisplayMode::Normal
+        } else if fmt_flags.contains(FmtFlags::ENG) {
+            DisplayMode::Eng
+        } else {
+            DisplayMode::Sci
+        };
+
+        let decimal_mode = if fmt_digits <= 9 {
+            DecimalMode::Fix(fmt_digits)
+        } else {
+            DecimalMode::Float
+        };
+
+        FormatOptions::new(display_mode, decimal_mode)
+    }
+}
+
+/// Approximates the characters `FormReal` writes for `value` in a field of `width` characters, under
+/// the modes given by `fmt_flags` and `fmt_digits`.
+///
+/// At most 10 significant digits are shown. If even the shortest scientific notation does not
+/// fit, it is returned anyways.
+pub fn form_real(value: &Float, fmt_flags: FmtFlags, fmt_digits: u8, width: u8) -> Vec<u8> {
+    let options = FormatOptions::from_mode_bytes(fmt_flags, fmt_digits);
+
+    to_ti_charset(&layout(value, &options, DISPLAY_DIGITS, width as usize))
+}
+
+/// Approximates the characters `FormEReal` writes for `value` in a field of `width` characters.
+///
+/// This is the format used when editing a number, which ignores Fix mode and shows all 14
+/// significant digits.
+pub fn form_e_real(value: &Float, fmt_flags: FmtFlags, width: u8) -> Vec<u8> {
+    let options = FormatOptions::from_mode_bytes(fmt_flags, FMT_DIGITS_FLOAT);
+
+    to_ti_charset(&layout(value, &options, <Mantissa>::DIGITS, width as usize))
+}
+
+fn to_ti_charset(string: &str) -> Vec<u8> {
+    string
+        .bytes()
+        .map(|byte| match byte {
+            b'-' => L_NEG_SIGN,
+            b'E' => L_EXPONENT,
+            byte => byte,
+        })
+        .collect()
+}
+
+fn layout(value: &Float, options: &FormatOptions, max_digits: usize, width: usize) -> String {
+    let fixed_digits = match options.decimal_mode {
+        DecimalMode::Float => None,
+        DecimalMode::Fix(digits) => Some(digits as usize),
+    };
+
+    let rounded = RoundedDigits::new(value, <Mantissa>::DIGITS as isize);
+    let mut output = String::new();
+
+    if rounded.is_zero() {
+        let fraction_digits = fixed_digits.unwrap_or(0).min(width.saturating_sub(2));
+        rounded.write_positional(&mut output, fraction_digits, '.');
+
+        return output;
+    }
+
+    if value.is_negative() {
+        output.push('-');
+    }
+    let budget = width.saturating_sub(output.len());
+
+    if options.display_mode == DisplayMode::Normal {
+        if let Some(positional) = positional(value, fixed_digits, max_digits, budget) {
+            output.push_str(&positional);
+
+            return output;
+        }
+    }
+
+    let engineering = options.display_mode == DisplayMode::Eng;
+    output.push_str(&scientific(
+        value,
+        engineering,
+        fixed_digits,
+        max_digits,
+        budget,
+    ));
+
+    output
+}
+
+fn positional(
+    value: &Float,
+    fixed_digits: Option<usize>,
+    max_digits: usize,
+    budget: usize,
+) -> Option<String> {
+    let exponent = value.exponent() as isize;
+    let max_exponent = max_digits as isize - 1;
+
+    // numbers just below .001 may round up into the displayable range.
+    if !(-4..=max_exponent).contains(&exponent) {
+        return None;
+    }
+
+    // the decimal point takes up one position unless the number is an integer filling the field
+    let positions = max_digits.min(budget.saturating_sub(1)) as isize;
+    let mut count = if exponent >= 0 {
+        let integer_digits = exponent + 1;
+        if integer_digits > (max_digits.min(budget) as isize) {
+            return None;
+        }
+
+        positions.max(integer_digits)
+    } else {
+        positions + exponent + 1
+    };
+    if let Some(fixed_digits) = fixed_digits {
+        count = count.min(exponent + 1 + fixed_digits as isize);
+    }
+
+    let rounded = RoundedDigits::new(value, count);
+    if rounded.is_zero() || !(-3..=max_exponent).contains(&(rounded.exponent as isize)) {
+        return None;
+    }
+
+    let fraction_positions = if rounded.exponent < 0 {
+        positions
+    } else {
+        positions - rounded.exponent as isize - 1
+    };
+    let fraction_digits = fixed_digits
+        .unwrap_or(0)
+        .min(fraction_positions.max(0) as usize);
+
+    let mut output = String::new();
+    rounded.write_positional(&mut output, fraction_digits, '.');
+
+    (output.len() <= budget).then_some(output)
+}
+
+fn scientific(
+    value: &Float,
+    engineering: bool,
+    fixed_digits: Option<usize>,
+    max_digits: usize,
+    budget: usize,
+) -> String {
+    let mut exponent = value.exponent() as i16;
+    let mut carried = None;
+
+    // rounding can carry into the next exponent, which may take a different number of characters
+    // to display. the carried digits are kept as they are, since a shorter exponent leaves room
+    // for another digit, and rounding again to that many digits would carry back down.
+    loop {
+        let integer_digits = if engineering {
+            exponent.rem_euclid(3) as usize + 1
+        } else {
+            1
+        };
+        let displayed_exponent = exponent - (integer_digits as i16 - 1);
+        let exponent_length = displayed_exponent.to_string().len();
+
+        // a decimal point is only worthwhile if at least one digit follows it
+        let available = budget as isize - integer_digits as isize - 1 - exponent_length as isize;
+        let mut fraction_digits = (available - 1).max(0) as usize;
+        fraction_digits = fraction_digits.min(max_digits - integer_digits);
+        if let Some(fixed_digits) = fixed_digits {
+            fraction_digits = fraction_digits.min(fixed_digits);
+        }
+
+        let rounded = carried.take().unwrap_or_else(|| {
+            RoundedDigits::new(value, (integer_digits + fraction_digits) as isize)
+        });
+
+        if rounded.exponent == exponent {
+            let mut output = String::new();
+            rounded.write_scientific(
+                &mut output,
+                engineering,
+                fixed_digits.map_or(0, |_| fraction_digits),
+                '.',
+            );
+
+            return output;
+        }
+
+        exponent = rounded.exponent;
+        carried = Some(rounded);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn string(bytes: Vec<u8>) -> String {
+        bytes
+            .into_iter()
+            .map(|byte| match byte {
+                L_NEG_SIGN => '⁻',
+                L_EXPONENT => 'ᴇ',
+                byte => byte as char,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn home_screen() {
+        let normal = FmtFlags::empty();
+
+        let cases = [
+            (tifloat!(0x31415926535898 * 10 ^ 0), "3.141592654"),
+            (tifloat!(-0x12345000000000 * 10 ^ 4), "⁻12345"),
+            (tifloat!(0x12345000000000 * 10 ^ -7), "1.2345ᴇ⁻7"),
+            (tifloat!(0x10000000000000 * 10 ^ 10), "1ᴇ10"),
+            (tifloat!(0x00000000000000 * 10 ^ 0), "0"),
+        ];
+
+        for (float, expected) in cases {
+            assert_eq!(
+                string(form_real(&float, normal, FMT_DIGITS_FLOAT, 16)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn narrow_fields() {
+        let normal = FmtFlags::empty();
+
+        let cases = [
+            (tifloat!(0x12345670000000 * 10 ^ 6), "1.23ᴇ6"),
+            (tifloat!(-0x12345670000000 * 10 ^ 6), "⁻1.2ᴇ6"),
+            (tifloat!(0x31415926535898 * 10 ^ 0), "3.1416"),
+            (tifloat!(0x12345600000000 * 10 ^ 5), "123456"),
+            (tifloat!(0x50000000000000 * 10 ^ -4), "5ᴇ⁻4"),
+            (tifloat!(0x12345000000000 * 10 ^ -3), ".00123"),
+            (tifloat!(0x99999900000000 * 10 ^ 5), "999999"),
+            (tifloat!(0x99999990000000 * 10 ^ 5), "1ᴇ6"),
+            (tifloat!(0x99999999999999 * 10 ^ 8), "1ᴇ9"),
+        ];
+
+        for (float, expected) in cases {
+            assert_eq!(
+                string(form_real(&float, normal, FMT_DIGITS_FLOAT, 6)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn carried_exponents() {
+        let cases = [
+            // rounding up to 1ᴇ⁻9 shortens the exponent, leaving room for a digit which would not
+            // round up
+            (tifloat!(0x99950000000000 * 10 ^ -10), 8, "1ᴇ⁻9"),
+            (tifloat!(0x99950000000000 * 10 ^ 9), 5, "1ᴇ10"),
+            (tifloat!(-0x99999999999999 * 10 ^ 99), 7, "⁻1ᴇ100"),
+        ];
+
+        for (float, width, expected) in cases {
+            assert_eq!(
+                string(form_real(
+                    &float,
+                    FmtFlags::EXPONENT,
+                    FMT_DIGITS_FLOAT,
+                    width
+                )),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn mode_bytes() {
+        let value = tifloat!(0x12345678901234 * 10 ^ 4);
+
+        let cases = [
+            (FmtFlags::empty(), 2, "12345.68"),
+            (FmtFlags::EXPONENT, FMT_DIGITS_FLOAT, "1.23456789ᴇ4"),
+            (FmtFlags::EXPONENT, 3, "1.235ᴇ4"),
+            (FmtFlags::EXPONENT | FmtFlags::ENG, 1, "12.3ᴇ3"),
+        ];
+
+        for (flags, digits, expected) in cases {
+            assert_eq!(string(form_real(&value, flags, digits, 16)), expected);
+        }
+    }
+
+    #[test]
+    fn edit_format() {
+        assert_eq!(
+            string(form_e_real(
+                &tifloat!(0x33333333333333 * 10 ^ -1),
+                FmtFlags::empty(),
+                16
+            )),
+            ".33333333333333"
+        );
+    }
+}
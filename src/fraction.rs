@@ -0,0 +1,303 @@
+use crate::float::ParseFloatError;
+use crate::{Float, FloatError};
+
+/// ►Frac accepts a fraction when it agrees with the value to this many significant digits.
+const FRAC_TOLERANCE_DIGITS: u32 = 12;
+
+impl Float {
+    /// The simplest fraction equal to this Float, like the calculator's ►Frac command.
+    ///
+    /// The fraction is found by expanding the value as a continued fraction. The first convergent
+    /// that agrees with the value to 12 significant digits is accepted, which is how `1/3` is
+    /// recovered from `.33333333333333`. Returns `None` if no convergent with a denominator of
+    /// at most `max_denominator` is accepted, if the numerator does not fit in an `i64`, or if
+    /// the value is undefined.
+    pub fn to_fraction(&self, max_denominator: u64) -> Option<(i64, u64)> {
+        if self.is_undefined() {
+            return None;
+        }
+
+        let (numerator, denominator) = exact_ratio(self)?;
+
+        if numerator == 0 {
+            return Some((0, 1));
+        }
+
+        // convergents h / k of the continued fraction of numerator / denominator
+        let (mut h, mut h_previous) = (1u128, 0u128);
+        let (mut k, mut k_previous) = (0u128, 1u128);
+        let (mut n, mut d) = (numerator, denominator);
+
+        while d != 0 {
+            let term = n / d;
+            (n, d) = (d, n % d);
+
+            (h, h_previous) = (term.checked_mul(h)?.checked_add(h_previous)?, h);
+            (k, k_previous) = (term.checked_mul(k)?.checked_add(k_previous)?, k);
+
+            if k > max_denominator as u128 {
+                return None;
+            }
+
+            if is_close(numerator, denominator, h, k) {
+                let h = i64::try_from(h).ok()?;
+
+                return Some((if self.is_negative() { -h } else { h }, k as u64));
+            }
+        }
+
+        None
+    }
+
+    /// The Float nearest to `numerator / denominator`, rounding the quotient half-up to 14
+    /// significant digits in a single step.
+    ///
+    /// Fails with [`FloatError::DivideByZero`] if the denominator is zero.
+    pub fn from_ratio(numerator: i64, denominator: u64) -> Result<Float, FloatError> {
+        if denominator == 0 {
+            return Err(FloatError::DivideByZero);
+        } else if numerator == 0 {
+            return Ok(Float::ZERO);
+        }
+
+        Float::from_scaled_ratio(
+            numerator < 0,
+            numerator.unsigned_abs() as u128,
+            denominator as u128,
+            0,
+        )
+    }
+
+    /// The Float nearest to `numerator / denominator * 10 ^ scale`, rounded half-up to 14
+    /// significant digits. The numerator must be nonzero and the denominator less than `10 ^ 22`.
+    pub(crate) fn from_scaled_ratio(
+        negative: bool,
+        numerator: u128,
+        denominator: u128,
+        scale: i64,
+    ) -> Result<Float, FloatError> {
+        // estimate the place-value of the leading digit, then correct it
+        let mut exponent = numerator.ilog10() as i64 - denominator.ilog10() as i64;
+        if scaled(numerator, denominator, -exponent) == 0 {
+            exponent -= 1;
+        }
+
+        // one digit past the mantissa is enough to round half-up
+        let digits = scaled(numerator, denominator, 14 - exponent)
+            .to_string()
+            .bytes()
+            .map(|byte| byte - b'0')
+            .collect::<Vec<_>>();
+
+        Float::from_decimal_digits(negative, &digits, exponent + scale)
+    }
+
+    /// Writes this Float as a mixed number in the style of the `Un/d` display mode, such as
+    /// `3_1/4`, `-1/3`, or `2`, using [`Float::to_fraction`] to find the fraction.
+    ///
+    /// Returns `None` if no acceptable fraction exists.
+    pub fn to_mixed_string(&self, max_denominator: u64) -> Option<String> {
+        let (numerator, denominator) = self.to_fraction(max_denominator)?;
+
+        let sign = if numerator < 0 { "-" } else { "" };
+        let whole = numerator.unsigned_abs() / denominator;
+        let remainder = numerator.unsigned_abs() % denominator;
+
+        Some(match (whole, remainder) {
+            (whole, 0) => format!("{sign}{whole}"),
+            (0, remainder) => format!("{sign}{remainder}/{denominator}"),
+            (whole, remainder) => format!("{sign}{whole}_{remainder}/{denominator}"),
+        })
+    }
+
+    /// Parses a mixed number written as `U_n/d`, `n/d`, or `U`, optionally preceded by `-`. The
+    /// value is rounded half-up to 14 significant digits.
+    pub fn from_mixed_str(s: &str) -> Result<Float, ParseFloatError> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        let (whole, fraction) = match s.split_once('_') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None if s.contains('/') => ("0", Some(s)),
+            None => (s, None),
+        };
+
+        let (numerator, denominator) = fraction
+            .map_or(Some(("0", "1")), |fraction| fraction.split_once('/'))
+            .ok_or(ParseFloatError::InvalidString)?;
+
+        let parse = |digits: &str| {
+            if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(ParseFloatError::InvalidString);
+            }
+
+            digits
+                .parse::<u64>()
+                .map_err(|_| ParseFloatError::InvalidExponent)
+        };
+
+        let (whole, numerator, denominator) =
+            (parse(whole)?, parse(numerator)?, parse(denominator)?);
+
+        if denominator == 0 {
+            return Err(ParseFloatError::InvalidString);
+        }
+
+        let total = whole as u128 * denominator as u128 + numerator as u128;
+        if total == 0 {
+            return Ok(Float::ZERO);
+        }
+
+        Float::from_scaled_ratio(negative, total, denominator as u128, 0)
+            .map_err(|_| ParseFloatError::InvalidExponent)
+    }
+}
+
+/// `floor(numerator / denominator * 10 ^ shift)`. Callers keep the result within 15 digits, so
+/// this cannot overflow for denominators less than `10 ^ 22`.
+fn scaled(numerator: u128, denominator: u128, shift: i64) -> u128 {
+    let scale = 10u128.pow(shift.unsigned_abs() as u32);
+
+    if shift >= 0 {
+        numerator * scale / denominator
+    } else {
+        numerator / (denominator * scale)
+    }
+}
+
+/// The magnitude of `float` as `numerator / denominator`, if both fit in a `u128`.
+fn exact_ratio(float: &Float) -> Option<(u128, u128)> {
+    let mantissa = float
+        .digits()
+        .iter()
+        .fold(0u128, |acc, &digit| acc * 10 + digit as u128);
+
+    let exponent = float.exponent() as i32 - 13;
+    let scale = 10u128.checked_pow(exponent.unsigned_abs())?;
+
+    if exponent >= 0 {
+        Some((mantissa.checked_mul(scale)?, 1))
+    } else {
+        Some((mantissa, scale))
+    }
+}
+
+/// Whether `h / k` agrees with `numerator / denominator` to [`FRAC_TOLERANCE_DIGITS`]
+/// significant digits.
+fn is_close(numerator: u128, denominator: u128, h: u128, k: u128) -> bool {
+    let (Some(exact), Some(approximate)) = (numerator.checked_mul(k), h.checked_mul(denominator))
+    else {
+        return false;
+    };
+
+    // |x - h/k| <= x * 5 * 10 ^ -(digits + 1)
+    exact
+        .abs_diff(approximate)
+        .checked_mul(2 * 10u128.pow(FRAC_TOLERANCE_DIGITS))
+        .is_some_and(|difference| difference <= exact)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn to_fraction() {
+        let cases = [
+            (tifloat!(0x33333333333333 * 10 ^ -1), Some((1, 3))),
+            (tifloat!(-0x66666666666667 * 10 ^ -1), Some((-2, 3))),
+            (tifloat!(0x12500000000000 * 10 ^ 1), Some((25, 2))),
+            (tifloat!(0x31415929203540 * 10 ^ 0), Some((355, 113))),
+            (tifloat!(0x14285714285714 * 10 ^ -1), Some((1, 7))),
+            (tifloat!(0x42000000000000 * 10 ^ 1), Some((42, 1))),
+            (tifloat!(0x00000000000000 * 10 ^ 0), Some((0, 1))),
+            (tifloat!(0x31415926535898 * 10 ^ 0), None),
+            (tifloat!(0x33333333333000 * 10 ^ -1), None),
+            (tifloat!(0x10000000000000 * 10 ^ 30), None),
+            (tifloat!(0x10000000000000 * 10 ^ -50), None),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(value.to_fraction(9999), expected, "{value:?}");
+        }
+
+        assert_eq!(Float::undefined().to_fraction(9999), None);
+        assert_eq!(Float::undefined().to_mixed_string(9999), None);
+    }
+
+    #[test]
+    fn from_ratio() {
+        let cases = [
+            (1, 3, tifloat!(0x33333333333333 * 10 ^ -1)),
+            (2, 3, tifloat!(0x66666666666667 * 10 ^ -1)),
+            (-355, 113, tifloat!(-0x31415929203540 * 10 ^ 0)),
+            (99, 100, tifloat!(0x99000000000000 * 10 ^ -1)),
+            (1, u64::MAX, tifloat!(0x54210108624275 * 10 ^ -20)),
+            (i64::MIN, 1, tifloat!(-0x92233720368548 * 10 ^ 18)),
+            (i64::MAX, u64::MAX, tifloat!(0x50000000000000 * 10 ^ -1)),
+            (0, 7, tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (numerator, denominator, expected) in cases {
+            assert_eq!(
+                Float::from_ratio(numerator, denominator).ok().unwrap(),
+                expected,
+                "{numerator}/{denominator}"
+            );
+        }
+
+        assert!(matches!(
+            Float::from_ratio(1, 0),
+            Err(FloatError::DivideByZero)
+        ));
+    }
+
+    #[test]
+    fn mixed_string() {
+        let cases = [
+            (tifloat!(0x32500000000000 * 10 ^ 0), Some("3_1/4")),
+            (tifloat!(-0x33333333333333 * 10 ^ -1), Some("-1/3")),
+            (tifloat!(-0x13333333333333 * 10 ^ 1), Some("-13_1/3")),
+            (tifloat!(0x20000000000000 * 10 ^ 0), Some("2")),
+            (tifloat!(0x31415926535898 * 10 ^ 0), None),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(
+                value.to_mixed_string(9999).as_deref(),
+                expected,
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_mixed() {
+        let cases = [
+            ("3_1/4", tifloat!(0x32500000000000 * 10 ^ 0)),
+            ("-1/3", tifloat!(-0x33333333333333 * 10 ^ -1)),
+            ("-13_1/3", tifloat!(-0x13333333333333 * 10 ^ 1)),
+            ("2", tifloat!(0x20000000000000 * 10 ^ 0)),
+            ("0_0/5", tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (string, expected) in cases {
+            assert_eq!(Float::from_mixed_str(string), Ok(expected), "{string}");
+        }
+
+        for string in ["", "_1/2", "1_2", "1/0", "1/2/3", "1.5", "--1"] {
+            assert!(Float::from_mixed_str(string).is_err(), "{string}");
+        }
+    }
+
+    #[test]
+    fn to_fraction_max_denominator() {
+        let float = tifloat!(0x31415929203540 * 10 ^ 0);
+
+        assert_eq!(float.to_fraction(113), Some((355, 113)));
+        assert_eq!(float.to_fraction(112), None);
+    }
+}
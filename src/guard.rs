@@ -0,0 +1,428 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::mantissa::Mantissa;
+use crate::{Flags, Float, FloatError, ParseFloatError, RoundingMode};
+
+/// Guard digits kept past the 14 stored digits, as in the two extra bytes of an OP register.
+const GUARD_DIGITS: u32 = 4;
+
+/// Significant digits of a guarded value.
+const DIGITS: u32 = 14 + GUARD_DIGITS;
+
+/// The significand of a guarded value.
+type Significand = Mantissa<{ DIGITS as usize }, u128>;
+
+/// A float carrying four guard digits, as the OS keeps in its 11-byte OP registers while it
+/// evaluates an expression. Chaining operations on guarded values and rounding once with
+/// [`GuardedFloat::round_guard`] gives results such as `1/3*3 = 1`, as the calculator does.
+///
+/// Only chains of guarded values, including those in [`op::Registers`](crate::op::Registers),
+/// carry guard digits. [`Float`]'s operators round every result to 14 digits, so the same chain
+/// of them can end an ULP or two away from the calculator's result. Guarded operations model the
+/// register layout rather than each OS routine, so they can still differ from the calculator in
+/// the last guard digit.
+///
+/// Each operation rounds half-up to 18 significant digits and fails if the exponent leaves
+/// `-99..=99`. An undefined operand makes the result undefined.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GuardedFloat {
+    negative: bool,
+    /// Place-value of the leading digit.
+    exponent: i16,
+    /// Zero, or normalized to a nonzero leading digit.
+    significand: Significand,
+    undefined: bool,
+}
+
+impl GuardedFloat {
+    const ZERO: GuardedFloat = GuardedFloat {
+        negative: false,
+        exponent: 0,
+        significand: Significand::from_unchecked(0),
+        undefined: false,
+    };
+
+    const UNDEFINED: GuardedFloat = GuardedFloat {
+        undefined: true,
+        ..GuardedFloat::ZERO
+    };
+
+    /// Produces the guarded value nearest to `magnitude * 10 ^ exponent`.
+    fn new(negative: bool, magnitude: u128, exponent: i32) -> Result<Self, FloatError> {
+        match Significand::from_dec_rounded(magnitude, RoundingMode::HalfUp) {
+            Some((significand, place)) => {
+                GuardedFloat::from_parts(negative, exponent + place, significand)
+            }
+            None => Ok(GuardedFloat::ZERO),
+        }
+    }
+
+    /// Assembles a guarded value from a rounded significand and the place-value of its leading
+    /// digit, failing if that is outside `-99..=99`.
+    fn from_parts(
+        negative: bool,
+        exponent: i32,
+        significand: Significand,
+    ) -> Result<Self, FloatError> {
+        trace!(crate::trace::Step::Guard {
+            significand: significand.to_dec() as u64,
+            exponent: exponent as i16,
+        });
+
+        if exponent > 99 {
+            Err(FloatError::Overflow)
+        } else if exponent < -99 {
+            Err(FloatError::Underflow)
+        } else {
+            Ok(GuardedFloat {
+                negative,
+                exponent: exponent as i16,
+                significand,
+                undefined: false,
+            })
+        }
+    }
+
+    /// Place-value of the last digit.
+    fn lsd_exponent(&self) -> i32 {
+        self.exponent as i32 - (DIGITS as i32 - 1)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.significand.is_zero()
+    }
+
+    /// The 11-byte representation of an OP register: flags, the exponent biased by `0x80`, and 18
+    /// packed-BCD digits.
+    pub fn to_raw_bytes(&self) -> [u8; 11] {
+        let mut bytes = [0u8; 11];
+
+        if self.undefined {
+            bytes[0] = Flags::UNDEFINED.bits();
+        } else if self.negative {
+            bytes[0] = Flags::NEGATIVE.bits();
+        }
+
+        bytes[1] = (self.exponent + 0x80) as u8;
+
+        bytes[2..].copy_from_slice(&self.significand.bits().to_be_bytes()[7..]);
+
+        bytes
+    }
+
+    /// Given the 11-byte representation of an OP register, produces a guarded value. Leading
+    /// zeros in the mantissa are normalized away.
+    pub fn from_raw_bytes(bytes: [u8; 11]) -> Result<Self, ParseFloatError> {
+        let flags = Flags::from_bits(bytes[0]).ok_or(ParseFloatError::InvalidFlags)?;
+
+        let exponent = bytes[1] as i32 - 0x80;
+        if !(-99..=99).contains(&exponent) {
+            return Err(ParseFloatError::InvalidExponent);
+        }
+
+        let mut bits = [0u8; 16];
+        bits[7..].copy_from_slice(&bytes[2..]);
+        let significand =
+            Significand::from(u128::from_be_bytes(bits)).ok_or(ParseFloatError::InvalidMantissa)?;
+
+        if flags.contains(Flags::UNDEFINED) {
+            return Ok(GuardedFloat::UNDEFINED);
+        }
+
+        // leading zeros can only lower the exponent, so the one possible failure is underflow,
+        // which flushes to zero
+        Ok(GuardedFloat::new(
+            flags.contains(Flags::NEGATIVE),
+            significand.to_dec(),
+            exponent - (DIGITS as i32 - 1),
+        )
+        .unwrap_or(GuardedFloat::ZERO))
+    }
+
+    /// Discards the fractional digits, rounding toward zero, as the OS's `Trunc` does.
+    pub fn trunc(self) -> GuardedFloat {
+        if self.undefined || self.exponent >= DIGITS as i16 - 1 {
+            self
+        } else if self.exponent < 0 {
+            GuardedFloat::ZERO
+        } else {
+            let unit = 10u128.pow(DIGITS - 1 - self.exponent as u32);
+
+            GuardedFloat {
+                significand: Significand::from_dec(self.significand.to_dec() / unit * unit),
+                ..self
+            }
+        }
+    }
+
+    /// Rounds toward negative infinity, as the OS's `Intgr` does.
+    pub fn floor(self) -> GuardedFloat {
+        let truncated = self.trunc();
+
+        if self.negative && truncated != self {
+            // a value with a fraction is below 10 ^ 17, so stepping down can't overflow
+            (truncated - GuardedFloat::from(Float::ONE)).unwrap()
+        } else {
+            truncated
+        }
+    }
+
+    /// Rounds half-up to 14 digits, discarding the guard digits as the OS's `RndGuard` does.
+    pub fn round_guard(self) -> Result<Float, FloatError> {
+        if self.undefined {
+            Ok(Float::undefined())
+        } else if self.is_zero() {
+            Ok(Float::ZERO)
+        } else {
+            Float::from_scaled_integer(
+                self.negative,
+                self.significand.to_dec(),
+                self.lsd_exponent() as i64,
+            )
+        }
+    }
+}
+
+impl From<Float> for GuardedFloat {
+    fn from(float: Float) -> Self {
+        if float.is_undefined() {
+            GuardedFloat::UNDEFINED
+        } else if float.is_zero() {
+            GuardedFloat::ZERO
+        } else {
+            GuardedFloat {
+                negative: float.is_negative(),
+                exponent: float.exponent() as i16,
+                significand: Significand::from_dec(
+                    float.significand_dec() as u128 * 10u128.pow(GUARD_DIGITS),
+                ),
+                undefined: false,
+            }
+        }
+    }
+}
+
+impl Neg for GuardedFloat {
+    type Output = GuardedFloat;
+
+    fn neg(self) -> Self::Output {
+        if self.is_zero() || self.undefined {
+            self
+        } else {
+            GuardedFloat {
+                negative: !self.negative,
+                ..self
+            }
+        }
+    }
+}
+
+impl Add for GuardedFloat {
+    type Output = Result<GuardedFloat, FloatError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.undefined || rhs.undefined {
+            return Ok(GuardedFloat::UNDEFINED);
+        } else if rhs.is_zero() {
+            return Ok(self);
+        } else if self.is_zero() {
+            return Ok(rhs);
+        }
+
+        let (a, b) = if self.exponent < rhs.exponent {
+            (rhs, self)
+        } else {
+            (self, rhs)
+        };
+
+        let Some((significand, shift, borrowed)) = a.significand.rounded_sum(
+            b.significand,
+            (a.exponent - b.exponent) as u32,
+            a.negative != b.negative,
+            RoundingMode::HalfUp,
+        ) else {
+            return Ok(GuardedFloat::ZERO);
+        };
+
+        GuardedFloat::from_parts(
+            a.negative != borrowed,
+            a.exponent as i32 + shift,
+            significand,
+        )
+    }
+}
+
+impl Sub for GuardedFloat {
+    type Output = Result<GuardedFloat, FloatError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for GuardedFloat {
+    type Output = Result<GuardedFloat, FloatError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.undefined || rhs.undefined {
+            return Ok(GuardedFloat::UNDEFINED);
+        } else if self.is_zero() || rhs.is_zero() {
+            return Ok(GuardedFloat::ZERO);
+        }
+
+        let (significand, shift) = self
+            .significand
+            .overflowing_mul(rhs.significand, RoundingMode::HalfUp);
+
+        GuardedFloat::from_parts(
+            self.negative != rhs.negative,
+            self.exponent as i32 + rhs.exponent as i32 + shift as i32,
+            significand,
+        )
+    }
+}
+
+impl Div for GuardedFloat {
+    type Output = Result<GuardedFloat, FloatError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if self.undefined || rhs.undefined {
+            return Ok(GuardedFloat::UNDEFINED);
+        } else if rhs.is_zero() {
+            return Err(FloatError::DivideByZero);
+        } else if self.is_zero() {
+            return Ok(GuardedFloat::ZERO);
+        }
+
+        let (significand, shift) = self
+            .significand
+            .overflowing_div(rhs.significand, RoundingMode::HalfUp);
+
+        GuardedFloat::from_parts(
+            self.negative != rhs.negative,
+            self.exponent as i32 - rhs.exponent as i32 - 1 + shift as i32,
+            significand,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    fn guarded(float: Float) -> GuardedFloat {
+        GuardedFloat::from(float)
+    }
+
+    #[test]
+    fn chained() {
+        let one = guarded(Float::ONE);
+        let three = guarded(tifloat!(0x30000000000000 * 10 ^ 0));
+
+        let third = (one / three).unwrap();
+
+        assert_eq!((third * three).unwrap().round_guard().unwrap(), Float::ONE);
+
+        // rounding after each step loses the last digit
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+        assert_eq!(
+            ((Float::ONE / three).unwrap() * three).unwrap(),
+            tifloat!(0x99999999999999 * 10 ^ -1)
+        );
+
+        assert_eq!(
+            ((third + third).unwrap() + third)
+                .unwrap()
+                .round_guard()
+                .unwrap(),
+            Float::ONE
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for float in [
+            Float::ZERO,
+            Float::MAX,
+            -Float::MIN_POSITIVE,
+            tifloat!(-0x31415926535898 * 10 ^ 7),
+        ] {
+            assert_eq!(guarded(float).round_guard().unwrap(), float);
+        }
+
+        assert!(guarded(Float::undefined())
+            .round_guard()
+            .unwrap()
+            .is_undefined());
+    }
+
+    #[test]
+    fn cancellation() {
+        let a = guarded(tifloat!(0x12345678901234 * 10 ^ 0));
+        let tiny = guarded(tifloat!(0x30000000000000 * 10 ^ -14));
+
+        // each term is rounded away on its own, but the guard digits keep them both
+        let sum = ((a + tiny).unwrap() + tiny).unwrap();
+
+        assert_eq!(
+            sum.round_guard().unwrap(),
+            tifloat!(0x12345678901235 * 10 ^ 0)
+        );
+        assert_eq!((a - a).unwrap().round_guard().unwrap(), Float::ZERO);
+    }
+
+    #[test]
+    fn raw_bytes() {
+        let third = (guarded(Float::ONE) / guarded(tifloat!(-0x30000000000000 * 10 ^ 0))).unwrap();
+        let bytes = [
+            0x80, 0x7F, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33,
+        ];
+
+        assert_eq!(third.to_raw_bytes(), bytes);
+        assert_eq!(GuardedFloat::from_raw_bytes(bytes), Ok(third));
+        assert_eq!(
+            GuardedFloat::from_raw_bytes([0x00, 0x82, 0x00, 0x12, 0, 0, 0, 0, 0, 0, 0]),
+            Ok(guarded(tifloat!(0x12000000000000 * 10 ^ 0)))
+        );
+        assert_eq!(
+            GuardedFloat::from_raw_bytes(guarded(Float::ZERO).to_raw_bytes()),
+            Ok(guarded(Float::ZERO))
+        );
+        assert_eq!(
+            GuardedFloat::from_raw_bytes([0x00, 0x80, 0x1A, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Err(ParseFloatError::InvalidMantissa)
+        );
+        assert_eq!(
+            GuardedFloat::from_raw_bytes([0x00, 0x00, 0x10, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Err(ParseFloatError::InvalidExponent)
+        );
+    }
+
+    #[test]
+    fn trunc_and_floor() {
+        let a = guarded(tifloat!(-0x25000000000000 * 10 ^ 0));
+        let b = guarded(tifloat!(0x99999999999999 * 10 ^ -1));
+
+        assert_eq!(a.trunc(), guarded(tifloat!(-0x20000000000000 * 10 ^ 0)));
+        assert_eq!(a.floor(), guarded(tifloat!(-0x30000000000000 * 10 ^ 0)));
+        assert_eq!(b.trunc(), guarded(Float::ZERO));
+        assert_eq!((-b).floor(), guarded(-Float::ONE));
+        assert_eq!(guarded(Float::MAX).floor(), guarded(Float::MAX));
+    }
+
+    #[test]
+    fn errors() {
+        let big = guarded(tifloat!(0x50000000000000 * 10 ^ 99));
+
+        assert!(matches!(big + big, Err(FloatError::Overflow)));
+        assert!(matches!(
+            guarded(Float::ONE) / guarded(Float::ZERO),
+            Err(FloatError::DivideByZero)
+        ));
+        assert!(matches!(
+            guarded(Float::MIN_POSITIVE) * guarded(Float::MIN_POSITIVE),
+            Err(FloatError::Underflow)
+        ));
+    }
+}
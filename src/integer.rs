@@ -0,0 +1,279 @@
+use crate::{Float, FloatError};
+
+impl Float {
+    /// Whether this Float is an integer, as required by commands like `randInt(` and `dim(`.
+    /// Zero is an integer; undefined Floats are not.
+    pub fn is_integer(&self) -> bool {
+        if self.is_undefined() {
+            false
+        } else if self.is_zero() {
+            true
+        } else if self.exponent() < 0 {
+            false
+        } else {
+            self.mantissa_digits()
+                .iter()
+                .skip(self.exponent() as usize + 1)
+                .all(|&digit| digit == 0)
+        }
+    }
+
+    /// This Float as an `i64`, if it is an integer in the range of one.
+    pub fn as_integer(&self) -> Option<i64> {
+        if !self.is_integer() {
+            return None;
+        } else if self.is_zero() {
+            return Some(0);
+        }
+
+        let magnitude = self
+            .mantissa_digits()
+            .iter()
+            .chain(std::iter::repeat(&0))
+            .take(self.exponent() as usize + 1)
+            .try_fold(0i64, |acc, &digit| {
+                acc.checked_mul(10)?.checked_add(digit as i64)
+            })?;
+
+        // i64::MIN has more than 14 significant digits, so the magnitude never needs to reach it
+        Some(if self.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
+
+    /// The integer part, rounding toward zero, like `iPart(`. `iPart(-2.5)` is `-2`.
+    #[doc(alias = "iPart(")]
+    pub fn trunc(&self) -> Float {
+        self.round_to_integer(false)
+    }
+
+    /// The fractional part, which has the sign of the value, like `fPart(`. `fPart(-2.5)` is
+    /// `-.5`, and the value is always `iPart(x)+fPart(x)` exactly.
+    #[doc(alias = "fPart(")]
+    pub fn fract(&self) -> Float {
+        if self.is_undefined() || self.exponent() < 0 {
+            *self
+        } else if self.exponent() >= 13 {
+            Float::ZERO
+        } else {
+            let (_, fraction) = self.split_digits();
+
+            Float::from_scaled_integer(self.is_negative(), fraction, self.exponent() as i64 - 13)
+                .unwrap()
+        }
+    }
+
+    /// The greatest integer no greater than the value, like `int(`. `int(-2.5)` is `-3`.
+    #[doc(alias = "int(")]
+    pub fn floor(&self) -> Float {
+        self.round_to_integer(self.is_negative())
+    }
+
+    /// The least integer no less than the value. `ceil(-2.5)` is `-2`.
+    pub fn ceil(&self) -> Float {
+        self.round_to_integer(!self.is_negative())
+    }
+
+    /// The greatest common divisor of two nonnegative integers, like `gcd(`. `gcd(0,0)` is `0`.
+    ///
+    /// Fails with [`FloatError::Domain`] unless both values are integers from `0` to
+    /// `99999999999999`. If either value is undefined, the result is undefined.
+    #[doc(alias = "gcd(")]
+    pub fn gcd(&self, other: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || other.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        let divisor = gcd(self.as_natural()?, other.as_natural()?);
+
+        Float::from_scaled_integer(false, divisor as u128, 0)
+    }
+
+    /// The least common multiple of two nonnegative integers, like `lcm(`, which is zero if
+    /// either is zero. Multiples of more than 14 digits are rounded half-up.
+    ///
+    /// Fails with [`FloatError::Domain`] unless both values are integers from `0` to
+    /// `99999999999999`. If either value is undefined, the result is undefined.
+    #[doc(alias = "lcm(")]
+    pub fn lcm(&self, other: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || other.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        let (a, b) = (self.as_natural()?, other.as_natural()?);
+        if a == 0 || b == 0 {
+            return Ok(Float::ZERO);
+        }
+
+        Float::from_scaled_integer(false, (a / gcd(a, b)) as u128 * b as u128, 0)
+    }
+
+    /// This Float as an integer for `gcd(` and `lcm(`, which accept at most 14 digits.
+    fn as_natural(&self) -> Result<u64, FloatError> {
+        match self.as_integer() {
+            Some(value @ 0..=99_999_999_999_999) => Ok(value as u64),
+            _ => Err(FloatError::Domain),
+        }
+    }
+
+    /// Drops the fractional digits, adding one to the magnitude if any of them were nonzero and
+    /// `away` is set. Every Float of `1E13` or more is already an integer.
+    fn round_to_integer(&self, away: bool) -> Float {
+        if self.is_undefined() || self.exponent() >= 13 {
+            return *self;
+        }
+
+        let (integer, fraction) = self.split_digits();
+        let magnitude = integer + (away && fraction != 0) as u128;
+
+        // at most 14 digits, even after carrying into a new place
+        Float::from_scaled_integer(self.is_negative(), magnitude, 0).unwrap()
+    }
+
+    /// The significand's digits before and after the decimal point, as integers, for a Float
+    /// below `1E13`.
+    fn split_digits(&self) -> (u128, u128) {
+        let significand = self.significand_dec() as u128;
+
+        if self.exponent() < 0 {
+            (0, significand)
+        } else {
+            let unit = 10u128.pow(13 - self.exponent() as u32);
+
+            (significand / unit, significand % unit)
+        }
+    }
+}
+
+/// Euclid's algorithm.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn is_integer() {
+        assert!(Float::ZERO.is_integer());
+        assert!(Float::MAX.is_integer());
+        assert!(Float::MAX_SAFE_INTEGER.is_integer());
+        assert!(tifloat!(-0x12500000000000 * 10 ^ 2).is_integer());
+        assert!(!tifloat!(-0x12500000000000 * 10 ^ 1).is_integer());
+        assert!(!Float::MIN_POSITIVE.is_integer());
+        assert!(!Float::undefined().is_integer());
+    }
+
+    #[test]
+    fn as_integer() {
+        assert_eq!(Float::ZERO.as_integer(), Some(0));
+        assert_eq!(
+            tifloat!(-0x12500000000000 * 10 ^ 2).as_integer(),
+            Some(-125)
+        );
+        assert_eq!(Float::MAX_SAFE_INTEGER.as_integer(), Some(99999999999999));
+        assert_eq!(
+            tifloat!(0x92233720368547 * 10 ^ 18).as_integer(),
+            Some(9223372036854700000)
+        );
+        assert_eq!(tifloat!(0x92233720368548 * 10 ^ 18).as_integer(), None);
+        assert_eq!(tifloat!(0x12500000000000 * 10 ^ 1).as_integer(), None);
+        assert_eq!(Float::MAX.as_integer(), None);
+    }
+
+    #[test]
+    fn integer_and_fractional_parts() {
+        let cases = [
+            // value, iPart(, fPart(, int(, ceil
+            (
+                tifloat!(0x25000000000000 * 10 ^ 0),
+                tifloat!(0x20000000000000 * 10 ^ 0),
+                tifloat!(0x50000000000000 * 10 ^ -1),
+                tifloat!(0x20000000000000 * 10 ^ 0),
+                tifloat!(0x30000000000000 * 10 ^ 0),
+            ),
+            (
+                tifloat!(-0x25000000000000 * 10 ^ 0),
+                tifloat!(-0x20000000000000 * 10 ^ 0),
+                tifloat!(-0x50000000000000 * 10 ^ -1),
+                tifloat!(-0x30000000000000 * 10 ^ 0),
+                tifloat!(-0x20000000000000 * 10 ^ 0),
+            ),
+            (
+                tifloat!(-0x12345678901234 * 10 ^ -5),
+                Float::ZERO,
+                tifloat!(-0x12345678901234 * 10 ^ -5),
+                -Float::ONE,
+                Float::ZERO,
+            ),
+            (
+                tifloat!(0x99999999999995 * 10 ^ 12),
+                tifloat!(0x99999999999990 * 10 ^ 12),
+                tifloat!(0x50000000000000 * 10 ^ -1),
+                tifloat!(0x99999999999990 * 10 ^ 12),
+                tifloat!(0x10000000000000 * 10 ^ 13),
+            ),
+            (
+                tifloat!(-0x99999999999999 * 10 ^ 0),
+                -tifloat!(0x90000000000000 * 10 ^ 0),
+                tifloat!(-0x99999999999990 * 10 ^ -1),
+                -tifloat!(0x10000000000000 * 10 ^ 1),
+                -tifloat!(0x90000000000000 * 10 ^ 0),
+            ),
+            (Float::MAX, Float::MAX, Float::ZERO, Float::MAX, Float::MAX),
+            (
+                Float::ZERO,
+                Float::ZERO,
+                Float::ZERO,
+                Float::ZERO,
+                Float::ZERO,
+            ),
+        ];
+
+        for (value, trunc, fract, floor, ceil) in cases {
+            assert_eq!(value.trunc(), trunc, "iPart({value:?})");
+            assert_eq!(value.fract(), fract, "fPart({value:?})");
+            assert_eq!(value.floor(), floor, "int({value:?})");
+            assert_eq!(value.ceil(), ceil, "ceil({value:?})");
+        }
+
+        assert!(Float::undefined().floor().is_undefined());
+        assert!(Float::undefined().fract().is_undefined());
+    }
+
+    #[test]
+    fn gcd_and_lcm() {
+        let integer = |value: u64| Float::from_scaled_integer(false, value as u128, 0).unwrap();
+
+        assert_eq!(integer(12).gcd(&integer(18)), Ok(integer(6)));
+        assert_eq!(integer(12).lcm(&integer(18)), Ok(integer(36)));
+        assert_eq!(integer(0).gcd(&integer(7)), Ok(integer(7)));
+        assert_eq!(integer(0).gcd(&integer(0)), Ok(Float::ZERO));
+        assert_eq!(integer(0).lcm(&integer(7)), Ok(Float::ZERO));
+        assert_eq!(
+            Float::MAX_SAFE_INTEGER.gcd(&integer(99_999_999_999_990)),
+            Ok(integer(9))
+        );
+        assert_eq!(
+            integer(99_999_999_999_973).lcm(&integer(99_999_999_999_971)),
+            Ok(tifloat!(0x99999999999944 * 10 ^ 27))
+        );
+        assert!(Float::undefined().lcm(&Float::ONE).unwrap().is_undefined());
+
+        for value in [
+            tifloat!(0x25000000000000 * 10 ^ 0),
+            -Float::ONE,
+            tifloat!(0x10000000000000 * 10 ^ 14),
+        ] {
+            assert_eq!(value.gcd(&Float::ONE), Err(FloatError::Domain), "{value:?}");
+            assert_eq!(Float::ONE.lcm(&value), Err(FloatError::Domain), "{value:?}");
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::io::{Error, ErrorKind, Read, Result, Write};
+use std::iter::FusedIterator;
+
+use crate::{Float, ParseFloatError};
+
+impl Float {
+    /// Reads exactly 9 bytes in the byte representation (flags first) and produces a Float.
+    ///
+    /// Fails with [`ErrorKind::UnexpectedEof`] if the reader runs out of bytes and with
+    /// [`ErrorKind::InvalidData`] if the bytes are not a valid Float.
+    pub fn read_from<R: Read>(mut reader: R) -> Result<Float> {
+        let mut bytes = [0u8; 9];
+        reader.read_exact(&mut bytes)?;
+
+        Float::from_raw_bytes(bytes)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, format!("invalid float: {err:?}")))
+    }
+
+    /// Writes exactly 9 bytes in the byte representation (flags first).
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<()> {
+        writer.write_all(&self.to_raw_bytes())
+    }
+}
+
+/// Decodes one 9-byte real, reporting the offset of the invalid byte within it.
+pub(crate) fn decode(bytes: [u8; 9]) -> std::result::Result<Float, (ParseFloatError, usize)> {
+    Float::from_raw_bytes(bytes).map_err(|err| {
+        let offset = match err {
+            ParseFloatError::InvalidFlags => 0,
+            ParseFloatError::InvalidExponent => 1,
+            _ => (2..9)
+                .find(|&i| bytes[i] >> 4 > 9 || bytes[i] & 0xF > 9)
+                .unwrap_or(2),
+        };
+
+        (err, offset)
+    })
+}
+
+/// An iterator over consecutive 9-byte reals in a slice, as TI lists and matrices store them.
+///
+/// Each item is a Float or an [`Error::Parse`](crate::Error::Parse) holding the offset of the
+/// invalid byte in the slice. A trailing partial real yields
+/// [`ParseFloatError::InvalidLength`] at the end of the slice. Decoding continues after invalid
+/// reals, so that one bad element doesn't hide the rest.
+#[derive(Debug, Clone)]
+pub struct FloatDecoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> FloatDecoder<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        FloatDecoder { bytes, offset: 0 }
+    }
+
+    /// The bytes that haven't been decoded yet.
+    pub fn remainder(&self) -> &'a [u8] {
+        &self.bytes[self.offset..]
+    }
+}
+
+impl Iterator for FloatDecoder<'_> {
+    type Item = std::result::Result<Float, crate::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remainder = self.remainder();
+        if remainder.is_empty() {
+            return None;
+        }
+
+        let start = self.offset;
+        let Some(bytes) = remainder.get(..9) else {
+            self.offset = self.bytes.len();
+            return Some(Err(ParseFloatError::InvalidLength.at(self.offset)));
+        };
+
+        self.offset += 9;
+        Some(decode(bytes.try_into().unwrap()).map_err(|(err, offset)| err.at(start + offset)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remainder().len().div_ceil(9);
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for FloatDecoder<'_> {}
+
+impl FusedIterator for FloatDecoder<'_> {}
+
+/// An iterator that reads consecutive 9-byte reals from a reader until it runs out, the streaming
+/// counterpart of [`FloatDecoder`].
+///
+/// An invalid real is an [`ErrorKind::InvalidData`] error wrapping an
+/// [`Error::Parse`](crate::Error::Parse) with the offset of the invalid byte in the stream, and
+/// reading continues after it. The reader running out partway through a real is an
+/// [`ErrorKind::UnexpectedEof`] error, which ends the iterator, as does any other I/O error.
+#[derive(Debug)]
+pub struct FloatReader<R> {
+    reader: R,
+    offset: usize,
+    done: bool,
+}
+
+impl<R: Read> FloatReader<R> {
+    pub fn new(reader: R) -> Self {
+        FloatReader {
+            reader,
+            offset: 0,
+            done: false,
+        }
+    }
+
+    /// The number of bytes consumed so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for FloatReader<R> {
+    type Item = Result<Float>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let start = self.offset;
+        let mut bytes = [0u8; 9];
+        let mut filled = 0;
+
+        while filled < 9 {
+            match self.reader.read(&mut bytes[filled..]) {
+                Ok(0) => break,
+                Ok(read) => {
+                    filled += read;
+                    self.offset += read;
+                }
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+
+        if filled < 9 {
+            self.done = true;
+
+            return (filled != 0).then(|| {
+                Err(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    ParseFloatError::InvalidLength.at(self.offset),
+                ))
+            });
+        }
+
+        Some(
+            decode(bytes).map_err(|(err, offset)| {
+                Error::new(ErrorKind::InvalidData, err.at(start + offset))
+            }),
+        )
+    }
+}
+
+impl<R: Read> FusedIterator for FloatReader<R> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    /// Three reals, the second with a non-BCD digit in its third mantissa byte, and two extra bytes.
+    fn list() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend(Float::PI.to_raw_bytes());
+        bytes.extend([0x00, 0x80, 0x10, 0x00, 0x0C, 0x00, 0x00, 0x00, 0x00]);
+        bytes.extend(Float::E.to_raw_bytes());
+        bytes.extend([0x00, 0x80]);
+
+        bytes
+    }
+
+    #[test]
+    fn round_trip() {
+        let floats = [
+            tifloat!(-0x55000000000000 * 10 ^ 5),
+            tifloat!(0x31415926535898 * 10 ^ 0),
+        ];
+
+        let mut buffer = Vec::new();
+        for float in floats {
+            float.write_to(&mut buffer).unwrap();
+        }
+        assert_eq!(buffer.len(), 18);
+
+        let mut reader = buffer.as_slice();
+        for float in floats {
+            assert_eq!(Float::read_from(&mut reader).unwrap(), float);
+        }
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn read_errors() {
+        let short = [0x00, 0x80, 0x10];
+        assert_eq!(
+            Float::read_from(short.as_slice()).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+
+        let invalid = [0x00, 0x80, 0xA0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        assert_eq!(
+            Float::read_from(invalid.as_slice()).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn decoder() {
+        let bytes = list();
+        let mut decoder = FloatDecoder::new(&bytes);
+
+        assert_eq!(decoder.len(), 4);
+        assert_eq!(decoder.next(), Some(Ok(Float::PI)));
+        assert_eq!(
+            decoder.next(),
+            Some(Err(ParseFloatError::InvalidMantissa.at(13)))
+        );
+        assert_eq!(decoder.next(), Some(Ok(Float::E)));
+        assert_eq!(decoder.remainder(), [0x00, 0x80]);
+        assert_eq!(
+            decoder.next(),
+            Some(Err(ParseFloatError::InvalidLength.at(29)))
+        );
+        assert_eq!(decoder.next(), None);
+
+        let floats: std::result::Result<Vec<Float>, _> = FloatDecoder::new(&bytes[..9]).collect();
+        assert_eq!(floats.unwrap(), [Float::PI]);
+    }
+
+    #[test]
+    fn reader() {
+        let bytes = list();
+        let mut reader = FloatReader::new(bytes.as_slice());
+
+        assert_eq!(reader.next().unwrap().unwrap(), Float::PI);
+
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(
+            err.get_ref().unwrap().downcast_ref::<crate::Error>(),
+            Some(&ParseFloatError::InvalidMantissa.at(13))
+        );
+
+        assert_eq!(reader.next().unwrap().unwrap(), Float::E);
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+        assert!(reader.next().is_none());
+        assert_eq!(reader.offset(), 29);
+
+        assert_eq!(FloatReader::new(&bytes[..18]).count(), 2);
+    }
+}
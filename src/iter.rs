@@ -0,0 +1,149 @@
+use std::iter::{Product, Sum};
+
+use crate::extended::Extended;
+use crate::{Float, FloatError};
+
+fn sum(iter: impl Iterator<Item = Float>) -> Result<Float, FloatError> {
+    iter.map(|float| Extended::new(&float))
+        .fold(Extended::new(&Float::ZERO), Extended::add)
+        .to_float()
+}
+
+fn product(iter: impl Iterator<Item = Float>) -> Result<Float, FloatError> {
+    iter.map(|float| Extended::new(&float))
+        .fold(Extended::new(&Float::ONE), Extended::mul)
+        .to_float()
+}
+
+/// Sums in extended precision, rounding once at the end.
+///
+/// # Panics
+///
+/// Panics if the sum is out of range; sum into a `Result<Float, FloatError>` to handle that.
+impl Sum for Float {
+    fn sum<I: Iterator<Item = Float>>(iter: I) -> Self {
+        sum(iter).unwrap_or_else(|err| panic!("attempt to add with {err:?}"))
+    }
+}
+
+impl<'a> Sum<&'a Float> for Float {
+    fn sum<I: Iterator<Item = &'a Float>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiplies in extended precision, rounding once at the end.
+///
+/// # Panics
+///
+/// Panics if the product is out of range; multiply into a `Result<Float, FloatError>` to handle
+/// that.
+impl Product for Float {
+    fn product<I: Iterator<Item = Float>>(iter: I) -> Self {
+        product(iter).unwrap_or_else(|err| panic!("attempt to multiply with {err:?}"))
+    }
+}
+
+impl<'a> Product<&'a Float> for Float {
+    fn product<I: Iterator<Item = &'a Float>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl Sum<Float> for Result<Float, FloatError> {
+    fn sum<I: Iterator<Item = Float>>(iter: I) -> Self {
+        sum(iter)
+    }
+}
+
+impl<'a> Sum<&'a Float> for Result<Float, FloatError> {
+    fn sum<I: Iterator<Item = &'a Float>>(iter: I) -> Self {
+        sum(iter.copied())
+    }
+}
+
+impl Product<Float> for Result<Float, FloatError> {
+    fn product<I: Iterator<Item = Float>>(iter: I) -> Self {
+        product(iter)
+    }
+}
+
+impl<'a> Product<&'a Float> for Result<Float, FloatError> {
+    fn product<I: Iterator<Item = &'a Float>>(iter: I) -> Self {
+        product(iter.copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn sum() {
+        let floats = [
+            tifloat!(0x10000000000000 * 10 ^ 14),
+            tifloat!(0x40000000000000 * 10 ^ -1),
+            tifloat!(0x40000000000000 * 10 ^ -1),
+            tifloat!(-0x10000000000000 * 10 ^ 14),
+        ];
+
+        // adding 0.4 to 1E14 one at a time would round it away twice
+        assert_eq!(
+            floats.iter().sum::<Float>(),
+            tifloat!(0x80000000000000 * 10 ^ -1)
+        );
+        assert!(matches!(
+            floats.into_iter().sum::<Result<Float, FloatError>>(),
+            Ok(float) if float == tifloat!(0x80000000000000 * 10 ^ -1)
+        ));
+        assert_eq!([].iter().sum::<Float>(), Float::ZERO);
+    }
+
+    #[test]
+    fn sum_overflow() {
+        let floats = [tifloat!(0x50000000000000 * 10 ^ 99); 3];
+
+        assert!(matches!(
+            floats.iter().sum::<Result<Float, FloatError>>(),
+            Err(FloatError::Overflow)
+        ));
+
+        // intermediate sums may leave the range
+        let floats = [
+            tifloat!(0x50000000000000 * 10 ^ 99),
+            tifloat!(0x50000000000000 * 10 ^ 99),
+            tifloat!(-0x90000000000000 * 10 ^ 99),
+        ];
+
+        assert_eq!(
+            floats.iter().sum::<Float>(),
+            tifloat!(0x10000000000000 * 10 ^ 99)
+        );
+    }
+
+    #[test]
+    fn product() {
+        let floats = [
+            tifloat!(0x30000000000000 * 10 ^ 0),
+            tifloat!(-0x20000000000000 * 10 ^ 0),
+            tifloat!(0x25000000000000 * 10 ^ -1),
+        ];
+
+        assert_eq!(
+            floats.iter().product::<Float>(),
+            tifloat!(-0x15000000000000 * 10 ^ 0)
+        );
+        assert_eq!([].iter().product::<Float>(), Float::ONE);
+        assert!([Float::ONE, Float::undefined()]
+            .iter()
+            .product::<Float>()
+            .is_undefined());
+
+        let floats = [tifloat!(0x10000000000000 * 10 ^ 60); 2];
+
+        assert!(matches!(
+            floats.into_iter().product::<Result<Float, FloatError>>(),
+            Err(FloatError::Overflow)
+        ));
+    }
+}
@@ -1,7 +1,97 @@
+/// Records a [`trace::Step`] while a capture is in progress, if the `trace` feature is enabled,
+/// and compiles to nothing otherwise.
+macro_rules! trace {
+    ($step:expr) => {
+        #[cfg(feature = "trace")]
+        $crate::trace::record(|| $step);
+    };
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "approx")]
+mod approx;
+mod backend;
+mod builder;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod checked;
+mod classify;
+mod compare;
+mod context;
+mod convert;
+mod dms;
+mod exp;
+mod extended;
+mod factorial;
+mod fixed;
 mod float;
+mod format;
+mod fraction;
+mod guard;
+mod integer;
+mod io;
+mod iter;
+mod log;
 mod mantissa;
+mod measured;
+mod mixed;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+mod parse;
+mod pow;
+mod pow10;
+#[cfg(feature = "rand")]
+mod rand;
+mod radix;
+mod range;
+mod rem;
+mod roots;
+mod round;
+mod rounding;
+#[cfg(feature = "serde")]
+mod serde;
+mod sign;
+mod trig;
+mod uint;
+mod ulp;
+mod view;
+mod wide;
 
-pub use float::Float;
+#[cfg(feature = "fast")]
+pub use backend::FastFloat;
+pub use backend::TIFloat;
+pub use builder::FloatBuilder;
+pub use classify::FloatKind;
+pub use context::{AngleMode, ComplexMode, Context};
+pub use float::{Flags, Float, ParseFloatError};
+pub use format::{
+    DecimalMode, DisplayMode, FormatOptions, DISPLAY_DIGITS, MAX_FIX_DIGITS, NORMAL_MAX_EXPONENT,
+    NORMAL_MIN_EXPONENT, UNDEFINED,
+};
+pub use guard::GuardedFloat;
+pub use io::{FloatDecoder, FloatReader};
+pub use measured::Measured;
+#[cfg(feature = "nalgebra")]
+pub use crate::nalgebra::MatrixFloat;
+#[cfg(feature = "rand")]
+pub use crate::rand::UniformFloatSampler;
+pub use range::FloatRange;
+pub use rounding::RoundingMode;
+pub use view::{FloatRef, RawFloat};
+pub use wide::WideFloat;
 
 pub mod error;
-pub use error::FloatError;
+pub mod exact;
+pub mod formreal;
+pub mod nspire;
+pub mod op;
+pub mod ti68k;
+pub mod ti86;
+#[cfg(feature = "titokens")]
+pub mod tokens;
+#[cfg(feature = "trace")]
+pub mod trace;
+#[cfg(feature = "verification")]
+pub mod verification;
+pub use error::{Error, FloatError, OsError, UnderflowMode};
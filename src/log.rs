@@ -0,0 +1,160 @@
+use crate::fixed::Fixed;
+use crate::{Float, FloatError};
+
+impl Float {
+    /// The natural logarithm, computed to more than 30 digits and rounded half-up to 14.
+    ///
+    /// Fails with [`FloatError::Domain`] for zero and negative values. The logarithm of an
+    /// undefined Float is undefined.
+    pub fn ln(&self) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() || self.is_negative() {
+            return Err(FloatError::Domain);
+        }
+
+        Fixed::ln(self).to_float()
+    }
+
+    /// The common (base-10) logarithm, computed to more than 30 digits and rounded half-up to
+    /// 14. The integer part comes straight from the exponent, so powers of ten have exact
+    /// integer logs.
+    ///
+    /// Fails with [`FloatError::Domain`] for zero and negative values. The logarithm of an
+    /// undefined Float is undefined.
+    pub fn log10(&self) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() || self.is_negative() {
+            return Err(FloatError::Domain);
+        }
+
+        Fixed::log10(self).to_float()
+    }
+
+    /// The logarithm to the given base, as `logBASE(` computes it, rounded once from the ratio
+    /// of two logs computed to more than 30 digits. Exact powers of the base have exact integer
+    /// logs, so the log of `8` to base `2` is `3`.
+    ///
+    /// Fails with [`FloatError::Domain`] if the value or the base is zero or negative, and with
+    /// [`FloatError::DivideByZero`] if the base is `1`.
+    #[doc(alias = "logBASE")]
+    pub fn log_base(&self, base: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || base.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() || self.is_negative() || base.is_zero() || base.is_negative() {
+            return Err(FloatError::Domain);
+        } else if *base == Float::ONE {
+            return Err(FloatError::DivideByZero);
+        }
+
+        Fixed::log10(self).div_to_float(Fixed::log10(base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn ln() {
+        assert_eq!(Float::ONE.ln(), Ok(Float::ZERO));
+        assert_eq!(
+            tifloat!(0x20000000000000 * 10 ^ 0).ln(),
+            Ok(tifloat!(0x69314718055995 * 10 ^ -1))
+        );
+        assert_eq!(
+            tifloat!(0x27182818284590 * 10 ^ 0).ln(),
+            Ok(tifloat!(0x99999999999998 * 10 ^ -1))
+        );
+        assert_eq!(Float::PI.ln(), Ok(tifloat!(0x11447298858494 * 10 ^ 0)));
+        assert_eq!(Float::MAX.ln(), Ok(tifloat!(0x23025850929940 * 10 ^ 2)));
+        assert_eq!(
+            Float::MIN_POSITIVE.ln(),
+            Ok(tifloat!(-0x22795592420641 * 10 ^ 2))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000001 * 10 ^ 0).ln(),
+            Ok(tifloat!(0x99999999999995 * 10 ^ -14))
+        );
+        assert_eq!(
+            tifloat!(0x99999999999999 * 10 ^ -1).ln(),
+            Ok(tifloat!(-0x10000000000000 * 10 ^ -14))
+        );
+        assert!(Float::undefined().ln().unwrap().is_undefined());
+
+        assert_eq!(Float::ZERO.ln(), Err(FloatError::Domain));
+        assert_eq!((-Float::ONE).ln(), Err(FloatError::Domain));
+    }
+
+    #[test]
+    fn log10() {
+        assert_eq!(Float::ONE.log10(), Ok(Float::ZERO));
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 42).log10(),
+            Ok(tifloat!(0x42000000000000 * 10 ^ 1))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ -7).log10(),
+            Ok(tifloat!(-0x70000000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x20000000000000 * 10 ^ 0).log10(),
+            Ok(tifloat!(0x30102999566398 * 10 ^ -1))
+        );
+        assert_eq!(
+            tifloat!(0x50000000000000 * 10 ^ -3).log10(),
+            Ok(tifloat!(-0x23010299956640 * 10 ^ 0))
+        );
+        assert_eq!(Float::MAX.log10(), Ok(tifloat!(0x10000000000000 * 10 ^ 2)));
+        assert_eq!(
+            Float::MIN_POSITIVE.log10(),
+            Ok(tifloat!(-0x99000000000000 * 10 ^ 1))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000001 * 10 ^ 0).log10(),
+            Ok(tifloat!(0x43429448190323 * 10 ^ -14))
+        );
+        assert!(Float::undefined().log10().unwrap().is_undefined());
+
+        assert_eq!(Float::ZERO.log10(), Err(FloatError::Domain));
+        assert_eq!((-Float::ONE).log10(), Err(FloatError::Domain));
+    }
+
+    #[test]
+    fn log_base() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+
+        assert_eq!(
+            tifloat!(0x80000000000000 * 10 ^ 0).log_base(&two),
+            Ok(three)
+        );
+        assert_eq!(
+            tifloat!(0x24300000000000 * 10 ^ 2).log_base(&three),
+            Ok(tifloat!(0x50000000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x80000000000000 * 10 ^ -3).log_base(&tifloat!(0x50000000000000 * 10 ^ 0)),
+            Ok(-three)
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 6).log_base(&tifloat!(0x10000000000000 * 10 ^ -3)),
+            Ok(-two)
+        );
+        assert_eq!(
+            three.log_base(&two),
+            Ok(tifloat!(0x15849625007212 * 10 ^ 0))
+        );
+        assert_eq!(
+            Float::MAX.log_base(&tifloat!(0x10000000000001 * 10 ^ 0)),
+            Ok(tifloat!(0x23025850929942 * 10 ^ 15))
+        );
+        assert_eq!(Float::ONE.log_base(&two), Ok(Float::ZERO));
+        assert!(two.log_base(&Float::undefined()).unwrap().is_undefined());
+
+        assert_eq!(Float::ZERO.log_base(&two), Err(FloatError::Domain));
+        assert_eq!(two.log_base(&-two), Err(FloatError::Domain));
+        assert_eq!(two.log_base(&Float::ONE), Err(FloatError::DivideByZero));
+    }
+}
@@ -1,5 +1,9 @@
+use std::fmt::Debug;
 use std::ops::{Add, Sub};
 
+use crate::uint::U256;
+use crate::RoundingMode;
+
 const DEC_TO_BCD: [u64; 100] = [
     0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, //
     0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, //
@@ -13,20 +17,82 @@ const DEC_TO_BCD: [u64; 100] = [
     0x90, 0x91, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, //
 ];
 
+/// Digits of the smaller term of a sum kept past the larger term's last digit, before a sticky
+/// digit.
+const SUM_GUARD_DIGITS: u32 = 3;
+
+/// Repeats a digit in each of the low `digits` nibbles.
+const fn repeat(digit: u128, digits: usize) -> u128 {
+    let mut result = 0;
+    let mut index = 0;
+    while index < digits {
+        result = result << 4 | digit;
+        index += 1;
+    }
+
+    result
+}
+
+/// Whether `data` holds only decimal digits, all in its low `digits` nibbles.
+const fn is_packed_bcd(data: u128, digits: usize) -> bool {
+    let sevens = repeat(0x7, digits);
+    let threes = repeat(0x3, digits);
+    let eights = repeat(0x8, digits);
+
+    0 == (((((data >> 1) & sevens) + threes) & eights) | (data & !repeat(0xF, digits)))
+}
+
+/// An unsigned integer that a mantissa's packed digits are stored in. Arithmetic is done on the
+/// digits widened to a `u128`.
+pub(crate) trait Word: Copy + Debug + Eq {
+    /// The most digits a mantissa stored in this word can have, leaving room in a `u128` for
+    /// a carry out of its leading digit.
+    const MAX_DIGITS: usize;
+
+    fn widen(self) -> u128;
+
+    /// Truncates digits widened by [`Word::widen`] back to this word.
+    fn narrow(data: u128) -> Self;
+}
+
+impl Word for u64 {
+    const MAX_DIGITS: usize = 16;
+
+    fn widen(self) -> u128 {
+        self as u128
+    }
+
+    fn narrow(data: u128) -> Self {
+        data as u64
+    }
+}
+
+impl Word for u128 {
+    const MAX_DIGITS: usize = 31;
+
+    fn widen(self) -> u128 {
+        self
+    }
+
+    fn narrow(data: u128) -> Self {
+        data
+    }
+}
+
+/// A packed-BCD mantissa of `DIGITS` digits with the MSD in the highest nibble, stored in a `W`.
+/// Floats use 14 digits and the 68k calculators use 16, which fit in a `u64`; guarded values use
+/// 18 and wide values 28, which take a `u128`.
 #[derive(Copy, Clone, Debug)]
-pub(crate) struct Mantissa {
-    data: u64,
+pub(crate) struct Mantissa<const DIGITS: usize = 14, W = u64> {
+    data: W,
 }
 
+#[allow(dead_code)]
 impl Mantissa {
     pub const PI: Mantissa = Mantissa {
         data: 0x0031415926535898,
     };
 
-    pub const ONE: Mantissa = Mantissa {
-        data: 0x0010000000000000,
-    };
-
     pub const FIVE: Mantissa = Mantissa {
         data: 0x0050000000000000,
     };
@@ -34,102 +100,134 @@ impl Mantissa {
     pub const E: Mantissa = Mantissa {
         data: 0x0027182818284590,
     };
+}
 
-    pub const ULP: Mantissa = Mantissa {
-        data: 0x0000000000000001,
+impl<const DIGITS: usize> Mantissa<DIGITS, u64> {
+    pub const ONE: Self = Mantissa {
+        data: 1 << (4 * (DIGITS - 1)),
     };
+
+    pub const ULP: Self = Mantissa { data: 1 };
+
+    pub const fn check(&self) -> bool {
+        is_packed_bcd(self.data as u128, DIGITS)
+    }
 }
 
-impl Mantissa {
-    pub const MASK: u64 = 0x00FFFFFFFFFFFFFF;
+impl<const DIGITS: usize, W: Word> Mantissa<DIGITS, W> {
+    pub const MASK: u128 = {
+        assert!(
+            DIGITS >= 1 && DIGITS <= W::MAX_DIGITS,
+            "a mantissa has at least one digit and fits in its word"
+        );
+
+        repeat(0xF, DIGITS)
+    };
+    /// The number of decimal digits in the Mantissa
+    pub const DIGITS: usize = DIGITS;
     /// The maximum value that the Mantissa can store, in base 10
-    pub const MAX_10: u64 = 99999999999999;
+    pub const MAX_10: u128 = 10u128.pow(DIGITS as u32) - 1;
+
+    pub fn tens_complement(&self) -> Self {
+        // the complement of zero wraps around to zero
+        if self.is_zero() {
+            return *self;
+        }
 
-    pub fn tens_complement(&self) -> Mantissa {
-        let t1 = (!0) - self.data;
+        let t1 = (!0) - self.data.widen();
         let t2 = t1 + 0x1;
         let t3 = t1 ^ 0x1;
         let t4 = t2 ^ t3;
-        let t5 = !t4 & 0x1111111111111110;
+        let t5 = !t4 & (repeat(0x1, 32) - 1);
         let t6 = (t5 >> 2) | (t5 >> 3);
 
-        Mantissa {
-            data: (t2 - t6) & Mantissa::MASK,
-        }
+        Mantissa::from_unchecked(W::narrow((t2 - t6) & Self::MASK))
     }
 
     #[cfg(test)]
     pub fn hex(&self) -> String {
-        format!("{:X}", self.data)
+        format!("{:X}", self.data.widen())
     }
 
-    pub fn bits(&self) -> u64 {
+    pub fn bits(&self) -> W {
         self.data
     }
 
     pub fn is_zero(&self) -> bool {
-        self.data == 0
-    }
-
-    pub fn msd(&self) -> u8 {
-        ((self.data >> (13 * 4)) & 0xFF) as u8
+        self.data.widen() == 0
     }
 
-    pub fn from(bits: u64) -> Option<Self> {
-        if 0 != (((((bits >> 1) & 0x0077777777777777) + 0x0033333333333333) & 0x0088888888888888)
-            | (bits & !Mantissa::MASK))
-        {
-            None
-        } else {
+    pub fn from(bits: W) -> Option<Self> {
+        if is_packed_bcd(bits.widen(), DIGITS) {
             Some(Mantissa { data: bits })
+        } else {
+            None
         }
     }
 
-    pub const fn from_unchecked(bits: u64) -> Self {
+    pub const fn from_unchecked(bits: W) -> Self {
         Mantissa { data: bits }
     }
 
-    pub fn check(&self) -> bool {
-        0 == (((((self.data >> 1) & 0x0077777777777777) + 0x0033333333333333) & 0x0088888888888888)
-            | (self.data & !Mantissa::MASK))
-    }
-
-    pub fn to_dec(self) -> u64 {
-        let mut output = 0u64;
-        for byte in self.data.to_be_bytes() {
-            output = output * 100 + (byte - 6 * (byte >> 4)) as u64;
+    pub fn to_dec(self) -> u128 {
+        let mut output = 0u128;
+        for byte in self.data.widen().to_be_bytes() {
+            output = output * 100 + (byte - 6 * (byte >> 4)) as u128;
         }
 
         output
     }
 
-    pub fn from_dec(mut data: u64) -> Self {
+    pub fn from_dec(mut data: u128) -> Self {
         let mut result = 0;
         let mut shift = 0;
 
-        for _ in 0..8 {
-            result += (DEC_TO_BCD[(data % 100) as usize]) << shift;
+        for _ in 0..DIGITS.div_ceil(2) {
+            result += (DEC_TO_BCD[(data % 100) as usize] as u128) << shift;
             data /= 100;
             shift += 8;
         }
 
-        Mantissa { data: result }
+        Mantissa::from_unchecked(W::narrow(result))
     }
 
-    pub fn from_dec_normalized(mut data: u64) -> (Self, u8) {
+    pub fn from_dec_normalized(data: u128) -> (Self, u8) {
         if data == 0 {
-            return (Mantissa::from_unchecked(data), 0)
+            return (Mantissa::from_dec(0), 0);
         }
 
-        let mut mantissa = Mantissa::from_dec(data);
+        let mantissa = Self::from_dec(data);
 
-        let mut count = (mantissa.data.leading_zeros()/4) as u8 - 2;
+        let count = (mantissa.data.widen().leading_zeros() / 4) as u8 - (32 - DIGITS as u8);
 
         (mantissa.shl(count), count)
     }
+
+    /// Rounds a decimal integer to `DIGITS` significant digits according to `mode`, returning
+    /// the mantissa and the place-value of its leading digit, or `None` if the integer is zero.
+    pub fn from_dec_rounded(data: u128, mode: RoundingMode) -> Option<(Self, i32)> {
+        let mut place = data.checked_ilog10()? as i32;
+
+        // round away every digit past the first `DIGITS` at once. an integer with fewer digits
+        // than that is exact, and is shifted left instead
+        let dropped = place - (DIGITS as i32 - 1);
+        let mut rounded = if dropped > 0 {
+            mode.divide(data, 10u128.pow(dropped as u32))
+        } else {
+            data * 10u128.pow(-dropped as u32)
+        };
+
+        // rounding 99...9|5 up carries into another digit, followed by zeros
+        if rounded > Self::MAX_10 {
+            rounded /= 10;
+            place += 1;
+        }
+
+        Some((Mantissa::from_dec(rounded), place))
+    }
 }
 
-impl Add for Mantissa {
+impl<const DIGITS: usize, W: Word> Add for Mantissa<DIGITS, W> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -137,15 +235,15 @@ impl Add for Mantissa {
     }
 }
 
-impl PartialEq for Mantissa {
+impl<const DIGITS: usize, W: Word> PartialEq for Mantissa<DIGITS, W> {
     fn eq(&self, other: &Self) -> bool {
         self.data == other.data
     }
 }
 
-impl Eq for Mantissa {}
+impl<const DIGITS: usize, W: Word> Eq for Mantissa<DIGITS, W> {}
 
-impl Sub for Mantissa {
+impl<const DIGITS: usize, W: Word> Sub for Mantissa<DIGITS, W> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -154,27 +252,26 @@ impl Sub for Mantissa {
 }
 
 /// # Core operations
-impl Mantissa {
+impl<const DIGITS: usize, W: Word> Mantissa<DIGITS, W> {
     /// Returns the unnormalized sum and the overflow flag.
     pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
         if rhs.is_zero() {
             return (self, false);
         }
 
-        let t1 = self.data + 0x0066666666666666;
-        let t2 = t1 + rhs.data;
-        let t3 = t1 ^ rhs.data;
+        // widened so that a sum of the most digits a word holds has room for its carry
+        let t1 = self.data.widen() + repeat(0x6, DIGITS);
+        let t2 = t1 + rhs.data.widen();
+        let t3 = t1 ^ rhs.data.widen();
         let t4 = t2 ^ t3;
-        let t5 = !t4 & 0x0111111111111110;
+        let t5 = !t4 & (repeat(0x1, DIGITS) << 4);
         let t6 = (t5 >> 2) | (t5 >> 3);
 
         let result = t2 - t6;
 
         (
-            Mantissa {
-                data: result & Mantissa::MASK,
-            },
-            (result & !Mantissa::MASK) != 0,
+            Mantissa::from_unchecked(W::narrow(result & Self::MASK)),
+            result >> (4 * DIGITS) != 0,
         )
     }
 
@@ -182,65 +279,112 @@ impl Mantissa {
     ///
     /// Differences can require multiple shls to normalize- consider 102-101=001
     pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
-        if self.data > rhs.data {
+        if self.data.widen() > rhs.data.widen() {
             (self.overflowing_add(rhs.tens_complement()).0, false)
         } else {
             (rhs.overflowing_add(self.tens_complement()).0, true)
         }
     }
 
-    /// Returns the unnormalized product and the overflow flag
-    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
-        let full_product = (self.to_dec() as u128) * (rhs.to_dec() as u128);
-
-        let half_product: u64 = (full_product / 10_u128.pow(13)).try_into().unwrap();
-        let mantissa = Mantissa::from_dec(half_product);
+    /// Adds `rhs`, shifted `distance` digits right, to `self`, or subtracts it if `subtract` is
+    /// set, and rounds the exact result to `DIGITS` digits once according to `mode`.
+    ///
+    /// Returns the rounded mantissa, the place-value of its leading digit relative to `self`'s,
+    /// and whether a difference borrowed, which flips its sign; or `None` if the result is zero.
+    pub fn rounded_sum(
+        self,
+        rhs: Self,
+        distance: u32,
+        subtract: bool,
+        mode: RoundingMode,
+    ) -> Option<(Self, i32, bool)> {
+        trace!(crate::trace::Step::Align {
+            distance: distance.min(u8::MAX as u32) as u8,
+            significand: rhs.to_dec(),
+        });
+
+        // the smaller term keeps SUM_GUARD_DIGITS digits past the larger's last digit, then a
+        // sticky digit which is nonzero if anything was shifted out past those. a difference only
+        // loses more than one leading digit when nothing was shifted out, so the sticky digit is
+        // always below the digits that decide the rounding.
+        let scale = 10u128.pow(SUM_GUARD_DIGITS + 1);
+        let larger = self.to_dec() * scale;
+        let smaller = match 10u128.checked_pow(distance) {
+            Some(divisor) => {
+                let aligned = rhs.to_dec() * 10u128.pow(SUM_GUARD_DIGITS);
+                aligned / divisor * 10 + !aligned.is_multiple_of(divisor) as u128
+            }
+            None => !rhs.is_zero() as u128,
+        };
 
-        (mantissa, half_product > Mantissa::MAX_10)
+        let (sum, borrowed) = if !subtract {
+            (larger + smaller, false)
+        } else if larger >= smaller {
+            (larger - smaller, false)
+        } else {
+            (smaller - larger, true)
+        };
+        trace!(crate::trace::Step::Combine {
+            significand: sum,
+            carry: borrowed || sum > Self::MAX_10 * scale,
+        });
+
+        let (mantissa, place) = Mantissa::from_dec_rounded(sum, mode)?;
+
+        Some((
+            mantissa,
+            place - (DIGITS as i32 - 1) - SUM_GUARD_DIGITS as i32 - 1,
+            borrowed,
+        ))
     }
 
-    /// Returns the unnormalized quotient and a flag indicating if normalization
-    /// (via a single right shift) is required.
-    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
-        let dividend = (self.to_dec() as u128) * 10_u128.pow(14);
-        let divisor = rhs.to_dec() as u128;
-
-        let quotient = ((dividend + (divisor >> 1)) / divisor).try_into().unwrap();
+    /// Returns the product rounded to `DIGITS` digits and a flag indicating that it has one more
+    /// integer digit than its factors, which raises the exponent by one.
+    pub fn overflowing_mul(self, rhs: Self, mode: RoundingMode) -> (Self, bool) {
+        let full_product = U256::mul(self.to_dec(), rhs.to_dec());
 
-        let mantissa = Mantissa::from_dec(quotient);
+        let shift = full_product >= U256::mul(10_u128.pow(DIGITS as u32 - 1), Self::MAX_10 + 1);
+        let divisor = 10_u128.pow(if shift { DIGITS } else { DIGITS - 1 } as u32);
 
-        (mantissa, quotient > Mantissa::MAX_10)
+        Mantissa::normalize_rounded(mode.divide_wide(full_product, divisor), shift)
     }
 
-    #[allow(clippy::should_implement_trait)]
-    pub fn shr(self, distance: u8) -> Self {
-        if distance >= 15 {
-            return Mantissa { data: 0 };
-        }
+    /// Returns the quotient rounded to `DIGITS` digits and a flag indicating that it has one
+    /// more integer digit than a quotient of a smaller dividend mantissa would, which raises the
+    /// exponent by one.
+    pub fn overflowing_div(self, rhs: Self, mode: RoundingMode) -> (Self, bool) {
+        let dividend = self.to_dec();
+        let divisor = rhs.to_dec();
 
-        let mut result = self.data >> ((distance * 4) as u64);
+        let shift = dividend >= divisor;
+        let scale = 10_u128.pow(if shift { DIGITS - 1 } else { DIGITS } as u32);
 
-        // rounding
-        if distance != 0 && (self.data >> (((distance - 1) * 4) as u64) & 0xF) >= 5 {
-            result = (Mantissa { data: result } + Mantissa::ULP).data;
-        }
+        Mantissa::normalize_rounded(mode.divide_wide(U256::mul(dividend, scale), divisor), shift)
+    }
 
-        Mantissa { data: result }
+    /// Rounding `99999999999999|5` up carries into another digit, which is always followed by
+    /// zeros and can be dropped exactly.
+    fn normalize_rounded(rounded: u128, shift: bool) -> (Self, bool) {
+        if rounded > Self::MAX_10 {
+            (Mantissa::from_dec(rounded / 10), true)
+        } else {
+            (Mantissa::from_dec(rounded), shift)
+        }
     }
 
     #[allow(clippy::should_implement_trait)]
     pub fn shl(self, distance: u8) -> Self {
-        Mantissa {
-            data: (self.data << ((distance * 4) as u64)) & Mantissa::MASK,
-        }
+        Mantissa::from_unchecked(W::narrow(
+            (self.data.widen() << (distance as u32 * 4)) & Self::MASK,
+        ))
     }
 }
 
-impl Mantissa {
+impl<const DIGITS: usize, W: Word> Mantissa<DIGITS, W> {
     pub fn digits(&self) -> Vec<u8> {
-        let mut nibbles = Vec::with_capacity(16);
-        for i in (0..14).rev() {
-            let nibble = (self.data >> 4*i) & 0x0F;
+        let mut nibbles = Vec::with_capacity(DIGITS);
+        for i in (0..DIGITS).rev() {
+            let nibble = (self.data.widen() >> (4 * i)) & 0x0F;
             nibbles.push(nibble as u8);
         }
 
@@ -252,6 +396,10 @@ impl Mantissa {
 mod tests {
     use super::*;
 
+    type Mantissa = super::Mantissa<14>;
+    type Mantissa16 = super::Mantissa<16>;
+    type Mantissa28 = super::Mantissa<28, u128>;
+
     #[test]
     fn add() {
         const SUM: Mantissa = Mantissa {
@@ -275,19 +423,6 @@ mod tests {
         assert_eq!(overflowed_sub.0.hex(), Mantissa::ONE.hex());
     }
 
-    #[test]
-    fn shr() {
-        const ONE: Mantissa = Mantissa {
-            data: 0x0010000000000000,
-        };
-
-        const BASICALLY_TEN: Mantissa = Mantissa {
-            data: 0x0099999999999999,
-        };
-
-        assert_eq!(BASICALLY_TEN.shr(1).hex(), ONE.hex());
-    }
-
     #[test]
     fn to_from_dec() {
         assert_eq!(Mantissa::from_dec(31415926535898), Mantissa::PI);
@@ -297,15 +432,15 @@ mod tests {
     #[test]
     fn mul() {
         assert_eq!(
-            Mantissa::PI.overflowing_mul(Mantissa::ONE),
+            Mantissa::PI.overflowing_mul(Mantissa::ONE, RoundingMode::HalfUp),
             (Mantissa::PI, false)
         );
 
         assert_eq!(
-            Mantissa::FIVE.overflowing_mul(Mantissa::FIVE),
+            Mantissa::FIVE.overflowing_mul(Mantissa::FIVE, RoundingMode::HalfUp),
             (
                 Mantissa {
-                    data: 0x0250000000000000
+                    data: 0x0025000000000000
                 },
                 true
             )
@@ -315,9 +450,12 @@ mod tests {
             (Mantissa {
                 data: 0x0014285714285714
             })
-            .overflowing_mul(Mantissa {
-                data: 0x0070000000000000
-            }),
+            .overflowing_mul(
+                Mantissa {
+                    data: 0x0070000000000000
+                },
+                RoundingMode::HalfUp
+            ),
             (
                 Mantissa {
                     data: 0x0099999999999998
@@ -333,9 +471,12 @@ mod tests {
             Mantissa {
                 data: 0x6000000000000000
             }
-            .overflowing_div(Mantissa {
-                data: 0x7000000000000000
-            }),
+            .overflowing_div(
+                Mantissa {
+                    data: 0x7000000000000000
+                },
+                RoundingMode::HalfUp
+            ),
             (
                 Mantissa {
                     data: 0x0085714285714286
@@ -348,9 +489,12 @@ mod tests {
             Mantissa {
                 data: 0x1000000000000000
             }
-            .overflowing_div(Mantissa {
-                data: 0x3000000000000000
-            }),
+            .overflowing_div(
+                Mantissa {
+                    data: 0x3000000000000000
+                },
+                RoundingMode::HalfUp
+            ),
             (
                 Mantissa {
                     data: 0x0033333333333333
@@ -363,9 +507,12 @@ mod tests {
             Mantissa {
                 data: 0x0355000000000000
             }
-            .overflowing_div(Mantissa {
-                data: 0x1130000000000000
-            }),
+            .overflowing_div(
+                Mantissa {
+                    data: 0x1130000000000000
+                },
+                RoundingMode::HalfUp
+            ),
             (
                 Mantissa {
                     data: 0x0031415929203540
@@ -375,6 +522,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mul_rounding() {
+        let a = Mantissa {
+            data: 0x0025000000000000,
+        };
+        let b = Mantissa {
+            data: 0x0010000000000001,
+        };
+
+        assert_eq!(
+            a.overflowing_mul(b, RoundingMode::HalfUp).0.hex(),
+            "25000000000003"
+        );
+        assert_eq!(
+            a.overflowing_mul(b, RoundingMode::HalfEven).0.hex(),
+            "25000000000002"
+        );
+        assert_eq!(
+            a.overflowing_mul(b, RoundingMode::Truncate).0.hex(),
+            "25000000000002"
+        );
+
+        // 99999999999999.9999999999990 rounds up into a 15th digit
+        let c = Mantissa {
+            data: 0x0010000000000001,
+        };
+        let d = Mantissa {
+            data: 0x0099999999999990,
+        };
+
+        assert_eq!(
+            c.overflowing_mul(d, RoundingMode::HalfUp),
+            (Mantissa::ONE, true)
+        );
+        assert_eq!(
+            c.overflowing_mul(d, RoundingMode::Truncate).0.hex(),
+            "99999999999999"
+        );
+    }
+
+    #[test]
+    fn div_rounding() {
+        let two = Mantissa {
+            data: 0x0020000000000000,
+        };
+        let three = Mantissa {
+            data: 0x0030000000000000,
+        };
+
+        assert_eq!(
+            two.overflowing_div(three, RoundingMode::HalfUp),
+            (
+                Mantissa {
+                    data: 0x0066666666666667
+                },
+                false
+            )
+        );
+        assert_eq!(
+            two.overflowing_div(three, RoundingMode::Truncate).0.hex(),
+            "66666666666666"
+        );
+        assert_eq!(
+            three.overflowing_div(two, RoundingMode::HalfEven),
+            (
+                Mantissa {
+                    data: 0x0015000000000000
+                },
+                true
+            )
+        );
+    }
+
     #[test]
     fn digits() {
         assert_eq!(
@@ -385,4 +605,92 @@ mod tests {
             vec![1, 4, 2, 8, 5, 7, 1, 4, 2, 8, 5, 7, 1, 4]
         )
     }
+
+    #[test]
+    fn sixteen_digits() {
+        let a = Mantissa16::from(0x9876543210987654).unwrap();
+        let b = Mantissa16::from(0x5000000000000006).unwrap();
+
+        assert!(Mantissa16::from(0x000000000000000A).is_none());
+        assert_eq!(a.digits().len(), 16);
+        assert_eq!(
+            a.overflowing_add(b),
+            (Mantissa16::from_unchecked(0x4876543210987660), true)
+        );
+        assert_eq!(
+            a.overflowing_mul(Mantissa16::ONE, RoundingMode::HalfUp),
+            (a, false)
+        );
+    }
+
+    #[test]
+    fn wide_words() {
+        let one = Mantissa28::from_dec(10u128.pow(27));
+        let three = Mantissa28::from_dec(3 * 10u128.pow(27));
+        let third = Mantissa28::from_dec(3333333333333333333333333333);
+
+        assert!(Mantissa28::from(0xA).is_none());
+        assert_eq!(third.digits(), vec![3; 28]);
+        assert_eq!(
+            Mantissa28::from_dec(1).tens_complement().to_dec(),
+            Mantissa28::MAX_10
+        );
+        assert_eq!(
+            Mantissa28::from_dec_normalized(12),
+            (Mantissa28::from_dec(12 * 10u128.pow(26)), 26)
+        );
+
+        // products and dividends past a u128
+        assert_eq!(
+            one.overflowing_div(three, RoundingMode::HalfUp),
+            (third, false)
+        );
+        assert_eq!(
+            third.overflowing_mul(three, RoundingMode::HalfUp),
+            (Mantissa28::from_dec(Mantissa28::MAX_10), false)
+        );
+        assert_eq!(
+            three.overflowing_mul(three, RoundingMode::HalfUp),
+            (Mantissa28::from_dec(9 * 10u128.pow(27)), false)
+        );
+    }
+
+    #[test]
+    fn rounded_sum() {
+        let one = Mantissa::ONE;
+
+        // 1 + .00000000000015 rounds once, up in the last digit
+        let tiny = Mantissa::from_dec(15 * 10u128.pow(12));
+        assert_eq!(
+            one.rounded_sum(tiny, 13, false, RoundingMode::HalfUp),
+            Some((Mantissa::from_dec(10000000000002), 0, false))
+        );
+        assert_eq!(
+            one.rounded_sum(tiny, 13, false, RoundingMode::Truncate),
+            Some((Mantissa::from_dec(10000000000001), 0, false))
+        );
+
+        // anything shifted out past the guard digits still borrows from a difference
+        assert_eq!(
+            one.rounded_sum(one, 40, true, RoundingMode::Truncate),
+            Some((Mantissa::from_dec(Mantissa::MAX_10), -1, false))
+        );
+        assert_eq!(
+            Mantissa::FIVE.rounded_sum(Mantissa::FIVE, 0, false, RoundingMode::HalfUp),
+            Some((one, 1, false))
+        );
+        assert_eq!(
+            Mantissa::PI.rounded_sum(Mantissa::FIVE, 0, true, RoundingMode::HalfUp),
+            Some((Mantissa::from_dec(18584073464102), 0, true))
+        );
+        assert_eq!(one.rounded_sum(one, 0, true, RoundingMode::HalfUp), None);
+
+        // a 28-digit sum rounds the same way
+        let one = Mantissa28::from_dec(10u128.pow(27));
+        let tiny = Mantissa28::from_dec(15 * 10u128.pow(26));
+        assert_eq!(
+            one.rounded_sum(tiny, 27, false, RoundingMode::HalfUp),
+            Some((Mantissa28::from_dec(10u128.pow(27) + 2), 0, false))
+        );
+    }
 }
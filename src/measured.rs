@@ -0,0 +1,207 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Float, FloatError};
+
+/// A measurement with a standard uncertainty, such as `9.81 ± .02`.
+///
+/// Operations propagate uncertainty to first order, treating their operands as independent:
+/// uncertainties of sums add in quadrature, and so do relative uncertainties of products and
+/// quotients. Values and their uncertainties are computed exactly as with [`Float`], with
+/// quadrature sums from [`Float::hypot`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Measured {
+    pub value: Float,
+    /// The standard uncertainty, which is never negative.
+    pub sigma: Float,
+}
+
+impl Measured {
+    /// A value with uncertainty `|sigma|`.
+    pub fn new(value: Float, sigma: Float) -> Self {
+        Measured {
+            value,
+            sigma: sigma.abs(),
+        }
+    }
+
+    /// A value with no uncertainty, such as a defined constant or a count.
+    pub fn exact(value: Float) -> Self {
+        Measured::new(value, Float::ZERO)
+    }
+
+    /// The uncertainty relative to the magnitude of the value. Fails with
+    /// [`FloatError::DivideByZero`] if the value is zero.
+    pub fn relative_sigma(&self) -> Result<Float, FloatError> {
+        self.sigma / self.value.abs()
+    }
+}
+
+impl Neg for Measured {
+    type Output = Measured;
+
+    fn neg(self) -> Self::Output {
+        Measured {
+            value: -self.value,
+            ..self
+        }
+    }
+}
+
+impl Add for Measured {
+    type Output = Result<Measured, FloatError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Ok(Measured {
+            value: (self.value + rhs.value)?,
+            sigma: self.sigma.hypot(&rhs.sigma)?,
+        })
+    }
+}
+
+impl Sub for Measured {
+    type Output = Result<Measured, FloatError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Measured {
+    type Output = Result<Measured, FloatError>;
+
+    /// The uncertainty of `a * b` is `sqrt((b σa)^2 + (a σb)^2)`, which doesn't need either value
+    /// to be nonzero.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Ok(Measured {
+            value: (self.value * rhs.value)?,
+            sigma: (rhs.value * self.sigma)?.hypot(&(self.value * rhs.sigma)?)?,
+        })
+    }
+}
+
+impl Div for Measured {
+    type Output = Result<Measured, FloatError>;
+
+    /// The uncertainty of `q = a / b` is `sqrt(σa^2 + (q σb)^2) / |b|`.
+    fn div(self, rhs: Self) -> Self::Output {
+        let value = (self.value / rhs.value)?;
+        let sigma = self.sigma.hypot(&(value * rhs.sigma)?)?;
+
+        Ok(Measured {
+            value,
+            sigma: (sigma / rhs.value.abs())?,
+        })
+    }
+}
+
+impl Display for Measured {
+    /// Writes the value and uncertainty as the calculator would display them in Normal mode,
+    /// such as `9.812±.023`. The uncertainty is rounded to two significant digits, and the value to the
+    /// same decimal place. An exact value is written alone.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.sigma.is_zero() {
+            return f.write_str(&self.value.to_normal_string());
+        }
+
+        let sigma = self.sigma.round_to_sig_digits(2).unwrap_or(self.sigma);
+        let place = sigma.exponent() as i64 - 1;
+        let value = self.value.round_to_place(place).unwrap_or(self.value);
+
+        write!(
+            f,
+            "{}±{}",
+            value.to_normal_string(),
+            sigma.to_normal_string()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn propagation() {
+        let a = Measured::new(
+            tifloat!(0x30000000000000 * 10 ^ 0),
+            tifloat!(0x40000000000000 * 10 ^ -1),
+        );
+        let b = Measured::new(
+            tifloat!(0x40000000000000 * 10 ^ 0),
+            tifloat!(-0x30000000000000 * 10 ^ -1),
+        );
+
+        assert_eq!(b.sigma, tifloat!(0x30000000000000 * 10 ^ -1));
+        assert_eq!(
+            (a + b).unwrap(),
+            Measured::new(
+                tifloat!(0x70000000000000 * 10 ^ 0),
+                tifloat!(0x50000000000000 * 10 ^ -1)
+            )
+        );
+        assert_eq!(
+            (a - b).unwrap(),
+            Measured::new(-Float::ONE, tifloat!(0x50000000000000 * 10 ^ -1))
+        );
+
+        let c = Measured::new(
+            tifloat!(0x20000000000000 * 10 ^ 0),
+            tifloat!(0x10000000000000 * 10 ^ -1),
+        );
+        let d = Measured::new(
+            tifloat!(0x30000000000000 * 10 ^ 0),
+            tifloat!(0x20000000000000 * 10 ^ -1),
+        );
+        assert_eq!(
+            (c * d).unwrap(),
+            Measured::new(
+                tifloat!(0x60000000000000 * 10 ^ 0),
+                tifloat!(0x50000000000000 * 10 ^ -1)
+            )
+        );
+
+        let six = Measured::new(
+            tifloat!(0x60000000000000 * 10 ^ 0),
+            tifloat!(0x50000000000000 * 10 ^ -1),
+        );
+        let three = Measured::exact(tifloat!(0x30000000000000 * 10 ^ 0));
+        assert_eq!(
+            (six / three).unwrap(),
+            Measured::new(
+                tifloat!(0x20000000000000 * 10 ^ 0),
+                tifloat!(0x16666666666667 * 10 ^ -1)
+            )
+        );
+        assert_eq!(three.relative_sigma().unwrap(), Float::ZERO);
+        assert!(matches!(
+            six / Measured::exact(Float::ZERO),
+            Err(FloatError::DivideByZero)
+        ));
+    }
+
+    #[test]
+    fn display() {
+        let g = Measured::new(
+            tifloat!(0x98123000000000 * 10 ^ 0),
+            tifloat!(0x23400000000000 * 10 ^ -2),
+        );
+        assert_eq!(g.to_string(), "9.812±.023");
+
+        let small = Measured::new(
+            tifloat!(0x60000000000000 * 10 ^ -2),
+            tifloat!(0x50000000000000 * 10 ^ -1),
+        );
+        assert_eq!(small.to_string(), ".06±.5");
+        assert_eq!(
+            Measured::new(
+                tifloat!(-0x12345000000000 * 10 ^ 4),
+                tifloat!(0x99600000000000 * 10 ^ 1)
+            )
+            .to_string(),
+            "-12350±100"
+        );
+        assert_eq!(Measured::exact(Float::PI).to_string(), "3.141592654");
+    }
+}
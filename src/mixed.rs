@@ -0,0 +1,151 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::extended::Extended;
+use crate::{Float, FloatError};
+
+/// `float / integer`, or `integer / float` if `inverted`, rounded once.
+fn divide(float: Float, integer: i128, inverted: bool) -> Result<Float, FloatError> {
+    if float.is_undefined() {
+        return Ok(Float::undefined());
+    }
+
+    let (divisor_is_zero, dividend_is_zero) = if inverted {
+        (float.is_zero(), integer == 0)
+    } else {
+        (integer == 0, float.is_zero())
+    };
+
+    if divisor_is_zero {
+        return Err(FloatError::DivideByZero);
+    } else if dividend_is_zero {
+        return Ok(Float::ZERO);
+    }
+
+    let negative = float.is_negative() != (integer < 0);
+    let significand = float.significand_dec() as u128;
+    let scale = float.exponent() as i64 - 13;
+
+    if inverted {
+        Float::from_scaled_ratio(negative, integer.unsigned_abs(), significand, -scale)
+    } else {
+        Float::from_scaled_ratio(negative, significand, integer.unsigned_abs(), scale)
+    }
+}
+
+/// Implements the arithmetic operators between `Float` and an integer type, in either order. The
+/// integer is used exactly and the result is rounded once, so `float + 10_000_000_000_000_001`
+/// is as accurate as if the calculator could hold the integer.
+macro_rules! integer_ops {
+    ($($int:ty),*) => {$(
+        impl Add<$int> for Float {
+            type Output = Result<Float, FloatError>;
+
+            fn add(self, rhs: $int) -> Self::Output {
+                Extended::new(&self).add(Extended::from_integer(rhs as i128)).to_float()
+            }
+        }
+
+        impl Add<Float> for $int {
+            type Output = Result<Float, FloatError>;
+
+            fn add(self, rhs: Float) -> Self::Output {
+                rhs + self
+            }
+        }
+
+        impl Sub<$int> for Float {
+            type Output = Result<Float, FloatError>;
+
+            fn sub(self, rhs: $int) -> Self::Output {
+                Extended::new(&self).add(Extended::from_integer(-(rhs as i128))).to_float()
+            }
+        }
+
+        impl Sub<Float> for $int {
+            type Output = Result<Float, FloatError>;
+
+            fn sub(self, rhs: Float) -> Self::Output {
+                Extended::from_integer(self as i128).add(Extended::new(&-rhs)).to_float()
+            }
+        }
+
+        impl Mul<$int> for Float {
+            type Output = Result<Float, FloatError>;
+
+            fn mul(self, rhs: $int) -> Self::Output {
+                Extended::new(&self).mul(Extended::from_integer(rhs as i128)).to_float()
+            }
+        }
+
+        impl Mul<Float> for $int {
+            type Output = Result<Float, FloatError>;
+
+            fn mul(self, rhs: Float) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl Div<$int> for Float {
+            type Output = Result<Float, FloatError>;
+
+            fn div(self, rhs: $int) -> Self::Output {
+                divide(self, rhs as i128, false)
+            }
+        }
+
+        impl Div<Float> for $int {
+            type Output = Result<Float, FloatError>;
+
+            fn div(self, rhs: Float) -> Self::Output {
+                divide(rhs, self as i128, true)
+            }
+        }
+    )*};
+}
+
+// only i64, so that integer literals like `float + 1` can still infer their type
+integer_ops!(i64);
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn integer_ops() {
+        let float = tifloat!(0x25000000000000 * 10 ^ 0);
+
+        assert_eq!((float + 1).unwrap(), tifloat!(0x35000000000000 * 10 ^ 0));
+        assert_eq!((1 - float).unwrap(), tifloat!(-0x15000000000000 * 10 ^ 0));
+        assert_eq!((float * -2).unwrap(), tifloat!(-0x50000000000000 * 10 ^ 0));
+        assert_eq!((float / 4).unwrap(), tifloat!(0x62500000000000 * 10 ^ -1));
+        assert_eq!((10 / float).unwrap(), tifloat!(0x40000000000000 * 10 ^ 0));
+        assert_eq!((0 / float).unwrap(), Float::ZERO);
+    }
+
+    #[test]
+    fn integer_ops_exact() {
+        // 12345678901234567 has too many digits for a Float, but the sum is rounded only once
+        assert_eq!(
+            (tifloat!(-0x12345678901234 * 10 ^ 16) + 12345678901234567).unwrap(),
+            tifloat!(0x56700000000000 * 10 ^ 2)
+        );
+        assert_eq!(
+            (i64::MAX * Float::ONE).unwrap(),
+            tifloat!(0x92233720368548 * 10 ^ 18)
+        );
+    }
+
+    #[test]
+    fn integer_ops_errors() {
+        assert!(matches!(Float::ONE / 0, Err(FloatError::DivideByZero)));
+        assert!(matches!(1 / Float::ZERO, Err(FloatError::DivideByZero)));
+        assert!(matches!(Float::MAX * 10, Err(FloatError::Overflow)));
+    }
+
+    #[test]
+    fn integer_ops_undefined() {
+        assert!((Float::undefined() + 1).unwrap().is_undefined());
+        assert!((2 * Float::undefined()).unwrap().is_undefined());
+        assert!((Float::undefined() / 0).unwrap().is_undefined());
+    }
+}
@@ -0,0 +1,672 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use nalgebra::{ComplexField, Field, RealField, SimdValue};
+use num_traits::{FromPrimitive, Num, One, Signed, Zero};
+use simba::scalar::SubsetOf;
+
+use crate::{AngleMode, Float, FloatError, ParseFloatError};
+
+/// A [`Float`] that can be used as a `nalgebra` scalar, so that `Matrix<MatrixFloat, ..>` and the
+/// decompositions built on it compute with calculator arithmetic.
+///
+/// `nalgebra` needs operators that return `Self`, so the operators on this type panic where
+/// [`Float`]'s would return an error, like [`Sum`](std::iter::Sum) does. Undefined values
+/// propagate as usual.
+///
+/// Addition, subtraction, multiplication, division, remainders, rounding to integers, powers,
+/// square and cube roots, `hypot`, logarithms other than `log2`, `exp`, and the sine, cosine,
+/// tangent, and arctangent in radians are computed exactly as they are for [`Float`]. The
+/// remaining [`ComplexField`] and [`RealField`] methods, such as `asin`, are approximated through
+/// an `f64` and are not bit-exact.
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct MatrixFloat(pub Float);
+
+impl MatrixFloat {
+    /// Unwraps the result of an operation, panicking with the error if there is one.
+    fn unwrap(result: Result<Float, FloatError>, operation: &str) -> MatrixFloat {
+        MatrixFloat(result.unwrap_or_else(|err| panic!("attempt to {operation} with {err:?}")))
+    }
+
+    /// Approximates a function through an `f64`.
+    fn approximate(self, operation: &str, f: impl FnOnce(f64) -> f64) -> MatrixFloat {
+        if self.0.is_undefined() {
+            self
+        } else {
+            MatrixFloat::unwrap(Float::try_from(f(self.0.into())), operation)
+        }
+    }
+
+    /// Approximates a constant through an `f64`.
+    fn constant(value: f64) -> MatrixFloat {
+        MatrixFloat(Float::try_from(value).unwrap())
+    }
+}
+
+impl From<Float> for MatrixFloat {
+    fn from(value: Float) -> Self {
+        MatrixFloat(value)
+    }
+}
+
+impl From<MatrixFloat> for Float {
+    fn from(value: MatrixFloat) -> Self {
+        value.0
+    }
+}
+
+impl Display for MatrixFloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Neg for MatrixFloat {
+    type Output = MatrixFloat;
+
+    fn neg(self) -> Self::Output {
+        MatrixFloat(-self.0)
+    }
+}
+
+/// Implements a panicking operator and its compound assignment in terms of [`Float`]'s.
+macro_rules! panicking_op {
+    ($($op:ident::$method:ident, $op_assign:ident::$method_assign:ident, $verb:literal;)*) => {$(
+        impl $op for MatrixFloat {
+            type Output = MatrixFloat;
+
+            fn $method(self, rhs: MatrixFloat) -> Self::Output {
+                MatrixFloat::unwrap(self.0.$method(rhs.0), $verb)
+            }
+        }
+
+        impl $op_assign for MatrixFloat {
+            fn $method_assign(&mut self, rhs: MatrixFloat) {
+                *self = self.$method(rhs);
+            }
+        }
+    )*};
+}
+
+panicking_op! {
+    Add::add, AddAssign::add_assign, "add";
+    Sub::sub, SubAssign::sub_assign, "subtract";
+    Mul::mul, MulAssign::mul_assign, "multiply";
+    Div::div, DivAssign::div_assign, "divide";
+    Rem::rem, RemAssign::rem_assign, "calculate the remainder";
+}
+
+impl Zero for MatrixFloat {
+    fn zero() -> Self {
+        MatrixFloat(Float::ZERO)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl One for MatrixFloat {
+    fn one() -> Self {
+        MatrixFloat(Float::ONE)
+    }
+}
+
+impl Num for MatrixFloat {
+    type FromStrRadixErr = ParseFloatError;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Float::from_str_radix(str, radix).map(MatrixFloat)
+    }
+}
+
+impl Signed for MatrixFloat {
+    fn abs(&self) -> Self {
+        MatrixFloat(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            MatrixFloat::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        MatrixFloat(self.0.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        !self.0.is_zero() && !self.0.is_negative()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+impl FromPrimitive for MatrixFloat {
+    /// Rounds integers with more than 14 digits.
+    fn from_i64(n: i64) -> Option<Self> {
+        Float::from_scaled_integer(n < 0, n.unsigned_abs() as u128, 0)
+            .ok()
+            .map(MatrixFloat)
+    }
+
+    /// Rounds integers with more than 14 digits.
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(MatrixFloat(Float::from(n)))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Float::try_from(n).ok().map(MatrixFloat)
+    }
+}
+
+impl AbsDiffEq for MatrixFloat {
+    type Epsilon = MatrixFloat;
+
+    fn default_epsilon() -> Self::Epsilon {
+        MatrixFloat(Float::default_epsilon())
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon.0)
+    }
+}
+
+impl RelativeEq for MatrixFloat {
+    fn default_max_relative() -> Self::Epsilon {
+        MatrixFloat(Float::default_max_relative())
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0.relative_eq(&other.0, epsilon.0, max_relative.0)
+    }
+}
+
+impl UlpsEq for MatrixFloat {
+    fn default_max_ulps() -> u32 {
+        Float::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon.0, max_ulps)
+    }
+}
+
+/// A `MatrixFloat` is its own single SIMD lane, like `f64` is.
+impl SimdValue for MatrixFloat {
+    const LANES: usize = 1;
+    type Element = MatrixFloat;
+    type SimdBool = bool;
+
+    fn splat(val: Self::Element) -> Self {
+        val
+    }
+
+    fn extract(&self, _: usize) -> Self::Element {
+        *self
+    }
+
+    unsafe fn extract_unchecked(&self, _: usize) -> Self::Element {
+        *self
+    }
+
+    fn replace(&mut self, _: usize, val: Self::Element) {
+        *self = val
+    }
+
+    unsafe fn replace_unchecked(&mut self, _: usize, val: Self::Element) {
+        *self = val
+    }
+
+    fn select(self, cond: Self::SimdBool, other: Self) -> Self {
+        if cond {
+            self
+        } else {
+            other
+        }
+    }
+}
+
+impl Field for MatrixFloat {}
+
+impl SubsetOf<MatrixFloat> for MatrixFloat {
+    fn to_superset(&self) -> MatrixFloat {
+        *self
+    }
+
+    fn from_superset_unchecked(element: &MatrixFloat) -> Self {
+        *element
+    }
+
+    fn is_in_subset(_: &MatrixFloat) -> bool {
+        true
+    }
+}
+
+/// Implements the conversions `nalgebra` uses to turn literals like `2.0` into scalars. They panic
+/// if the value is out of range.
+macro_rules! primitive_subset {
+    ($($primitive:ty),*) => {$(
+        impl SubsetOf<MatrixFloat> for $primitive {
+            fn to_superset(&self) -> MatrixFloat {
+                MatrixFloat::unwrap(Float::try_from(*self), "convert")
+            }
+
+            fn from_superset_unchecked(element: &MatrixFloat) -> Self {
+                f64::from(element.0) as $primitive
+            }
+
+            fn is_in_subset(_: &MatrixFloat) -> bool {
+                true
+            }
+        }
+    )*};
+}
+
+primitive_subset!(f32, f64);
+
+impl ComplexField for MatrixFloat {
+    type RealField = MatrixFloat;
+
+    fn from_real(re: Self::RealField) -> Self {
+        re
+    }
+
+    fn real(self) -> Self::RealField {
+        self
+    }
+
+    fn imaginary(self) -> Self::RealField {
+        MatrixFloat::zero()
+    }
+
+    fn modulus(self) -> Self::RealField {
+        Signed::abs(&self)
+    }
+
+    fn modulus_squared(self) -> Self::RealField {
+        self * self
+    }
+
+    fn argument(self) -> Self::RealField {
+        if self.0.is_negative() {
+            MatrixFloat::pi()
+        } else {
+            MatrixFloat::zero()
+        }
+    }
+
+    fn norm1(self) -> Self::RealField {
+        Signed::abs(&self)
+    }
+
+    fn scale(self, factor: Self::RealField) -> Self {
+        self * factor
+    }
+
+    fn unscale(self, factor: Self::RealField) -> Self {
+        self / factor
+    }
+
+    fn floor(self) -> Self {
+        MatrixFloat(self.0.floor())
+    }
+
+    fn ceil(self) -> Self {
+        MatrixFloat(self.0.ceil())
+    }
+
+    /// Rounds half away from zero.
+    fn round(self) -> Self {
+        let half = MatrixFloat(Float::new_unchecked(false, -1, 0x50000000000000));
+
+        if Signed::abs(&self.fract()) >= half {
+            self.trunc() + Signed::signum(&self)
+        } else {
+            self.trunc()
+        }
+    }
+
+    fn trunc(self) -> Self {
+        MatrixFloat(self.0.trunc())
+    }
+
+    fn fract(self) -> Self {
+        MatrixFloat(self.0.fract())
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        MatrixFloat::unwrap(self.0.mul_add(&a.0, &b.0), "compute mul_add")
+    }
+
+    fn abs(self) -> Self::RealField {
+        Signed::abs(&self)
+    }
+
+    fn hypot(self, other: Self) -> Self::RealField {
+        MatrixFloat::unwrap(self.0.hypot(&other.0), "compute hypot")
+    }
+
+    fn recip(self) -> Self {
+        MatrixFloat::unwrap(self.0.recip(), "compute recip")
+    }
+
+    fn conjugate(self) -> Self {
+        self
+    }
+
+    fn sin(self) -> Self {
+        MatrixFloat::unwrap(self.0.sin(AngleMode::Radian), "compute sin")
+    }
+
+    fn cos(self) -> Self {
+        MatrixFloat::unwrap(self.0.cos(AngleMode::Radian), "compute cos")
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn tan(self) -> Self {
+        MatrixFloat::unwrap(self.0.tan(AngleMode::Radian), "compute tan")
+    }
+
+    fn asin(self) -> Self {
+        self.approximate("compute asin", f64::asin)
+    }
+
+    fn acos(self) -> Self {
+        self.approximate("compute acos", f64::acos)
+    }
+
+    fn atan(self) -> Self {
+        self.atan2(MatrixFloat::one())
+    }
+
+    fn sinh(self) -> Self {
+        self.approximate("compute sinh", f64::sinh)
+    }
+
+    fn cosh(self) -> Self {
+        self.approximate("compute cosh", f64::cosh)
+    }
+
+    fn tanh(self) -> Self {
+        self.approximate("compute tanh", f64::tanh)
+    }
+
+    fn asinh(self) -> Self {
+        self.approximate("compute asinh", f64::asinh)
+    }
+
+    fn acosh(self) -> Self {
+        self.approximate("compute acosh", f64::acosh)
+    }
+
+    fn atanh(self) -> Self {
+        self.approximate("compute atanh", f64::atanh)
+    }
+
+    fn log(self, base: Self::RealField) -> Self {
+        MatrixFloat::unwrap(self.0.log_base(&base.0), "compute log")
+    }
+
+    fn log2(self) -> Self {
+        self.approximate("compute log2", f64::log2)
+    }
+
+    fn log10(self) -> Self {
+        MatrixFloat::unwrap(self.0.log10(), "compute log10")
+    }
+
+    fn ln(self) -> Self {
+        MatrixFloat::unwrap(self.0.ln(), "compute ln")
+    }
+
+    fn ln_1p(self) -> Self {
+        self.approximate("compute ln_1p", f64::ln_1p)
+    }
+
+    fn sqrt(self) -> Self {
+        MatrixFloat::unwrap(self.0.sqrt(), "compute sqrt")
+    }
+
+    fn exp(self) -> Self {
+        MatrixFloat::unwrap(self.0.exp(), "compute exp")
+    }
+
+    fn exp2(self) -> Self {
+        self.approximate("compute exp2", f64::exp2)
+    }
+
+    fn exp_m1(self) -> Self {
+        self.approximate("compute exp_m1", f64::exp_m1)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        let exponent = Float::from_scaled_integer(n < 0, n.unsigned_abs() as u128, 0).unwrap();
+        MatrixFloat::unwrap(self.0.pow(&exponent), "compute powi")
+    }
+
+    fn powf(self, n: Self::RealField) -> Self {
+        MatrixFloat::unwrap(self.0.pow(&n.0), "compute powf")
+    }
+
+    fn powc(self, n: Self) -> Self {
+        self.powf(n)
+    }
+
+    fn cbrt(self) -> Self {
+        MatrixFloat::unwrap(self.0.cbrt(), "compute cbrt")
+    }
+
+    fn is_finite(&self) -> bool {
+        !self.0.is_undefined()
+    }
+
+    fn try_sqrt(self) -> Option<Self> {
+        if self.0.is_negative() {
+            None
+        } else {
+            Some(self.sqrt())
+        }
+    }
+}
+
+impl RealField for MatrixFloat {
+    fn is_sign_positive(&self) -> bool {
+        !self.0.is_negative()
+    }
+
+    fn is_sign_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    fn copysign(self, sign: Self) -> Self {
+        MatrixFloat(self.0.copysign(&sign.0))
+    }
+
+    fn max(self, other: Self) -> Self {
+        MatrixFloat(self.0.max(other.0))
+    }
+
+    fn min(self, other: Self) -> Self {
+        MatrixFloat(self.0.min(other.0))
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        MatrixFloat(self.0.clamp(min.0, max.0))
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        MatrixFloat::unwrap(self.0.atan2(&other.0, AngleMode::Radian), "compute atan2")
+    }
+
+    fn min_value() -> Option<Self> {
+        Some(MatrixFloat(-Float::MAX))
+    }
+
+    fn max_value() -> Option<Self> {
+        Some(MatrixFloat(Float::MAX))
+    }
+
+    fn pi() -> Self {
+        MatrixFloat(Float::PI)
+    }
+
+    fn two_pi() -> Self {
+        MatrixFloat::constant(std::f64::consts::TAU)
+    }
+
+    fn frac_pi_2() -> Self {
+        MatrixFloat::constant(std::f64::consts::FRAC_PI_2)
+    }
+
+    fn frac_pi_3() -> Self {
+        MatrixFloat::constant(std::f64::consts::FRAC_PI_3)
+    }
+
+    fn frac_pi_4() -> Self {
+        MatrixFloat::constant(std::f64::consts::FRAC_PI_4)
+    }
+
+    fn frac_pi_6() -> Self {
+        MatrixFloat::constant(std::f64::consts::FRAC_PI_6)
+    }
+
+    fn frac_pi_8() -> Self {
+        MatrixFloat::constant(std::f64::consts::FRAC_PI_8)
+    }
+
+    fn frac_1_pi() -> Self {
+        MatrixFloat::constant(std::f64::consts::FRAC_1_PI)
+    }
+
+    fn frac_2_pi() -> Self {
+        MatrixFloat::constant(std::f64::consts::FRAC_2_PI)
+    }
+
+    fn frac_2_sqrt_pi() -> Self {
+        MatrixFloat::constant(std::f64::consts::FRAC_2_SQRT_PI)
+    }
+
+    fn e() -> Self {
+        MatrixFloat(Float::E)
+    }
+
+    fn log2_e() -> Self {
+        MatrixFloat::constant(std::f64::consts::LOG2_E)
+    }
+
+    fn log10_e() -> Self {
+        MatrixFloat::constant(std::f64::consts::LOG10_E)
+    }
+
+    fn ln_2() -> Self {
+        MatrixFloat::constant(std::f64::consts::LN_2)
+    }
+
+    fn ln_10() -> Self {
+        MatrixFloat::constant(std::f64::consts::LN_10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{ComplexField, Matrix2, Vector2};
+    use simba::scalar::SubsetOf;
+
+    use super::MatrixFloat;
+    use crate::{tifloat, Float};
+
+    fn matrix(floats: [Float; 4]) -> Matrix2<MatrixFloat> {
+        Matrix2::from_row_slice(&floats.map(MatrixFloat))
+    }
+
+    #[test]
+    fn solve() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+        let a = matrix([two, Float::ONE, Float::ONE, three]);
+        let b = Vector2::new(MatrixFloat(two), MatrixFloat(Float::ONE));
+
+        let x = a.lu().solve(&b).unwrap();
+        assert_eq!(
+            x,
+            Vector2::new(MatrixFloat(Float::ONE), MatrixFloat::default())
+        );
+        assert_eq!(
+            a.determinant(),
+            MatrixFloat(tifloat!(0x50000000000000 * 10 ^ 0))
+        );
+    }
+
+    #[test]
+    fn calculator_arithmetic() {
+        let third = (Float::ONE / tifloat!(0x30000000000000 * 10 ^ 0)).unwrap();
+        let a = matrix([third, Float::ZERO, Float::ZERO, third]);
+        let product = a * matrix([Float::ONE; 4]);
+
+        assert_eq!(product[(0, 0)], MatrixFloat(third));
+        assert_eq!(
+            (product + product + product)[(1, 1)],
+            MatrixFloat(tifloat!(0x99999999999999 * 10 ^ -1))
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        let x = MatrixFloat(tifloat!(-0x25000000000000 * 10 ^ 0));
+
+        assert_eq!(x.trunc(), MatrixFloat(tifloat!(-0x20000000000000 * 10 ^ 0)));
+        assert_eq!(x.floor(), MatrixFloat(tifloat!(-0x30000000000000 * 10 ^ 0)));
+        assert_eq!(x.ceil(), MatrixFloat(tifloat!(-0x20000000000000 * 10 ^ 0)));
+        assert_eq!(x.round(), MatrixFloat(tifloat!(-0x30000000000000 * 10 ^ 0)));
+        assert_eq!(
+            x.fract(),
+            MatrixFloat(tifloat!(-0x50000000000000 * 10 ^ -1))
+        );
+        assert_eq!(
+            x.powi(-2),
+            MatrixFloat(tifloat!(0x16000000000000 * 10 ^ -1))
+        );
+        assert_eq!(x.powi(3), MatrixFloat(tifloat!(-0x15625000000000 * 10 ^ 1)));
+    }
+
+    #[test]
+    fn roots() {
+        let x = MatrixFloat(tifloat!(0x27000000000000 * 10 ^ 1));
+
+        assert_eq!(x.cbrt(), MatrixFloat(tifloat!(0x30000000000000 * 10 ^ 0)));
+        assert_eq!(
+            (-x).cbrt(),
+            MatrixFloat(tifloat!(-0x30000000000000 * 10 ^ 0))
+        );
+    }
+
+    #[test]
+    fn undefined() {
+        let undefined = MatrixFloat(Float::undefined());
+
+        assert!(f64::from_superset_unchecked(&undefined).is_nan());
+        assert!(undefined.sin().0.is_undefined());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with DivideByZero")]
+    fn singular() {
+        let _ = MatrixFloat(Float::ONE) / MatrixFloat(Float::ZERO);
+    }
+}
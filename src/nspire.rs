@@ -0,0 +1,60 @@
+//! Conversion to and from the TI-Nspire's numeric storage, which uses IEEE 754 doubles.
+//!
+//! A double holds about 15.9 significant digits, so every Float survives the trip to the Nspire
+//! and back. The reverse direction rounds to 14 digits, and doubles outside the range of a Float
+//! cannot be converted at all. The Nspire's NaN corresponds to a Float with the UNDEFINED flag.
+
+use crate::{Float, FloatError};
+
+/// Converts a number stored on the Nspire to the nearest Float.
+///
+/// NaNs become undefined Floats. Fails with [`FloatError::Overflow`] for infinities and values too
+/// large for a Float, and with [`FloatError::Underflow`] for nonzero values too small for one.
+pub fn from_nspire(value: f64) -> Result<Float, FloatError> {
+    if value.is_nan() {
+        Ok(Float::undefined())
+    } else {
+        Float::try_from(value)
+    }
+}
+
+/// Converts a Float to the nearest number the Nspire can store. Undefined Floats become NaN.
+pub fn to_nspire(value: &Float) -> f64 {
+    if value.is_undefined() {
+        f64::NAN
+    } else {
+        f64::from(*value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn undefined() {
+        assert!(from_nspire(f64::NAN).ok().unwrap().is_undefined());
+        assert!(to_nspire(&Float::undefined()).is_nan());
+    }
+
+    #[test]
+    fn round_trip() {
+        for float in [
+            tifloat!(0x31415926535898 * 10 ^ 0),
+            tifloat!(-0x12345678901234 * 10 ^ -99),
+            tifloat!(0x99999999999999 * 10 ^ 99),
+        ] {
+            assert_eq!(from_nspire(to_nspire(&float)).ok().unwrap(), float);
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(matches!(
+            from_nspire(f64::NEG_INFINITY),
+            Err(FloatError::Overflow)
+        ));
+        assert!(matches!(from_nspire(1e300), Err(FloatError::Overflow)));
+    }
+}
@@ -0,0 +1,278 @@
+//! The OS's floating-point registers OP1 to OP6 and the ROM calls that operate on them, for
+//! emulators and assembly tooling that reason in those terms.
+//!
+//! Each register holds 11 bytes: a [`Float`]'s 9 bytes followed by two more bytes of mantissa,
+//! which hold the four guard digits of a [`GuardedFloat`]. The arithmetic calls take their
+//! operands from OP1 and OP2 and leave their result in OP1, rounded half-up to 18 digits. A
+//! result too small for a float flushes to zero, as it does on the calculator.
+//!
+//! ```
+//! use tifloats::op::{Register, Registers};
+//! use tifloats::{tifloat, Float};
+//!
+//! let three = tifloat!(0x30000000000000 * 10 ^ 0);
+//! let mut registers = Registers::new();
+//!
+//! registers.set(Register::Op1, Float::ONE);
+//! registers.set(Register::Op2, three);
+//! registers.fp_div()?;
+//! registers.fp_mult()?;
+//! registers.rnd_guard()?;
+//!
+//! assert_eq!(registers.get(Register::Op1)?.round_guard()?, Float::ONE);
+//! # Ok::<(), tifloats::Error>(())
+//! ```
+
+use crate::{Error, Float, FloatError, GuardedFloat, ParseFloatError};
+
+/// One of the six OP registers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Register {
+    Op1,
+    Op2,
+    Op3,
+    Op4,
+    Op5,
+    Op6,
+}
+
+/// The contents of OP1 to OP6, stored as raw bytes so that they can be loaded from and copied
+/// back to an emulator's memory unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registers {
+    ops: [[u8; 11]; 6],
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        Registers::new()
+    }
+}
+
+impl Registers {
+    /// Registers that each hold zero.
+    pub fn new() -> Self {
+        Registers {
+            ops: [GuardedFloat::from(Float::ZERO).to_raw_bytes(); 6],
+        }
+    }
+
+    pub fn bytes(&self, register: Register) -> &[u8; 11] {
+        &self.ops[register as usize]
+    }
+
+    pub fn bytes_mut(&mut self, register: Register) -> &mut [u8; 11] {
+        &mut self.ops[register as usize]
+    }
+
+    /// Reads a register, validating its bytes as [`GuardedFloat::from_raw_bytes`] does.
+    pub fn get(&self, register: Register) -> Result<GuardedFloat, ParseFloatError> {
+        GuardedFloat::from_raw_bytes(*self.bytes(register))
+    }
+
+    /// Stores a value in a register. A [`Float`] is stored with zero guard digits.
+    pub fn set(&mut self, register: Register, value: impl Into<GuardedFloat>) {
+        *self.bytes_mut(register) = value.into().to_raw_bytes();
+    }
+
+    /// Copies one register into another, like the `OP1ToOP2` family of ROM calls.
+    #[doc(alias = "OP1ToOP2")]
+    pub fn copy(&mut self, from: Register, to: Register) {
+        self.ops[to as usize] = self.ops[from as usize];
+    }
+
+    /// Exchanges two registers, like the `OP1ExOP2` family of ROM calls.
+    #[doc(alias = "OP1ExOP2")]
+    pub fn swap(&mut self, a: Register, b: Register) {
+        self.ops.swap(a as usize, b as usize);
+    }
+
+    /// `OP1 = OP1 + OP2`.
+    #[doc(alias = "FPAdd")]
+    pub fn fp_add(&mut self) -> Result<(), Error> {
+        self.binary("FPAdd", |lhs, rhs| lhs + rhs)
+    }
+
+    /// `OP1 = OP1 - OP2`.
+    #[doc(alias = "FPSub")]
+    pub fn fp_sub(&mut self) -> Result<(), Error> {
+        self.binary("FPSub", |lhs, rhs| lhs - rhs)
+    }
+
+    /// `OP1 = OP1 * OP2`.
+    #[doc(alias = "FPMult")]
+    pub fn fp_mult(&mut self) -> Result<(), Error> {
+        self.binary("FPMult", |lhs, rhs| lhs * rhs)
+    }
+
+    /// `OP1 = OP1 / OP2`.
+    #[doc(alias = "FPDiv")]
+    pub fn fp_div(&mut self) -> Result<(), Error> {
+        self.binary("FPDiv", |lhs, rhs| lhs / rhs)
+    }
+
+    /// `OP1 = OP1 * OP1`.
+    #[doc(alias = "FPSquare")]
+    pub fn fp_square(&mut self) -> Result<(), Error> {
+        self.unary("FPSquare", |value| value * value)
+    }
+
+    /// `OP1 = 1 / OP1`.
+    #[doc(alias = "FPRecip")]
+    pub fn fp_recip(&mut self) -> Result<(), Error> {
+        self.unary("FPRecip", |value| GuardedFloat::from(Float::ONE) / value)
+    }
+
+    /// Rounds OP1 half-up to 14 digits, leaving its guard digits zero.
+    #[doc(alias = "RndGuard")]
+    pub fn rnd_guard(&mut self) -> Result<(), Error> {
+        self.unary("RndGuard", |value| {
+            value.round_guard().map(GuardedFloat::from)
+        })
+    }
+
+    /// Discards the fractional digits of OP1, rounding toward zero.
+    #[doc(alias = "Trunc")]
+    pub fn trunc(&mut self) -> Result<(), Error> {
+        self.unary("Trunc", |value| Ok(value.trunc()))
+    }
+
+    /// Rounds OP1 toward negative infinity, like `int(`.
+    #[doc(alias = "Intgr")]
+    pub fn intgr(&mut self) -> Result<(), Error> {
+        self.unary("Intgr", |value| Ok(value.floor()))
+    }
+
+    fn unary(
+        &mut self,
+        name: &'static str,
+        op: impl FnOnce(GuardedFloat) -> Result<GuardedFloat, FloatError>,
+    ) -> Result<(), Error> {
+        let result = op(self.get(Register::Op1)?);
+
+        self.store(name, result)
+    }
+
+    fn binary(
+        &mut self,
+        name: &'static str,
+        op: impl FnOnce(GuardedFloat, GuardedFloat) -> Result<GuardedFloat, FloatError>,
+    ) -> Result<(), Error> {
+        let result = op(self.get(Register::Op1)?, self.get(Register::Op2)?);
+
+        self.store(name, result)
+    }
+
+    /// Writes a result to OP1, leaving it unchanged if the call failed.
+    fn store(
+        &mut self,
+        name: &'static str,
+        result: Result<GuardedFloat, FloatError>,
+    ) -> Result<(), Error> {
+        let value = match result {
+            Err(FloatError::Underflow) => GuardedFloat::from(Float::ZERO),
+            result => result.map_err(|err| err.during(name))?,
+        };
+
+        self.set(Register::Op1, value);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn arithmetic() {
+        let mut registers = Registers::new();
+
+        registers.set(Register::Op1, tifloat!(0x20000000000000 * 10 ^ 0));
+        registers.fp_square().unwrap();
+        registers.fp_recip().unwrap();
+        registers.copy(Register::Op1, Register::Op2);
+        registers.fp_add().unwrap();
+        registers.fp_add().unwrap();
+        registers.fp_add().unwrap();
+
+        assert_eq!(
+            registers.get(Register::Op1).unwrap().round_guard().unwrap(),
+            Float::ONE
+        );
+
+        registers.fp_sub().unwrap();
+        registers.swap(Register::Op1, Register::Op2);
+        assert_eq!(
+            registers.get(Register::Op2).unwrap().round_guard().unwrap(),
+            tifloat!(0x75000000000000 * 10 ^ -1)
+        );
+    }
+
+    #[test]
+    fn guard_digits() {
+        let mut registers = Registers::new();
+
+        registers.set(Register::Op1, Float::ONE);
+        registers.set(Register::Op2, tifloat!(0x30000000000000 * 10 ^ 0));
+        registers.fp_div().unwrap();
+        assert_eq!(
+            registers.bytes(Register::Op1),
+            &[0x00, 0x7F, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33]
+        );
+
+        registers.rnd_guard().unwrap();
+        assert_eq!(
+            registers.bytes(Register::Op1)[2..],
+            [0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn integer_parts() {
+        let mut registers = Registers::new();
+
+        registers.set(Register::Op1, tifloat!(-0x27500000000000 * 10 ^ 0));
+        registers.copy(Register::Op1, Register::Op3);
+        registers.trunc().unwrap();
+        assert_eq!(
+            registers.get(Register::Op1).unwrap().round_guard().unwrap(),
+            tifloat!(-0x20000000000000 * 10 ^ 0)
+        );
+
+        registers.copy(Register::Op3, Register::Op1);
+        registers.intgr().unwrap();
+        assert_eq!(
+            registers.get(Register::Op1).unwrap().round_guard().unwrap(),
+            tifloat!(-0x30000000000000 * 10 ^ 0)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut registers = Registers::new();
+
+        registers.set(Register::Op1, Float::ONE);
+        assert_eq!(
+            registers.fp_div(),
+            Err(FloatError::DivideByZero.during("FPDiv"))
+        );
+        assert_eq!(
+            registers.get(Register::Op1).unwrap(),
+            GuardedFloat::from(Float::ONE)
+        );
+
+        registers.set(Register::Op1, Float::MIN_POSITIVE);
+        registers.fp_square().unwrap();
+        assert_eq!(
+            registers.get(Register::Op1).unwrap(),
+            GuardedFloat::from(Float::ZERO)
+        );
+
+        registers.bytes_mut(Register::Op2)[5] = 0xAB;
+        assert_eq!(
+            registers.fp_add(),
+            Err(Error::from(ParseFloatError::InvalidMantissa))
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::str::FromStr;
+
+use crate::float::ParseFloatError;
+use crate::mantissa::Mantissa;
+use crate::{Float, FloatError};
+
+impl FromStr for Float {
+    type Err = ParseFloatError;
+
+    /// Parses decimal strings such as `-12.5`, `.001`, or `1.5E-3`, rounding half-up to 14
+    /// significant digits, and [`UNDEFINED`](crate::UNDEFINED) as an undefined Float.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == crate::UNDEFINED {
+            return Ok(Float::undefined());
+        }
+
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let (significand, exponent) = match s.find(['e', 'E']) {
+            Some(index) => {
+                let exponent = &s[index + 1..];
+                let exponent_digits = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+
+                if exponent_digits.is_empty()
+                    || !exponent_digits.bytes().all(|byte| byte.is_ascii_digit())
+                {
+                    return Err(ParseFloatError::InvalidString);
+                }
+
+                (
+                    &s[..index],
+                    exponent
+                        .parse::<i32>()
+                        .map_err(|_| ParseFloatError::InvalidExponent)?,
+                )
+            }
+            None => (s, 0),
+        };
+
+        let (integer, fraction) = significand.split_once('.').unwrap_or((significand, ""));
+
+        if integer.len() + fraction.len() == 0
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|byte| byte.is_ascii_digit())
+        {
+            return Err(ParseFloatError::InvalidString);
+        }
+
+        let digits = integer
+            .bytes()
+            .chain(fraction.bytes())
+            .map(|byte| byte - b'0')
+            .collect::<Vec<_>>();
+
+        // place-value of the first digit
+        let exponent = exponent as i64 + integer.len() as i64 - 1;
+
+        Float::from_decimal_digits(negative, &digits, exponent).map_err(|err| match err {
+            FloatError::Overflow | FloatError::Underflow => ParseFloatError::InvalidExponent,
+            _ => ParseFloatError::InvalidMantissa,
+        })
+    }
+}
+
+impl Float {
+    /// Converts a decimal literal such as `3.14159e-2` to the nearest Float, rounding half-up to
+    /// 14 significant digits. In a `const` context (such as the expansion of `tifloat!`), an
+    /// invalid or out-of-range literal is a compile-time error.
+    #[doc(hidden)]
+    pub const fn from_literal_or_panic(negative: bool, literal: &str) -> Self {
+        let bytes = literal.as_bytes();
+
+        // the first 15 significant digits, which is enough to round half-up
+        let mut digits = [0u8; 15];
+        let mut digit_count = 0;
+        let mut integer_digits = 0i32;
+        let mut leading_fraction_zeros = 0i32;
+        let mut seen_point = false;
+
+        let mut index = 0;
+        while index < bytes.len() {
+            match bytes[index] {
+                b'_' => {}
+                b'.' if !seen_point => seen_point = true,
+                b'0' if digit_count == 0 => {
+                    if seen_point {
+                        leading_fraction_zeros += 1;
+                    }
+                }
+                byte @ b'0'..=b'9' => {
+                    if digit_count < digits.len() {
+                        digits[digit_count] = byte - b'0';
+                    }
+                    digit_count += 1;
+
+                    if !seen_point {
+                        integer_digits += 1;
+                    }
+                }
+                b'e' | b'E' => break,
+                _ => panic!("tifloat! literal must be a decimal number"),
+            }
+
+            index += 1;
+        }
+
+        let mut exponent = 0i32;
+        if index < bytes.len() {
+            index += 1;
+
+            let exponent_negative = index < bytes.len() && bytes[index] == b'-';
+            if index < bytes.len() && (bytes[index] == b'-' || bytes[index] == b'+') {
+                index += 1;
+            }
+
+            if index == bytes.len() {
+                panic!("tifloat! literal has an empty exponent");
+            }
+
+            while index < bytes.len() {
+                match bytes[index] {
+                    b'_' => {}
+                    byte @ b'0'..=b'9' if exponent < 1000 => {
+                        exponent = exponent * 10 + (byte - b'0') as i32
+                    }
+                    b'0'..=b'9' => {}
+                    _ => panic!("tifloat! literal must be a decimal number"),
+                }
+
+                index += 1;
+            }
+
+            if exponent_negative {
+                exponent = -exponent;
+            }
+        }
+
+        if digit_count == 0 {
+            return Float::ZERO;
+        }
+
+        // place-value of the first significant digit
+        exponent += if integer_digits > 0 {
+            integer_digits - 1
+        } else {
+            -(leading_fraction_zeros + 1)
+        };
+
+        let mut mantissa = 0u64;
+        let mut place = 0;
+        while place < 14 {
+            mantissa = mantissa * 10 + digits[place] as u64;
+            place += 1;
+        }
+
+        if digit_count > 14 && digits[14] >= 5 {
+            mantissa += 1;
+
+            if mantissa as u128 > <Mantissa>::MAX_10 {
+                mantissa /= 10;
+                exponent += 1;
+            }
+        }
+
+        if exponent < -99 || exponent > 99 {
+            panic!("tifloat! literal is outside the range of a Float");
+        }
+
+        let mut bcd = 0u64;
+        let mut shift = 0;
+        while mantissa != 0 {
+            bcd |= (mantissa % 10) << shift;
+            mantissa /= 10;
+            shift += 4;
+        }
+
+        Float::new_unchecked(negative, exponent as i8, bcd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn parse() {
+        let cases = [
+            ("12345", tifloat!(0x12345000000000 * 10 ^ 4)),
+            ("-12.5", tifloat!(-0x12500000000000 * 10 ^ 1)),
+            (".001", tifloat!(0x10000000000000 * 10 ^ -3)),
+            ("000.00100", tifloat!(0x10000000000000 * 10 ^ -3)),
+            ("1.5E-3", tifloat!(0x15000000000000 * 10 ^ -3)),
+            ("+15e+2", tifloat!(0x15000000000000 * 10 ^ 3)),
+            ("9.9999999999999E99", tifloat!(0x99999999999999 * 10 ^ 99)),
+            ("3.14159265358979", tifloat!(0x31415926535898 * 10 ^ 0)),
+            ("9.99999999999995", tifloat!(0x10000000000000 * 10 ^ 1)),
+            ("0", tifloat!(0x00000000000000 * 10 ^ 0)),
+            ("-0.0", tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (string, expected) in cases {
+            assert_eq!(string.parse::<Float>(), Ok(expected), "{string}");
+        }
+    }
+
+    #[test]
+    fn literal_macro() {
+        let cases = [
+            (tifloat!(3.14159e-2), tifloat!(0x31415900000000 * 10 ^ -2)),
+            (tifloat!(-12.5), tifloat!(-0x12500000000000 * 10 ^ 1)),
+            (tifloat!(1_000), tifloat!(0x10000000000000 * 10 ^ 3)),
+            (tifloat!(0.001), tifloat!(0x10000000000000 * 10 ^ -3)),
+            (tifloat!(3.14159265358979), tifloat!(0x31415926535898 * 10 ^ 0)),
+            (tifloat!(9.99999999999995), tifloat!(0x10000000000000 * 10 ^ 1)),
+            (tifloat!(9.9999999999999E99), tifloat!(0x99999999999999 * 10 ^ 99)),
+            (tifloat!(1e-99), tifloat!(0x10000000000000 * 10 ^ -99)),
+            (tifloat!(0.0), tifloat!(0x00000000000000 * 10 ^ 0)),
+        ];
+
+        for (literal, expected) in cases {
+            assert_eq!(literal, expected);
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let cases = [
+            ("", ParseFloatError::InvalidString),
+            (".", ParseFloatError::InvalidString),
+            ("1.2.3", ParseFloatError::InvalidString),
+            ("12a", ParseFloatError::InvalidString),
+            ("1E", ParseFloatError::InvalidString),
+            ("1E--2", ParseFloatError::InvalidString),
+            ("1E100", ParseFloatError::InvalidExponent),
+            ("9.99999999999999E99", ParseFloatError::InvalidExponent),
+            ("1E-100", ParseFloatError::InvalidExponent),
+        ];
+
+        for (string, expected) in cases {
+            assert_eq!(string.parse::<Float>(), Err(expected), "{string}");
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use crate::fixed::Fixed;
+use crate::uint::U256;
+use crate::{Float, FloatError};
+
+impl Float {
+    /// Raises this Float to the power `exponent`, as `^` does.
+    ///
+    /// Integer powers are computed exactly wherever the exact power fits in 256 bits, and
+    /// rounded half-up once. Other powers are computed as `exp(exponent * ln self)` with more than
+    /// 30 digits in the product, so that the error in the log isn't magnified by the exponent
+    /// past the 14 digits that are kept.
+    ///
+    /// Fails with [`FloatError::NonReal`] for a negative base and a non-integer exponent, as
+    /// [`Float::sqrt`] does for a negative value, and with [`FloatError::Domain`] for `0^0`; a
+    /// negative power of zero fails with [`FloatError::DivideByZero`]. An out-of-range result
+    /// fails with [`FloatError::Overflow`] or [`FloatError::Underflow`].
+    pub fn pow(&self, exponent: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || exponent.is_undefined() {
+            return Ok(Float::undefined());
+        } else if exponent.is_zero() {
+            return if self.is_zero() {
+                Err(FloatError::Domain)
+            } else {
+                Ok(Float::ONE)
+            };
+        } else if self.is_zero() {
+            return if exponent.is_negative() {
+                Err(FloatError::DivideByZero)
+            } else {
+                Ok(Float::ZERO)
+            };
+        }
+
+        let integer = exponent.as_integer();
+        if self.is_negative() && !exponent.is_integer() {
+            return Err(FloatError::NonReal);
+        }
+
+        // an odd power of a negative base is negative; integers too large for an i64 are even
+        let negative = self.is_negative() && integer.is_some_and(|power| power % 2 != 0);
+
+        let magnitude = match integer.and_then(|power| exact_power(&self.abs(), power)) {
+            Some(power) => power?,
+            None => {
+                let log = Fixed::ln(&self.abs());
+
+                match log.mul_float(exponent) {
+                    Some(product) => product.exp()?,
+                    None if log.is_negative() == exponent.is_negative() => {
+                        return Err(FloatError::Overflow)
+                    }
+                    None => return Err(FloatError::Underflow),
+                }
+            }
+        };
+
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+
+    /// The square, like `x²`, computed exactly and rounded half-up once.
+    ///
+    /// Fails with [`FloatError::Overflow`] or [`FloatError::Underflow`] if the square is out of
+    /// range. The square of an undefined Float is undefined.
+    #[doc(alias = "²")]
+    #[doc(alias = "FPSquare")]
+    pub fn squared(&self) -> Result<Float, FloatError> {
+        self.small_power(2)
+    }
+
+    /// The cube, like `x³`, computed exactly and rounded half-up once. The cube of a negative
+    /// value is negative.
+    ///
+    /// Fails with [`FloatError::Overflow`] or [`FloatError::Underflow`] if the cube is out of
+    /// range. The cube of an undefined Float is undefined.
+    #[doc(alias = "³")]
+    #[doc(alias = "Cube")]
+    pub fn cubed(&self) -> Result<Float, FloatError> {
+        self.small_power(3)
+    }
+
+    /// A positive power small enough that the exact power of any Float fits in 256 bits.
+    fn small_power(&self, power: i64) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() {
+            return Ok(Float::ZERO);
+        }
+
+        let magnitude = exact_power(&self.abs(), power).unwrap()?;
+
+        Ok(if self.is_negative() && power % 2 != 0 {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
+
+    /// The reciprocal, like `x⁻¹`, rounded half-up to 14 significant digits in a single step.
+    ///
+    /// Fails with [`FloatError::DivideByZero`] for zero, and with [`FloatError::Underflow`] for
+    /// values above `1E99`, whose reciprocals are too small. The reciprocal of an undefined Float
+    /// is undefined.
+    #[doc(alias = "⁻¹")]
+    #[doc(alias = "FPRecip")]
+    pub fn recip(&self) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() {
+            return Err(FloatError::DivideByZero);
+        }
+
+        Float::from_scaled_ratio(
+            self.is_negative(),
+            1,
+            self.significand_dec() as u128,
+            13 - self.exponent() as i64,
+        )
+    }
+}
+
+/// `base ^ power` for a positive base, computed exactly and rounded once, or `None` if the exact
+/// power is too large to compute this way.
+fn exact_power(base: &Float, power: i64) -> Option<Result<Float, FloatError>> {
+    // shorter significands have more powers that fit
+    let mut significand = base.significand_dec() as u128;
+    let mut exponent = base.exponent() as i64 - 13;
+    while significand.is_multiple_of(10) {
+        significand /= 10;
+        exponent += 1;
+    }
+
+    if power.unsigned_abs() > 256 {
+        return None;
+    }
+
+    let value = (0..power.unsigned_abs())
+        .try_fold(U256::new(1), |value, _| value.checked_mul(significand))?;
+    let exponent = exponent * power;
+
+    if power > 0 {
+        Some(value.to_float(false, exponent))
+    } else {
+        // the reciprocal of a power that doesn't end in zero never terminates within 22 digits
+        // of anything that would fit
+        let denominator = value.to_u128().filter(|&value| value < 10u128.pow(22))?;
+
+        Some(Float::from_scaled_ratio(false, 1, denominator, exponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn integer_powers() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+
+        assert_eq!(
+            two.pow(&tifloat!(0x10000000000000 * 10 ^ 1)),
+            Ok(tifloat!(0x10240000000000 * 10 ^ 3))
+        );
+        assert_eq!(
+            (-two).pow(&tifloat!(0x30000000000000 * 10 ^ 0)),
+            Ok(tifloat!(-0x80000000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            (-two).pow(&tifloat!(-0x20000000000000 * 10 ^ 0)),
+            Ok(tifloat!(0x25000000000000 * 10 ^ -1))
+        );
+        // 1.0000005 ^ 2 = 1.00000100000025 and 2 ^ -21 = 4.76837158203125E-7 are both halfway
+        assert_eq!(
+            tifloat!(0x10000005000000 * 10 ^ 0).pow(&two),
+            Ok(tifloat!(0x10000010000003 * 10 ^ 0))
+        );
+        assert_eq!(
+            two.pow(&tifloat!(-0x21000000000000 * 10 ^ 1)),
+            Ok(tifloat!(0x47683715820313 * 10 ^ -7))
+        );
+        assert_eq!(
+            Float::PI.pow(&tifloat!(0x30000000000000 * 10 ^ 0)),
+            Ok(tifloat!(0x31006276680300 * 10 ^ 1))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 1).pow(&tifloat!(0x99000000000000 * 10 ^ 1)),
+            Ok(tifloat!(0x10000000000000 * 10 ^ 99))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000001 * 10 ^ 0).pow(&tifloat!(0x10000000000000 * 10 ^ 13)),
+            Ok(tifloat!(0x27182818284589 * 10 ^ 0))
+        );
+        assert_eq!(
+            (-Float::ONE).pow(&tifloat!(0x10000000000000 * 10 ^ 50)),
+            Ok(Float::ONE)
+        );
+    }
+
+    #[test]
+    fn real_powers() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+        let half = tifloat!(0x50000000000000 * 10 ^ -1);
+
+        assert_eq!(two.pow(&half), two.sqrt());
+        assert_eq!(
+            tifloat!(0x81000000000000 * 10 ^ 1).pow(&tifloat!(0x25000000000000 * 10 ^ -1)),
+            Ok(tifloat!(0x30000000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            Float::PI.pow(&Float::PI),
+            Ok(tifloat!(0x36462159607208 * 10 ^ 1))
+        );
+        assert_eq!(
+            two.pow(&tifloat!(-0x15000000000000 * 10 ^ 0)),
+            Ok(tifloat!(0x35355339059327 * 10 ^ -1))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+        let half = tifloat!(0x50000000000000 * 10 ^ -1);
+
+        assert_eq!(Float::ZERO.pow(&two), Ok(Float::ZERO));
+        assert_eq!(two.pow(&Float::ZERO), Ok(Float::ONE));
+        assert!(two.pow(&Float::undefined()).unwrap().is_undefined());
+
+        assert_eq!((-two).pow(&half), Err(FloatError::NonReal));
+        assert_eq!((-two).pow(&half), (-two).sqrt());
+        assert_eq!(Float::ZERO.pow(&Float::ZERO), Err(FloatError::Domain));
+        assert_eq!(Float::ZERO.pow(&-two), Err(FloatError::DivideByZero));
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 1).pow(&tifloat!(0x10000000000000 * 10 ^ 2)),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            half.pow(&tifloat!(0x40000000000000 * 10 ^ 2)),
+            Err(FloatError::Underflow)
+        );
+        assert_eq!(two.pow(&Float::MAX), Err(FloatError::Overflow));
+        assert_eq!(two.pow(&-Float::MAX), Err(FloatError::Underflow));
+        assert_eq!(half.pow(&Float::MAX), Err(FloatError::Underflow));
+    }
+
+    #[test]
+    fn squared_and_cubed() {
+        let value = tifloat!(-0x12345678901234 * 10 ^ 2);
+
+        assert_eq!(value.squared(), Ok(tifloat!(0x15241578753237 * 10 ^ 4)));
+        assert_eq!(value.cubed(), Ok(tifloat!(-0x18816763723534 * 10 ^ 6)));
+        assert_eq!(
+            tifloat!(0x10000005000000 * 10 ^ 0).squared(),
+            Ok(tifloat!(0x10000010000003 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(-0x30000000000000 * 10 ^ -1).cubed(),
+            Ok(tifloat!(-0x27000000000000 * 10 ^ -2))
+        );
+        assert_eq!(Float::ZERO.cubed(), Ok(Float::ZERO));
+        assert!(Float::undefined().squared().unwrap().is_undefined());
+
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 50).squared(),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            tifloat!(-0x10000000000000 * 10 ^ -34).cubed(),
+            Err(FloatError::Underflow)
+        );
+    }
+
+    #[test]
+    fn recip() {
+        let cases = [
+            (
+                tifloat!(0x30000000000000 * 10 ^ 0),
+                tifloat!(0x33333333333333 * 10 ^ -1),
+            ),
+            (
+                tifloat!(-0x15000000000000 * 10 ^ 0),
+                tifloat!(-0x66666666666667 * 10 ^ -1),
+            ),
+            (
+                tifloat!(0x80000000000000 * 10 ^ -50),
+                tifloat!(0x12500000000000 * 10 ^ 49),
+            ),
+            (
+                Float::MAX_SAFE_INTEGER,
+                tifloat!(0x10000000000000 * 10 ^ -14),
+            ),
+            (Float::MIN_POSITIVE, tifloat!(0x10000000000000 * 10 ^ 99)),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(value.recip(), Ok(expected), "{value:?}");
+        }
+
+        assert!(Float::undefined().recip().unwrap().is_undefined());
+        assert_eq!(Float::ZERO.recip(), Err(FloatError::DivideByZero));
+        assert_eq!(Float::MAX.recip(), Err(FloatError::Underflow));
+    }
+}
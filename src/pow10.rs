@@ -0,0 +1,79 @@
+use crate::{Float, FloatError};
+
+impl Float {
+    /// Multiplies by `10 ^ power` exactly, by adjusting the exponent. Fails with
+    /// [`FloatError::Overflow`] or [`FloatError::Underflow`] if the exponent leaves `-99..=99`.
+    /// Zero and undefined Floats are returned unchanged.
+    pub fn mul_pow10(&self, power: i32) -> Result<Float, FloatError> {
+        if self.is_zero() || self.is_undefined() {
+            return Ok(*self);
+        }
+
+        let exponent = (self.exponent() as i32).saturating_add(power);
+
+        self.with_exponent(exponent.clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+    }
+
+    /// Splits this Float into a mantissa with magnitude in `[1, 10)` and a decimal exponent, such
+    /// that `self == mantissa * 10 ^ exponent`. Zero splits into zero and zero.
+    pub fn frexp10(&self) -> (Float, i8) {
+        if self.is_zero() || self.is_undefined() {
+            return (*self, 0);
+        }
+
+        // the exponent becomes zero, which is always in range
+        (self.shift(-self.exponent()).unwrap(), self.exponent())
+    }
+
+    /// Computes `mantissa * 10 ^ exponent`, the inverse of [`Float::frexp10`].
+    pub fn ldexp10(mantissa: Float, exponent: i32) -> Result<Float, FloatError> {
+        mantissa.mul_pow10(exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Flags, Float, FloatError};
+
+    #[test]
+    fn mul_pow10() {
+        let float = tifloat!(0x15000000000000 * 10 ^ 0);
+
+        assert_eq!(
+            float.mul_pow10(3).unwrap(),
+            tifloat!(0x15000000000000 * 10 ^ 3)
+        );
+        assert_eq!(
+            float.mul_pow10(-7).unwrap(),
+            tifloat!(0x15000000000000 * 10 ^ -7)
+        );
+        assert_eq!(
+            Float::MIN_POSITIVE.mul_pow10(198).unwrap(),
+            tifloat!(0x10000000000000 * 10 ^ 99)
+        );
+        assert_eq!(Float::ZERO.mul_pow10(1000).unwrap(), Float::ZERO);
+
+        let flagged = float.with_flags(Flags::NEGATIVE | Flags::IDK);
+        assert_eq!(flagged.mul_pow10(2).unwrap().flags(), flagged.flags());
+
+        assert!(matches!(float.mul_pow10(100), Err(FloatError::Overflow)));
+        assert!(matches!(float.mul_pow10(-100), Err(FloatError::Underflow)));
+        assert!(matches!(
+            float.mul_pow10(-7).unwrap().mul_pow10(i32::MIN),
+            Err(FloatError::Underflow)
+        ));
+    }
+
+    #[test]
+    fn frexp10() {
+        let float = tifloat!(-0x12345000000000 * 10 ^ 4);
+
+        assert_eq!(float.frexp10(), (tifloat!(-0x12345000000000 * 10 ^ 0), 4));
+        assert_eq!(Float::ZERO.frexp10(), (Float::ZERO, 0));
+
+        for float in [float, Float::MAX, Float::MIN_POSITIVE, -Float::PI] {
+            let (mantissa, exponent) = float.frexp10();
+            assert_eq!(Float::ldexp10(mantissa, exponent as i32).unwrap(), float);
+        }
+    }
+}
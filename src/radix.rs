@@ -0,0 +1,178 @@
+use crate::float::ParseFloatError;
+use crate::{Float, FloatError};
+
+impl Float {
+    /// Writes this integer in the given radix, using uppercase letters for digits past 9, like
+    /// the TI-86's BASE menu. Negative integers are written with a leading `-`.
+    ///
+    /// Fails with [`FloatError::NotAnInteger`] if the value has a fractional part, with
+    /// [`FloatError::Overflow`] if its magnitude does not fit in a `u64`, and with
+    /// [`FloatError::NotANumber`] if it is undefined, since it has no digits to write.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub fn to_radix_string(&self, radix: u32) -> Result<String, FloatError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36"
+        );
+
+        if self.is_undefined() {
+            return Err(FloatError::NotANumber);
+        }
+
+        let significant_figures = self.significant_figures();
+        let exponent = self.exponent() as i32 - (significant_figures.len() as i32 - 1);
+
+        let mut magnitude = significant_figures
+            .iter()
+            .fold(0u64, |acc, &digit| acc * 10 + digit as u64);
+
+        if magnitude != 0 && exponent < 0 {
+            return Err(FloatError::NotAnInteger);
+        } else if magnitude != 0 {
+            magnitude = 10u64
+                .checked_pow(exponent as u32)
+                .and_then(|scale| magnitude.checked_mul(scale))
+                .ok_or(FloatError::Overflow)?;
+        }
+
+        let mut digits = Vec::new();
+        loop {
+            let digit = char::from_digit((magnitude % radix as u64) as u32, radix).unwrap();
+            digits.push(digit.to_ascii_uppercase());
+
+            magnitude /= radix as u64;
+            if magnitude == 0 {
+                break;
+            }
+        }
+
+        if self.is_negative() && digits != ['0'] {
+            digits.push('-');
+        }
+
+        Ok(digits.iter().rev().collect())
+    }
+
+    /// Parses an integer written in the given radix, optionally preceded by `-`. Letters may be
+    /// upper or lowercase.
+    ///
+    /// The integer must be exactly representable, so one with more than 14 significant digits
+    /// in decimal fails with [`ParseFloatError::Inexact`] rather than being rounded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Float, ParseFloatError> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36"
+        );
+
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(ParseFloatError::InvalidString);
+        }
+
+        let magnitude =
+            u64::from_str_radix(digits, radix).map_err(|_| ParseFloatError::InvalidExponent)?;
+        let decimal_digits = magnitude
+            .to_string()
+            .bytes()
+            .map(|byte| byte - b'0')
+            .collect::<Vec<_>>();
+
+        if decimal_digits.iter().skip(14).any(|&digit| digit != 0) {
+            return Err(ParseFloatError::Inexact);
+        }
+
+        Float::from_decimal_digits(negative, &decimal_digits, decimal_digits.len() as i64 - 1)
+            .map_err(|_| ParseFloatError::InvalidExponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn to_radix_string() {
+        let cases = [
+            (tifloat!(0x25500000000000 * 10 ^ 2), 16, "FF"),
+            (tifloat!(-0x25500000000000 * 10 ^ 2), 2, "-11111111"),
+            (tifloat!(0x64000000000000 * 10 ^ 1), 8, "100"),
+            (tifloat!(0x10000000000000 * 10 ^ 18), 16, "DE0B6B3A7640000"),
+            (tifloat!(0x00000000000000 * 10 ^ 0), 16, "0"),
+        ];
+
+        for (float, radix, expected) in cases {
+            assert_eq!(float.to_radix_string(radix).unwrap(), expected, "{float:?}");
+        }
+    }
+
+    #[test]
+    fn to_radix_string_errors() {
+        assert!(matches!(
+            tifloat!(0x12500000000000 * 10 ^ 1).to_radix_string(16),
+            Err(FloatError::NotAnInteger)
+        ));
+        assert!(matches!(
+            tifloat!(0x10000000000000 * 10 ^ 20).to_radix_string(16),
+            Err(FloatError::Overflow)
+        ));
+        assert!(matches!(
+            Float::undefined().to_radix_string(16),
+            Err(FloatError::NotANumber)
+        ));
+    }
+
+    #[test]
+    fn from_str_radix() {
+        let cases = [
+            ("FF", 16, tifloat!(0x25500000000000 * 10 ^ 2)),
+            ("ff", 16, tifloat!(0x25500000000000 * 10 ^ 2)),
+            ("-11111111", 2, tifloat!(-0x25500000000000 * 10 ^ 2)),
+            ("777", 8, tifloat!(0x51100000000000 * 10 ^ 2)),
+            ("DE0B6B3A7640000", 16, tifloat!(0x10000000000000 * 10 ^ 18)),
+        ];
+
+        for (string, radix, expected) in cases {
+            assert_eq!(
+                Float::from_str_radix(string, radix),
+                Ok(expected),
+                "{string}"
+            );
+        }
+
+        let errors = [
+            ("", ParseFloatError::InvalidString),
+            ("12", ParseFloatError::InvalidString),
+            ("+1", ParseFloatError::InvalidString),
+            ("1.0", ParseFloatError::InvalidString),
+        ];
+
+        for (string, expected) in errors {
+            assert_eq!(Float::from_str_radix(string, 2), Err(expected), "{string}");
+        }
+
+        assert_eq!(
+            Float::from_str_radix("10000000000000000", 16),
+            Err(ParseFloatError::InvalidExponent)
+        );
+        assert_eq!(
+            Float::from_str_radix("FFFFFFFFFFFFFFFF", 16),
+            Err(ParseFloatError::Inexact)
+        );
+        assert_eq!(
+            Float::from_str_radix("38D7EA4C68001", 16),
+            Err(ParseFloatError::Inexact)
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::Float;
+
+/// The number of 14-digit steps in `[0, 1)`.
+const STEPS: u128 = 10u128.pow(14);
+
+/// Samples uniformly from the `10 ^ 14` multiples of `1E-14` in `[0, 1)`.
+impl Distribution<Float> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float {
+        Float::from_scaled_integer(false, rng.gen_range(0..STEPS), -14).unwrap()
+    }
+}
+
+/// Samples Floats in a range, for [`Uniform<Float>`](rand::distributions::Uniform) and
+/// [`Rng::gen_range`].
+///
+/// A sample from [`Standard`] is scaled into the range and rounded, so the result is always a
+/// representable Float but, as with `f64`, not every Float in the range is equally likely.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UniformFloatSampler {
+    low: Float,
+    high: Float,
+    inclusive: bool,
+}
+
+impl UniformSampler for UniformFloatSampler {
+    type X = Float;
+
+    /// # Panics
+    ///
+    /// Panics if `low >= high` or either bound is undefined.
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Float> + Sized,
+        B2: SampleBorrow<Float> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        assert!(
+            !low.is_undefined() && !high.is_undefined() && low < high,
+            "Uniform::new called with `low >= high`"
+        );
+
+        UniformFloatSampler {
+            low,
+            high,
+            inclusive: false,
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `low > high` or either bound is undefined.
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Float> + Sized,
+        B2: SampleBorrow<Float> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        assert!(
+            !low.is_undefined() && !high.is_undefined() && low <= high,
+            "Uniform::new_inclusive called with `low > high`"
+        );
+
+        UniformFloatSampler {
+            low,
+            high,
+            inclusive: true,
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float {
+        loop {
+            let t: Float = rng.gen();
+            let complement = (Float::ONE - t).unwrap();
+
+            // interpolating between the bounds, rather than scaling their difference, can't
+            // overflow, and rounding can only push the result just past a bound
+            let float = self
+                .low
+                .saturating_mul(complement)
+                .saturating_add(self.high.saturating_mul(t))
+                .clamp(self.low, self.high);
+
+            if self.inclusive || float < self.high {
+                return float;
+            }
+        }
+    }
+}
+
+impl SampleUniform for Float {
+    type Sampler = UniformFloatSampler;
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::distributions::{Distribution, Standard, Uniform};
+    use rand::rngs::mock::StepRng;
+    use rand::Rng;
+
+    use crate::{tifloat, Float};
+
+    #[test]
+    fn standard() {
+        let mut rng = StepRng::new(0, 0x0123456789ABCDEF);
+
+        for float in Standard.sample_iter(&mut rng).take(100) {
+            let float: Float = float;
+
+            assert!(float >= Float::ZERO && float < Float::ONE);
+            assert!(float.mul_pow10(14).unwrap().is_integer());
+        }
+
+        assert_eq!(StepRng::new(0, 0).gen::<Float>(), Float::ZERO);
+    }
+
+    #[test]
+    fn uniform() {
+        let low = tifloat!(-0x50000000000000 * 10 ^ 99);
+        let high = tifloat!(0x12000000000000 * 10 ^ 0);
+        let mut rng = StepRng::new(0, 0x0123456789ABCDEF);
+
+        for float in Uniform::new(low, high).sample_iter(&mut rng).take(100) {
+            assert!(float >= low && float < high);
+        }
+
+        for float in Uniform::new_inclusive(-Float::MAX, Float::MAX)
+            .sample_iter(&mut rng)
+            .take(100)
+        {
+            assert!(!float.is_undefined());
+        }
+
+        assert_eq!(rng.gen_range(Float::PI..=Float::PI), Float::PI);
+        assert_eq!(StepRng::new(0, 0).gen_range(low..high), low);
+    }
+
+    #[test]
+    #[should_panic(expected = "Uniform::new called with `low >= high`")]
+    fn empty_range() {
+        Uniform::new(Float::ONE, Float::ONE);
+    }
+}
@@ -0,0 +1,165 @@
+use std::iter::FusedIterator;
+
+use crate::extended::Extended;
+use crate::Float;
+
+/// An iterator over `start`, `start + step`, `start + 2 * step`, ... up to and including `end`,
+/// created by [`Float::range`].
+#[derive(Debug, Clone)]
+pub struct FloatRange {
+    start: Float,
+    end: Float,
+    step: Float,
+    index: i128,
+    done: bool,
+}
+
+impl Float {
+    /// Iterates from `start` to `end` inclusive in increments of `step`, like `seq(`. A negative
+    /// `step` counts down.
+    ///
+    /// Each value is computed as `start + k * step` and rounded once, rather than by adding `step`
+    /// to the previous value, so rounding errors don't accumulate. The range stops at the last
+    /// value that doesn't pass `end` before rounding, so it includes `end` whenever `end - start`
+    /// is a multiple of `step`. Values that round to the same Float are each yielded, and values
+    /// too small for a Float are yielded as zero, as `seq(` flushes them.
+    ///
+    /// The range is empty if `end` is on the wrong side of `start`, or if any argument is
+    /// undefined.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, like [`Iterator::step_by`].
+    pub fn range(start: Float, end: Float, step: Float) -> FloatRange {
+        assert!(!step.is_zero(), "range step must be nonzero");
+
+        FloatRange {
+            start,
+            end,
+            step,
+            index: 0,
+            done: start.is_undefined() || end.is_undefined() || step.is_undefined(),
+        }
+    }
+}
+
+impl Iterator for FloatRange {
+    type Item = Float;
+
+    fn next(&mut self) -> Option<Float> {
+        if self.done {
+            return None;
+        }
+
+        let value = Extended::new(&self.step)
+            .mul(Extended::from_integer(self.index))
+            .add(Extended::new(&self.start));
+
+        // compare before rounding, so that values which round to the end don't extend the range
+        let overshoot = value.add(Extended::new(&-self.end)).signum();
+        if overshoot != 0 && (overshoot < 0) == self.step.is_negative() {
+            self.done = true;
+            return None;
+        }
+
+        // the next value is past an end that was reached exactly
+        self.index += 1;
+        self.done = overshoot == 0;
+
+        // a value between two Floats can only fail by underflowing
+        Some(value.to_float().unwrap_or(Float::ZERO))
+    }
+}
+
+impl FusedIterator for FloatRange {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float};
+
+    #[test]
+    fn range() {
+        let tenth = tifloat!(0x10000000000000 * 10 ^ -1);
+        let values: Vec<Float> = Float::range(Float::ZERO, Float::ONE, tenth).collect();
+
+        assert_eq!(values.len(), 11);
+        assert_eq!(values[3], tifloat!(0x30000000000000 * 10 ^ -1));
+        assert_eq!(values[10], Float::ONE);
+
+        let third = (Float::ONE / tifloat!(0x30000000000000 * 10 ^ 0)).unwrap();
+        assert_eq!(
+            Float::range(Float::ZERO, Float::ONE, third).last(),
+            Some(tifloat!(0x99999999999999 * 10 ^ -1))
+        );
+
+        let down: Vec<Float> = Float::range(Float::ONE, -Float::ONE, -Float::ONE).collect();
+        assert_eq!(down, [Float::ONE, Float::ZERO, -Float::ONE]);
+    }
+
+    #[test]
+    fn accumulation() {
+        // adding 0.4 to 1E14 one step at a time would round every step away
+        let start = tifloat!(0x10000000000000 * 10 ^ 14);
+        let step = tifloat!(0x40000000000000 * 10 ^ -1);
+        let end = tifloat!(0x10000000000001 * 10 ^ 14);
+        let values: Vec<Float> = Float::range(start, end, step).collect();
+
+        assert_eq!(values.len(), 26);
+        assert_eq!(values[12], start);
+        assert_eq!(values[13], end);
+        assert_eq!(values[25], end);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Float::range(Float::ONE, Float::ZERO, Float::ONE).count(), 0);
+        assert_eq!(
+            Float::range(Float::ZERO, Float::ONE, -Float::ONE).count(),
+            0
+        );
+        assert_eq!(
+            Float::range(Float::ZERO, Float::undefined(), Float::ONE).count(),
+            0
+        );
+        assert_eq!(
+            Float::range(Float::ONE, Float::ONE, Float::ONE).collect::<Vec<_>>(),
+            [Float::ONE]
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let range = Float::range(-Float::MAX, Float::MAX, Float::MAX);
+
+        assert_eq!(
+            range.collect::<Vec<_>>(),
+            [-Float::MAX, Float::ZERO, Float::MAX]
+        );
+        assert_eq!(Float::range(Float::MAX, Float::MAX, Float::ONE).count(), 1);
+    }
+
+    #[test]
+    fn underflow() {
+        let range = Float::range(
+            tifloat!(-0x15000000000000 * 10 ^ -99),
+            tifloat!(0x15000000000000 * 10 ^ -99),
+            tifloat!(0x10000000000000 * 10 ^ -99),
+        );
+
+        assert_eq!(
+            range.collect::<Vec<_>>(),
+            [
+                tifloat!(-0x15000000000000 * 10 ^ -99),
+                Float::ZERO,
+                Float::ZERO,
+                tifloat!(0x15000000000000 * 10 ^ -99),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "range step must be nonzero")]
+    fn zero_step() {
+        Float::range(Float::ZERO, Float::ONE, Float::ZERO);
+    }
+}
@@ -0,0 +1,184 @@
+use std::ops::Rem;
+
+use crate::float::ref_op;
+use crate::{Float, FloatError};
+
+/// An exact remainder `remainder * 10 ^ exponent` of dividing by `modulus * 10 ^ exponent`.
+struct Remainder {
+    remainder: u128,
+    modulus: u128,
+    exponent: i64,
+}
+
+impl Float {
+    /// Computes `|self| mod |rhs|` exactly, using the integer significands of both Floats.
+    fn remainder(&self, rhs: &Float) -> Remainder {
+        let (a, a_exponent) = (self.significand_dec() as u128, self.exponent() as i64 - 13);
+        let (b, b_exponent) = (rhs.significand_dec() as u128, rhs.exponent() as i64 - 13);
+
+        if a_exponent >= b_exponent {
+            // a * 10 ^ k mod b, one power of ten at a time to stay within a u128
+            let remainder = (0..a_exponent - b_exponent).fold(a % b, |acc, _| acc * 10 % b);
+
+            Remainder {
+                remainder,
+                modulus: b,
+                exponent: b_exponent,
+            }
+        } else {
+            let modulus = 10u128
+                .checked_pow((b_exponent - a_exponent) as u32)
+                .and_then(|power| power.checked_mul(b));
+
+            Remainder {
+                // a modulus too large for a u128 dwarfs a
+                remainder: modulus.map_or(a, |modulus| a % modulus),
+                modulus: modulus.unwrap_or(u128::MAX),
+                exponent: a_exponent,
+            }
+        }
+    }
+
+    /// The remainder of `self / rhs` as TI-BASIC computes it with `fPart(A/B)*B`. The quotient is
+    /// rounded to 14 digits before its fractional part is taken, so `1E50 fmod 3` is `0`, and
+    /// the result has the sign of `self`. [`Float::rem_exact`] computes the true remainder.
+    ///
+    /// Fails like the division and multiplication it's made of, with
+    /// [`FloatError::DivideByZero`] if `rhs` is zero, or [`FloatError::Overflow`] or
+    /// [`FloatError::Underflow`] if the quotient is out of range.
+    pub fn fmod(&self, rhs: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || rhs.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        (*self / *rhs)?.fract() * *rhs
+    }
+
+    /// The remainder of `self / rhs` with the quotient rounded toward zero, so that the result
+    /// has the sign of `self`. Unlike [`Float::fmod`], it is computed exactly, and rounded only
+    /// if it falls outside the Float range, so `1E50 rem_exact 3` is `1`.
+    pub fn rem_exact(&self, rhs: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || rhs.is_undefined() {
+            return Ok(Float::undefined());
+        } else if rhs.is_zero() {
+            return Err(FloatError::DivideByZero);
+        }
+
+        let Remainder {
+            remainder,
+            exponent,
+            ..
+        } = self.remainder(rhs);
+
+        Float::from_scaled_integer(self.is_negative(), remainder, exponent)
+    }
+
+    /// The least nonnegative remainder of `self / rhs`, like [`i64::rem_euclid`]. It is in
+    /// `0..|rhs|` up to rounding, which makes it the right choice for reducing a periodic
+    /// argument such as an angle.
+    pub fn rem_euclid(&self, rhs: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || rhs.is_undefined() {
+            return Ok(Float::undefined());
+        } else if rhs.is_zero() {
+            return Err(FloatError::DivideByZero);
+        }
+
+        let Remainder {
+            remainder,
+            modulus,
+            exponent,
+        } = self.remainder(rhs);
+
+        if self.is_negative() && remainder != 0 {
+            if modulus == u128::MAX {
+                // self is negligible next to rhs
+                return Ok(rhs.abs());
+            }
+
+            Float::from_scaled_integer(false, modulus - remainder, exponent)
+        } else {
+            Float::from_scaled_integer(false, remainder, exponent)
+        }
+    }
+}
+
+/// The remainder of `self / rhs` as the calculator computes `fPart(A/B)*B`; see [`Float::fmod`].
+/// Use [`Float::rem_exact`] for the exact remainder of large quotients.
+impl Rem for Float {
+    type Output = Result<Float, FloatError>;
+
+    fn rem(self, rhs: Float) -> Self::Output {
+        self.fmod(&rhs)
+    }
+}
+
+ref_op!(Rem, rem);
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn rem() {
+        let seven = tifloat!(0x70000000000000 * 10 ^ 0);
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+
+        assert_eq!((seven % two).unwrap(), Float::ONE);
+        assert_eq!((-seven % two).unwrap(), Float::NEG_ONE);
+        assert_eq!((seven % -two).unwrap(), Float::ONE);
+
+        // the quotient is rounded before its fractional part is taken
+        let big = tifloat!(0x10000000000000 * 10 ^ 50);
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+        assert_eq!((big % three).unwrap(), Float::ZERO);
+        assert_eq!(
+            (Float::ONE % three).unwrap(),
+            tifloat!(0x99999999999999 * 10 ^ -1)
+        );
+
+        assert!(matches!(seven % Float::ZERO, Err(FloatError::DivideByZero)));
+        assert!((Float::undefined() % seven).unwrap().is_undefined());
+    }
+
+    #[test]
+    fn rem_exact() {
+        let seven = tifloat!(0x70000000000000 * 10 ^ 0);
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+
+        assert_eq!(seven.rem_exact(&two).unwrap(), Float::ONE);
+        assert_eq!((-seven).rem_exact(&two).unwrap(), Float::NEG_ONE);
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 50)
+                .rem_exact(&tifloat!(0x30000000000000 * 10 ^ 0))
+                .unwrap(),
+            Float::ONE
+        );
+        assert_eq!(
+            tifloat!(0x25000000000000 * 10 ^ -1)
+                .rem_exact(&tifloat!(0x10000000000000 * 10 ^ 5))
+                .unwrap(),
+            tifloat!(0x25000000000000 * 10 ^ -1)
+        );
+        assert!(matches!(
+            seven.rem_exact(&Float::ZERO),
+            Err(FloatError::DivideByZero)
+        ));
+    }
+
+    #[test]
+    fn rem_euclid() {
+        let seven = tifloat!(0x70000000000000 * 10 ^ 0);
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+
+        assert_eq!((-seven).rem_euclid(&two).unwrap(), Float::ONE);
+        assert_eq!((-seven).rem_euclid(&-two).unwrap(), Float::ONE);
+        assert_eq!(seven.rem_euclid(&two).unwrap(), Float::ONE);
+        assert_eq!((-two).rem_euclid(&two).unwrap(), Float::ZERO);
+        assert_eq!(
+            tifloat!(-0x10000000000000 * 10 ^ -90)
+                .rem_euclid(&tifloat!(0x20000000000000 * 10 ^ 0))
+                .unwrap(),
+            two
+        );
+    }
+}
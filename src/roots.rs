@@ -0,0 +1,303 @@
+use crate::fixed::Fixed;
+use crate::uint::U256;
+use crate::{Float, FloatError};
+
+impl Float {
+    /// The square root, correctly rounded half-up to 14 significant digits.
+    ///
+    /// Fails with [`FloatError::NonReal`] for a negative value, as `√(` does in Real mode. The
+    /// root of an undefined Float is undefined.
+    pub fn sqrt(&self) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() {
+            return Ok(Float::ZERO);
+        } else if self.is_negative() {
+            return Err(FloatError::NonReal);
+        }
+
+        // halve an even exponent, so the root of the significand is the root of the value
+        let (mut significand, mut exponent) =
+            (self.significand_dec() as u128, self.exponent() as i64 - 13);
+        if exponent % 2 != 0 {
+            significand *= 10;
+            exponent -= 1;
+        }
+
+        // the root has at least 15 digits, and the halfway point between two 14-digit results is
+        // an integer, so rounding the floor of the root half-up rounds the root itself correctly
+        let root = (significand * 10u128.pow(16)).isqrt();
+
+        Float::from_scaled_integer(false, root, (exponent - 16) / 2)
+    }
+
+    /// The cube root, correctly rounded half-up to 14 significant digits. Like `³√(`, it is
+    /// defined for negative values, so the cube root of `-8` is `-2`.
+    pub fn cbrt(&self) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() {
+            return Ok(Float::ZERO);
+        }
+
+        // make the exponent a multiple of three, so it can be divided exactly
+        let exponent = self.exponent() as i64 - 13;
+        let shift = exponent.rem_euclid(3);
+        let significand = self.significand_dec() as u128 * 10u128.pow(shift as u32);
+        let exponent = exponent - shift;
+
+        // as with square roots, a root of at least 15 digits can be rounded from its floor
+        let scaled = U256::new(significand).checked_mul(10u128.pow(30)).unwrap();
+        let estimate = (significand as f64).cbrt() * 1e10;
+        let root = integer_cbrt(scaled, (estimate * (1.0 + 1e-9)) as u128 + 2);
+
+        Float::from_scaled_integer(self.is_negative(), root, (exponent - 30) / 3)
+    }
+
+    /// The `self`-th root of `radicand`, as `xˣ√y` computes it with `x` as `self` and `y` as
+    /// `radicand`. It is rounded once from more than 30 digits, rather than twice by raising the
+    /// radicand to the power `1 / self`, and square and cube roots are exactly [`Float::sqrt`]
+    /// and [`Float::cbrt`].
+    ///
+    /// As on the calculator, odd integer roots of negative values are negative, so `3ˣ√-8` is
+    /// `-2`. Any other root of a negative value fails with [`FloatError::NonReal`], as
+    /// [`Float::sqrt`] does, and any zeroth root with [`FloatError::Domain`]. A negative root of
+    /// zero fails with [`FloatError::DivideByZero`], and an out-of-range result with
+    /// [`FloatError::Overflow`] or [`FloatError::Underflow`].
+    #[doc(alias = "ˣ√")]
+    pub fn xroot(&self, radicand: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || radicand.is_undefined() {
+            return Ok(Float::undefined());
+        } else if self.is_zero() {
+            return Err(FloatError::Domain);
+        }
+
+        let index = self.as_integer();
+        if radicand.is_negative() && index.is_none_or(|index| index % 2 == 0) {
+            return Err(FloatError::NonReal);
+        } else if radicand.is_zero() {
+            return if self.is_negative() {
+                Err(FloatError::DivideByZero)
+            } else {
+                Ok(Float::ZERO)
+            };
+        }
+
+        match index {
+            Some(1) => return Ok(*radicand),
+            Some(2) => return radicand.sqrt(),
+            Some(3) => return radicand.cbrt(),
+            _ => {}
+        }
+
+        // |radicand| ^ (1 / self) = exp(ln |radicand| / self), and a quotient too large for a
+        // Fixed is far out of range
+        let log = Fixed::ln(&radicand.abs());
+        let root = match log.div_float(self) {
+            Some(exponent) => exponent.exp()?,
+            None if log.is_negative() == self.is_negative() => return Err(FloatError::Overflow),
+            None => return Err(FloatError::Underflow),
+        };
+
+        Ok(if radicand.is_negative() { -root } else { root })
+    }
+
+    /// `√(self² + other²)`, the distance of the point `(self, other)` from the origin, correctly
+    /// rounded half-up to 14 significant digits, as `R►Pr(` and `abs(` of a complex number find
+    /// it.
+    ///
+    /// The squares are computed exactly, so they can't overflow or underflow even when the
+    /// squares of the values would be out of range: only a result of `1E100` or more fails, with
+    /// [`FloatError::Overflow`]. If either value is undefined, the result is undefined.
+    pub fn hypot(&self, other: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || other.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        let (larger, smaller) = if self.abs() >= other.abs() {
+            (self.abs(), other.abs())
+        } else {
+            (other.abs(), self.abs())
+        };
+
+        // a square more than 30 places smaller can't move the root to the next rounding
+        let shift = larger.exponent() as i64 - smaller.exponent() as i64;
+        if smaller.is_zero() || shift > 15 {
+            return Ok(larger);
+        }
+
+        // line both up 15 places past the larger's last digit, so the root has at least 29
+        // digits and can be rounded from its floor, as in `Float::sqrt`
+        let a = larger.significand_dec() as u128 * 10u128.pow(15);
+        let b = smaller.significand_dec() as u128 * 10u128.pow(15 - shift as u32);
+        let root = U256::mul(a, a)
+            .checked_add(U256::mul(b, b))
+            .unwrap()
+            .isqrt();
+
+        Float::from_scaled_integer(false, root, larger.exponent() as i64 - 28)
+    }
+}
+
+/// The cube root of `n`, rounded down, by Newton's method from an `estimate` at least that large.
+fn integer_cbrt(n: U256, estimate: u128) -> u128 {
+    // from above, each step stays at or above the root, and the first step that doesn't descend
+    // has found it
+    let mut root = estimate;
+    loop {
+        let quotient = n.div_rem(root * root).0.to_u128().unwrap();
+        let next = (2 * root + quotient) / 3;
+
+        if next >= root {
+            return root;
+        }
+
+        root = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn sqrt() {
+        assert_eq!(
+            tifloat!(0x20000000000000 * 10 ^ 0).sqrt(),
+            Ok(tifloat!(0x14142135623731 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x40000000000000 * 10 ^ 0).sqrt(),
+            Ok(tifloat!(0x20000000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x25000000000000 * 10 ^ -1).sqrt(),
+            Ok(tifloat!(0x50000000000000 * 10 ^ -1))
+        );
+        assert_eq!(
+            tifloat!(0x12345678901234 * 10 ^ 13).sqrt(),
+            Ok(tifloat!(0x35136418288201 * 10 ^ 6))
+        );
+        assert_eq!(Float::MAX.sqrt(), Ok(tifloat!(0x99999999999999 * 10 ^ 49)));
+        assert_eq!(
+            Float::MIN_POSITIVE.sqrt(),
+            Ok(tifloat!(0x31622776601684 * 10 ^ -50))
+        );
+        assert_eq!(Float::ZERO.sqrt(), Ok(Float::ZERO));
+        assert!(Float::undefined().sqrt().unwrap().is_undefined());
+
+        assert_eq!((-Float::ONE).sqrt(), Err(FloatError::NonReal));
+    }
+
+    #[test]
+    fn cbrt() {
+        assert_eq!(
+            tifloat!(-0x80000000000000 * 10 ^ 0).cbrt(),
+            Ok(tifloat!(-0x20000000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x20000000000000 * 10 ^ 0).cbrt(),
+            Ok(tifloat!(0x12599210498949 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x27000000000000 * 10 ^ -5).cbrt(),
+            Ok(tifloat!(0x30000000000000 * 10 ^ -2))
+        );
+        assert_eq!(
+            tifloat!(0x12345678901234 * 10 ^ 13).cbrt(),
+            Ok(tifloat!(0x23112042409018 * 10 ^ 4))
+        );
+        assert_eq!(Float::MAX.cbrt(), Ok(tifloat!(0x21544346900319 * 10 ^ 33)));
+        assert_eq!(
+            Float::MIN_POSITIVE.cbrt(),
+            Ok(tifloat!(0x10000000000000 * 10 ^ -33))
+        );
+        assert_eq!(tifloat!(0x99999999999999 * 10 ^ -1).cbrt(), Ok(Float::ONE));
+        assert!(Float::undefined().cbrt().unwrap().is_undefined());
+    }
+
+    #[test]
+    fn xroot() {
+        let two = tifloat!(0x20000000000000 * 10 ^ 0);
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+        let four = tifloat!(0x40000000000000 * 10 ^ 0);
+        let five = tifloat!(0x50000000000000 * 10 ^ 0);
+
+        assert_eq!(five.xroot(&tifloat!(0x32000000000000 * 10 ^ 1)), Ok(two));
+        assert_eq!(five.xroot(&tifloat!(-0x32000000000000 * 10 ^ 1)), Ok(-two));
+        assert_eq!(three.xroot(&tifloat!(-0x80000000000000 * 10 ^ 0)), Ok(-two));
+        assert_eq!(four.xroot(&two), Ok(tifloat!(0x11892071150027 * 10 ^ 0)));
+        assert_eq!(two.xroot(&two), two.sqrt());
+        assert_eq!(
+            (-two).xroot(&four),
+            Ok(tifloat!(0x50000000000000 * 10 ^ -1))
+        );
+        assert_eq!(
+            tifloat!(0x25000000000000 * 10 ^ 0).xroot(&two),
+            Ok(tifloat!(0x13195079107729 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 2).xroot(&Float::MAX),
+            Ok(tifloat!(0x10000000000000 * 10 ^ 1))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ -2).xroot(&five),
+            Ok(tifloat!(0x78886090522101 * 10 ^ 69))
+        );
+        assert_eq!(Float::ONE.xroot(&Float::PI), Ok(Float::PI));
+        assert_eq!(four.xroot(&Float::ZERO), Ok(Float::ZERO));
+        assert!(two.xroot(&Float::undefined()).unwrap().is_undefined());
+
+        assert_eq!(four.xroot(&-two), Err(FloatError::NonReal));
+        assert_eq!(
+            tifloat!(0x25000000000000 * 10 ^ 0).xroot(&-two),
+            Err(FloatError::NonReal)
+        );
+        assert_eq!(two.xroot(&-two), (-two).sqrt());
+        assert_eq!(Float::ZERO.xroot(&two), Err(FloatError::Domain));
+        assert_eq!((-two).xroot(&Float::ZERO), Err(FloatError::DivideByZero));
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ -3).xroot(&five),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            tifloat!(-0x10000000000000 * 10 ^ -3).xroot(&five),
+            Err(FloatError::Underflow)
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ -50).xroot(&five),
+            Err(FloatError::Overflow)
+        );
+    }
+
+    #[test]
+    fn hypot() {
+        let three = tifloat!(0x30000000000000 * 10 ^ 0);
+        let four = tifloat!(0x40000000000000 * 10 ^ 0);
+
+        assert_eq!(three.hypot(&-four), Ok(tifloat!(0x50000000000000 * 10 ^ 0)));
+        assert_eq!(
+            Float::ONE.hypot(&Float::ONE),
+            Ok(tifloat!(0x14142135623731 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ -99).hypot(&tifloat!(0x10000000000000 * 10 ^ -99)),
+            Ok(tifloat!(0x14142135623731 * 10 ^ -99))
+        );
+        assert_eq!(
+            tifloat!(0x60000000000000 * 10 ^ 60).hypot(&tifloat!(-0x80000000000000 * 10 ^ 60)),
+            Ok(tifloat!(0x10000000000000 * 10 ^ 61))
+        );
+        assert_eq!(
+            tifloat!(0x30000000000000 * 10 ^ -60).hypot(&tifloat!(0x40000000000000 * 10 ^ -60)),
+            Ok(tifloat!(0x50000000000000 * 10 ^ -60))
+        );
+        assert_eq!(Float::ZERO.hypot(&-three), Ok(three));
+        assert_eq!(
+            three.hypot(&tifloat!(0x10000000000000 * 10 ^ -20)),
+            Ok(three)
+        );
+        assert_eq!(Float::MAX.hypot(&Float::MAX), Err(FloatError::Overflow));
+        assert!(Float::undefined().hypot(&three).unwrap().is_undefined());
+    }
+}
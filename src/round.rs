@@ -0,0 +1,150 @@
+use crate::{Float, FloatError, RoundingMode};
+
+impl Float {
+    /// Rounds the value half-up to `digits` significant digits, as the OS does when it rounds a
+    /// result to the 10 digits it displays. Fails with [`FloatError::Overflow`] if rounding
+    /// carries past `9.9999999999999E99`.
+    ///
+    /// Zero and undefined Floats, and any Float when `digits` is 14 or more, are returned
+    /// unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `digits` is zero.
+    pub fn round_to_sig_digits(&self, digits: u8) -> Result<Float, FloatError> {
+        assert!(digits != 0, "cannot round to zero significant digits");
+
+        if digits >= 14 || self.is_zero() || self.is_undefined() {
+            return Ok(*self);
+        }
+
+        let rounded = RoundingMode::HalfUp.divide(
+            self.significand_dec() as u128,
+            10u128.pow(14 - digits as u32),
+        );
+
+        Float::from_scaled_integer(
+            self.is_negative(),
+            rounded,
+            self.exponent() as i64 - (digits as i64 - 1),
+        )
+    }
+
+    /// Rounds the value half-up to `places` digits after the decimal point, like
+    /// `round(value,#decimals)`, so `round(-2.345,2)` is `-2.35`. The one-argument `round(value)`
+    /// rounds to 10 significant digits instead, as [`Float::round_to_sig_digits`] does.
+    ///
+    /// Fails with [`FloatError::Domain`] for more than 9 places, as the calculator does.
+    #[doc(alias = "round(")]
+    pub fn round_to_decimal_places(&self, places: u8) -> Result<Float, FloatError> {
+        if places > 9 {
+            return Err(FloatError::Domain);
+        }
+
+        self.round_to_place(-(places as i64))
+    }
+
+    /// Rounds the value half-up to the decimal place `10 ^ place`. Values already on that place
+    /// are returned unchanged.
+    pub(crate) fn round_to_place(&self, place: i64) -> Result<Float, FloatError> {
+        let shift = place - (self.exponent() as i64 - 13);
+
+        if shift <= 0 || self.is_zero() || self.is_undefined() {
+            Ok(*self)
+        } else if shift > 14 {
+            Ok(Float::ZERO)
+        } else {
+            let rounded = RoundingMode::HalfUp
+                .divide(self.significand_dec() as u128, 10u128.pow(shift as u32));
+
+            Float::from_scaled_integer(self.is_negative(), rounded, place)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn round_to_sig_digits() {
+        assert_eq!(
+            Float::PI.round_to_sig_digits(10).unwrap(),
+            tifloat!(0x31415926540000 * 10 ^ 0)
+        );
+        assert_eq!(
+            (-Float::PI).round_to_sig_digits(1).unwrap(),
+            tifloat!(-0x30000000000000 * 10 ^ 0)
+        );
+        assert_eq!(
+            tifloat!(0x99999999999999 * 10 ^ -3)
+                .round_to_sig_digits(10)
+                .unwrap(),
+            tifloat!(0x10000000000000 * 10 ^ -2)
+        );
+        assert_eq!(
+            tifloat!(0x12345000000000 * 10 ^ 0)
+                .round_to_sig_digits(4)
+                .unwrap(),
+            tifloat!(0x12350000000000 * 10 ^ 0)
+        );
+        assert_eq!(Float::PI.round_to_sig_digits(14).unwrap(), Float::PI);
+        assert_eq!(Float::ZERO.round_to_sig_digits(3).unwrap(), Float::ZERO);
+
+        assert!(matches!(
+            Float::MAX.round_to_sig_digits(10),
+            Err(FloatError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn round_to_decimal_places() {
+        let value = tifloat!(-0x23450000000000 * 10 ^ 0);
+
+        assert_eq!(
+            value.round_to_decimal_places(2),
+            Ok(tifloat!(-0x23500000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            value.round_to_decimal_places(0),
+            Ok(tifloat!(-0x20000000000000 * 10 ^ 0))
+        );
+        assert_eq!(value.round_to_decimal_places(9), Ok(value));
+        assert_eq!(
+            Float::PI.round_to_decimal_places(4),
+            Ok(tifloat!(0x31416000000000 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(0x99999999999999 * 10 ^ -1).round_to_decimal_places(3),
+            Ok(Float::ONE)
+        );
+        assert_eq!(
+            tifloat!(0x50000000000000 * 10 ^ -10).round_to_decimal_places(9),
+            Ok(tifloat!(0x10000000000000 * 10 ^ -9))
+        );
+        assert_eq!(
+            tifloat!(0x49999999999999 * 10 ^ -10).round_to_decimal_places(9),
+            Ok(Float::ZERO)
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ -50).round_to_decimal_places(9),
+            Ok(Float::ZERO)
+        );
+        assert_eq!(Float::MAX.round_to_decimal_places(0), Ok(Float::MAX));
+        assert!(Float::undefined()
+            .round_to_decimal_places(1)
+            .unwrap()
+            .is_undefined());
+
+        assert_eq!(
+            Float::PI.round_to_decimal_places(10),
+            Err(FloatError::Domain)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot round to zero significant digits")]
+    fn round_to_zero_digits() {
+        let _ = Float::ONE.round_to_sig_digits(0);
+    }
+}
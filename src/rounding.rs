@@ -0,0 +1,94 @@
+use std::cmp::Ordering;
+
+use crate::uint::U256;
+
+/// How to round a result that has more than 14 significant digits.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest value, and away from zero on a tie, as the TI-OS does.
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, and to an even last digit on a tie.
+    HalfEven,
+    /// Discard the extra digits, rounding toward zero.
+    Truncate,
+}
+
+impl RoundingMode {
+    /// Decides whether to increment a truncated magnitude, given whether its last digit is odd and
+    /// how the discarded part compares to half of one unit in that digit.
+    pub(crate) fn rounds_up(self, odd: bool, discarded: Ordering) -> bool {
+        match self {
+            RoundingMode::HalfUp => discarded != Ordering::Less,
+            RoundingMode::HalfEven => {
+                discarded == Ordering::Greater || (discarded == Ordering::Equal && odd)
+            }
+            RoundingMode::Truncate => false,
+        }
+    }
+
+    /// Divides two integers, rounding the quotient to an integer.
+    pub(crate) fn divide(self, dividend: u128, divisor: u128) -> u128 {
+        self.round(dividend / divisor, dividend % divisor, divisor)
+    }
+
+    /// Divides a 256-bit integer by a `u128`, rounding the quotient to an integer, which must fit
+    /// in a `u128`.
+    pub(crate) fn divide_wide(self, dividend: U256, divisor: u128) -> u128 {
+        match dividend.to_u128() {
+            Some(dividend) => self.divide(dividend, divisor),
+            None => {
+                let (quotient, remainder) = dividend.div_rem(divisor);
+
+                self.round(quotient.to_u128().unwrap(), remainder, divisor)
+            }
+        }
+    }
+
+    /// Rounds `quotient + remainder / divisor` to an integer.
+    fn round(self, quotient: u128, remainder: u128, divisor: u128) -> u128 {
+        let rounded_up = self.rounds_up(quotient & 1 == 1, (2 * remainder).cmp(&divisor));
+        trace!(crate::trace::Step::Round {
+            mode: self,
+            kept: quotient,
+            remainder,
+            divisor,
+            rounded_up,
+        });
+
+        if rounded_up {
+            quotient + 1
+        } else {
+            quotient
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divide() {
+        assert_eq!(RoundingMode::HalfUp.divide(25, 10), 3);
+        assert_eq!(RoundingMode::HalfEven.divide(25, 10), 2);
+        assert_eq!(RoundingMode::HalfEven.divide(35, 10), 4);
+        assert_eq!(RoundingMode::HalfEven.divide(26, 10), 3);
+        assert_eq!(RoundingMode::Truncate.divide(29, 10), 2);
+        assert_eq!(RoundingMode::Truncate.divide(20, 10), 2);
+
+        let wide = U256::mul(10u128.pow(30), 10u128.pow(10) + 5);
+        assert_eq!(
+            RoundingMode::HalfUp.divide_wide(wide, 10u128.pow(30)),
+            10u128.pow(10) + 5
+        );
+        assert_eq!(
+            RoundingMode::HalfEven.divide_wide(wide, 10u128.pow(31)),
+            10u128.pow(9)
+        );
+        assert_eq!(
+            RoundingMode::HalfUp.divide_wide(wide, 10u128.pow(31)),
+            10u128.pow(9) + 1
+        );
+    }
+}
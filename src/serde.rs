@@ -0,0 +1,113 @@
+use std::fmt::Formatter;
+
+use serde::de::{Error, SeqAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Float;
+
+/// Human-readable formats (JSON, TOML, ...) store the shortest round-trip decimal string, or
+/// `undefined` for an undefined Float, and binary formats store the 9-byte representation.
+impl Serialize for Float {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_shortest_string())
+        } else {
+            serializer.serialize_bytes(&self.to_raw_bytes())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Float {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FloatVisitor)
+        } else {
+            deserializer.deserialize_bytes(FloatVisitor)
+        }
+    }
+}
+
+struct FloatVisitor;
+
+impl<'de> Visitor<'de> for FloatVisitor {
+    type Value = Float;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a decimal string or the 9-byte representation of a TI float")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        if v.len() != 9 {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        Float::from_raw_slice(v).map_err(|_| E::invalid_value(Unexpected::Bytes(v), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0u8; 9];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+        }
+
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(A::Error::invalid_length(10, &self));
+        }
+
+        Float::from_raw_bytes(bytes)
+            .map_err(|_| A::Error::invalid_value(Unexpected::Bytes(&bytes), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token};
+
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn readable() {
+        let cases = [
+            (tifloat!(-0x55000000000000 * 10 ^ 5), "-5.5E5"),
+            (tifloat!(0x12345000000000 * 10 ^ -7), "1.2345E-7"),
+            (tifloat!(0x00000000000000 * 10 ^ 0), "0"),
+            (Float::undefined(), "undefined"),
+        ];
+
+        for (float, string) in cases {
+            assert_tokens(&float.readable(), &[Token::Str(string)]);
+        }
+
+        assert_de_tokens_error::<Readable<Float>>(
+            &[Token::Str("1.2.3")],
+            "invalid value: string \"1.2.3\", expected a decimal string or the 9-byte \
+             representation of a TI float",
+        );
+    }
+
+    #[test]
+    fn compact() {
+        let float = tifloat!(-0x55000000000000 * 10 ^ 5);
+
+        assert_tokens(
+            &float.compact(),
+            &[Token::Bytes(&[
+                0x80, 0x85, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ])],
+        );
+
+        assert_de_tokens_error::<Compact<Float>>(
+            &[Token::Bytes(&[0x80, 0x85, 0x55])],
+            "invalid length 3, expected a decimal string or the 9-byte representation of a TI \
+             float",
+        );
+    }
+}
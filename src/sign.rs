@@ -0,0 +1,70 @@
+use crate::{Flags, Float};
+
+impl Float {
+    /// The absolute value of this Float. Only the sign bit is cleared; other flags are kept.
+    pub fn abs(&self) -> Float {
+        self.with_flags(self.flags() - Flags::NEGATIVE)
+    }
+
+    /// This Float with the sign of `sign`. Other flags are kept, and zero stays unsigned.
+    pub fn copysign(&self, sign: &Float) -> Float {
+        if self.is_zero() {
+            return *self;
+        }
+
+        let mut flags = self.flags();
+        flags.set(Flags::NEGATIVE, sign.is_negative());
+
+        self.with_flags(flags)
+    }
+
+    /// `-1`, `0`, or `1`, according to the sign of this Float. Undefined Floats are returned
+    /// unchanged.
+    pub fn signum(&self) -> Float {
+        if self.is_undefined() || self.is_zero() {
+            *self
+        } else if self.is_negative() {
+            Float::NEG_ONE
+        } else {
+            Float::ONE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Flags, Float};
+
+    #[test]
+    fn abs() {
+        let float = tifloat!(-0x12500000000000 * 10 ^ 3).with_flags(Flags::NEGATIVE | Flags::IDK);
+
+        assert_eq!(float.abs(), tifloat!(0x12500000000000 * 10 ^ 3));
+        assert_eq!(float.abs().flags(), Flags::IDK);
+        assert_eq!(Float::ONE.abs(), Float::ONE);
+    }
+
+    #[test]
+    fn copysign() {
+        let float = tifloat!(0x12500000000000 * 10 ^ 3).with_flags(Flags::COMPLEX_HALF);
+
+        assert_eq!(
+            float.copysign(&Float::NEG_ONE).flags(),
+            Flags::NEGATIVE | Flags::COMPLEX_HALF
+        );
+        assert_eq!(float.copysign(&Float::ONE), float);
+        assert_eq!((-float).copysign(&Float::ZERO), float);
+        assert!(!Float::ZERO.copysign(&Float::NEG_ONE).is_negative());
+    }
+
+    #[test]
+    fn signum() {
+        assert_eq!(
+            tifloat!(-0x12500000000000 * 10 ^ 3).signum(),
+            Float::NEG_ONE
+        );
+        assert_eq!(Float::MIN_POSITIVE.signum(), Float::ONE);
+        assert_eq!(Float::ZERO.signum(), Float::ZERO);
+        assert!(Float::undefined().signum().is_undefined());
+    }
+}
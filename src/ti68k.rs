@@ -0,0 +1,176 @@
+//! The BCD float format of the 68k calculators (TI-89, TI-92 Plus, and Voyage 200).
+//!
+//! These floats are 10 bytes: a big-endian word holding the sign in its high bit and the exponent
+//! (biased by [`EXPONENT_BIAS`]) in the rest, followed by a 16-digit packed-BCD mantissa with the
+//! decimal point after the first digit. Exponents range from -999 to 999.
+
+use crate::float::ParseFloatError;
+use crate::mantissa::Mantissa;
+use crate::{Float, FloatError};
+
+/// The value of the exponent field for an exponent of zero.
+pub const EXPONENT_BIAS: u16 = 0x4000;
+
+/// The largest magnitude of an exponent.
+pub const MAX_EXPONENT: i16 = 999;
+
+/// The number of decimal digits in the mantissa.
+pub const DIGITS: usize = 16;
+
+const SIGN_BIT: u16 = 0x8000;
+
+/// A float in the 68k calculators' format.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Float68k {
+    negative: bool,
+    exponent: i16,
+    mantissa: Mantissa<DIGITS>,
+}
+
+impl Float68k {
+    /// Given the byte representation (exponent word first), produces a Float68k.
+    pub fn from_raw_bytes(bytes: [u8; 10]) -> Result<Self, ParseFloatError> {
+        let word = u16::from_be_bytes([bytes[0], bytes[1]]);
+        let exponent = (word & !SIGN_BIT) as i16 - EXPONENT_BIAS as i16;
+
+        if !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+            return Err(ParseFloatError::InvalidExponent);
+        }
+
+        let mantissa = Mantissa::from(u64::from_be_bytes(bytes[2..].try_into().unwrap()))
+            .ok_or(ParseFloatError::InvalidMantissa)?;
+
+        Ok(Float68k {
+            negative: word & SIGN_BIT != 0,
+            exponent,
+            mantissa,
+        })
+    }
+
+    /// Given a Float68k, produces byte representation (exponent word first).
+    pub fn to_raw_bytes(&self) -> [u8; 10] {
+        let mut word = (self.exponent + EXPONENT_BIAS as i16) as u16;
+        if self.negative {
+            word |= SIGN_BIT;
+        }
+
+        let mut result = [0u8; 10];
+        result[..2].copy_from_slice(&word.to_be_bytes());
+        result[2..].copy_from_slice(&self.mantissa.bits().to_be_bytes());
+
+        result
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn exponent(&self) -> i16 {
+        self.exponent
+    }
+
+    /// All 16 digits of the mantissa, in order from greatest to least place-value.
+    pub fn digits(&self) -> Vec<u8> {
+        self.mantissa.digits()
+    }
+}
+
+/// Every Float is exactly representable, with two trailing zero digits.
+impl From<Float> for Float68k {
+    fn from(value: Float) -> Self {
+        let mantissa = Mantissa::from_unchecked(
+            value
+                .digits()
+                .iter()
+                .fold(0u64, |acc, &digit| acc << 4 | digit as u64)
+                << 8,
+        );
+
+        Float68k {
+            negative: value.is_negative(),
+            exponent: value.exponent() as i16,
+            mantissa,
+        }
+    }
+}
+
+impl TryFrom<Float68k> for Float {
+    type Error = FloatError;
+
+    /// Rounds the mantissa half-up to 14 digits. Fails with [`FloatError::Overflow`] or
+    /// [`FloatError::Underflow`] if the rounded exponent is outside the range of a Float.
+    fn try_from(value: Float68k) -> Result<Self, Self::Error> {
+        Float::from_decimal_digits(value.negative, &value.digits(), value.exponent as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn raw_bytes() {
+        let float = Float68k::from(tifloat!(-0x55000000000000 * 10 ^ 5));
+
+        let repr = [0xC0, 0x05, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+        assert_eq!(float.to_raw_bytes(), repr);
+        assert_eq!(Float68k::from_raw_bytes(repr), Ok(float));
+
+        let repr = [0x3C, 0x19, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34, 0x56];
+        assert_eq!(Float68k::from_raw_bytes(repr).unwrap().exponent(), -999);
+    }
+
+    #[test]
+    fn raw_bytes_errors() {
+        let cases = [
+            (
+                [0x43, 0xE8, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                ParseFloatError::InvalidExponent,
+            ),
+            (
+                [0x40, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0A],
+                ParseFloatError::InvalidMantissa,
+            ),
+        ];
+
+        for (repr, expected) in cases {
+            assert_eq!(Float68k::from_raw_bytes(repr), Err(expected), "{repr:X?}");
+        }
+    }
+
+    #[test]
+    fn to_float() {
+        let cases = [
+            (
+                [0x40, 0x00, 0x31, 0x41, 0x59, 0x26, 0x53, 0x58, 0x97, 0x93],
+                tifloat!(0x31415926535898 * 10 ^ 0),
+            ),
+            (
+                [0xBF, 0xFF, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x50],
+                tifloat!(-0x10000000000000 * 10 ^ 0),
+            ),
+            (
+                [0x40, 0x63, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                tifloat!(0x12000000000000 * 10 ^ 99),
+            ),
+        ];
+
+        for (repr, expected) in cases {
+            let float = Float68k::from_raw_bytes(repr).unwrap();
+
+            assert_eq!(Float::try_from(float).ok().unwrap(), expected, "{repr:X?}");
+        }
+
+        let large = Float68k::from_raw_bytes([0x40, 0x64, 0x10, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        assert!(matches!(Float::try_from(large), Err(FloatError::Overflow)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let float = tifloat!(-0x12345678901234 * 10 ^ -99);
+
+        assert_eq!(Float::try_from(Float68k::from(float)).ok().unwrap(), float);
+    }
+}
@@ -0,0 +1,166 @@
+//! The float format of the TI-85 and TI-86, which extends the exponent range to ±999.
+//!
+//! These floats are 10 bytes: a flags byte with the sign in its high bit, a little-endian exponent
+//! word biased by [`EXPONENT_BIAS`], and the same 14-digit packed-BCD mantissa as a [`Float`]. The
+//! other flag bits have different meanings than on the TI-83 Plus, so they are kept as-is but not
+//! carried over by conversions.
+
+use crate::float::ParseFloatError;
+use crate::mantissa::Mantissa;
+use crate::{Float, FloatError};
+
+/// The value of the exponent word for an exponent of zero.
+pub const EXPONENT_BIAS: u16 = 0xFC00;
+
+/// The largest magnitude of an exponent.
+pub const MAX_EXPONENT: i16 = 999;
+
+const SIGN_BIT: u8 = 0x80;
+
+/// A float in the TI-85/86 format.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Float86 {
+    flags: u8,
+    exponent: i16,
+    mantissa: Mantissa,
+}
+
+impl Float86 {
+    /// Given the byte representation (flags at index zero), produces a Float86.
+    pub fn from_raw_bytes(bytes: [u8; 10]) -> Result<Self, ParseFloatError> {
+        let exponent = u16::from_le_bytes([bytes[1], bytes[2]]).wrapping_sub(EXPONENT_BIAS) as i16;
+        if !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+            return Err(ParseFloatError::InvalidExponent);
+        }
+
+        let mut arr = [0u8; 8];
+        arr[1..].copy_from_slice(&bytes[3..]);
+        let mantissa =
+            Mantissa::from(u64::from_be_bytes(arr)).ok_or(ParseFloatError::InvalidMantissa)?;
+
+        Ok(Float86 {
+            flags: bytes[0],
+            exponent,
+            mantissa,
+        })
+    }
+
+    /// Given a Float86, produces byte representation (flags at index zero).
+    pub fn to_raw_bytes(&self) -> [u8; 10] {
+        let mut result = [0u8; 10];
+
+        result[0] = self.flags;
+        result[1..3].copy_from_slice(
+            &(self.exponent as u16)
+                .wrapping_add(EXPONENT_BIAS)
+                .to_le_bytes(),
+        );
+        result[3..].copy_from_slice(&self.mantissa.bits().to_be_bytes()[1..]);
+
+        result
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.flags & SIGN_BIT != 0
+    }
+
+    pub fn exponent(&self) -> i16 {
+        self.exponent
+    }
+
+    /// All 14 digits of the mantissa, in order from greatest to least place-value.
+    pub fn digits(&self) -> Vec<u8> {
+        self.mantissa.digits()
+    }
+}
+
+/// Every Float is exactly representable.
+impl From<Float> for Float86 {
+    fn from(value: Float) -> Self {
+        let mantissa = Mantissa::from_unchecked(
+            value
+                .digits()
+                .iter()
+                .fold(0u64, |acc, &digit| acc << 4 | digit as u64),
+        );
+
+        Float86 {
+            flags: if value.is_negative() { SIGN_BIT } else { 0 },
+            exponent: value.exponent() as i16,
+            mantissa,
+        }
+    }
+}
+
+impl TryFrom<Float86> for Float {
+    type Error = FloatError;
+
+    /// Fails with [`FloatError::Overflow`] or [`FloatError::Underflow`] if the exponent is outside
+    /// the range of a Float.
+    fn try_from(value: Float86) -> Result<Self, Self::Error> {
+        if value.exponent > 99 {
+            return Err(FloatError::Overflow);
+        } else if value.exponent < -99 {
+            return Err(FloatError::Underflow);
+        }
+
+        Float::new(
+            value.is_negative(),
+            value.exponent as i8,
+            value.mantissa.bits(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn raw_bytes() {
+        let float = Float86::from(tifloat!(-0x55000000000000 * 10 ^ 5));
+
+        let repr = [0x80, 0x05, 0xFC, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+        assert_eq!(float.to_raw_bytes(), repr);
+        assert_eq!(Float86::from_raw_bytes(repr), Ok(float));
+
+        let repr = [0x00, 0xE7, 0xFF, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34];
+        assert_eq!(Float86::from_raw_bytes(repr).unwrap().exponent(), 999);
+
+        let repr = [0x00, 0x19, 0xF8, 0x12, 0x34, 0x56, 0x78, 0x90, 0x12, 0x34];
+        assert_eq!(Float86::from_raw_bytes(repr).unwrap().exponent(), -999);
+    }
+
+    #[test]
+    fn raw_bytes_errors() {
+        let cases = [
+            (
+                [0x00, 0xE8, 0xFF, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                ParseFloatError::InvalidExponent,
+            ),
+            (
+                [0x00, 0x00, 0xFC, 0x1A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                ParseFloatError::InvalidMantissa,
+            ),
+        ];
+
+        for (repr, expected) in cases {
+            assert_eq!(Float86::from_raw_bytes(repr), Err(expected), "{repr:X?}");
+        }
+    }
+
+    #[test]
+    fn to_float() {
+        for float in [
+            tifloat!(-0x12345678901234 * 10 ^ -99),
+            tifloat!(0x99999999999999 * 10 ^ 99),
+        ] {
+            assert_eq!(Float::try_from(Float86::from(float)).ok().unwrap(), float);
+        }
+
+        let large = Float86::from_raw_bytes([0x00, 0x64, 0xFC, 0x10, 0, 0, 0, 0, 0, 0]).unwrap();
+        assert!(matches!(Float::try_from(large), Err(FloatError::Overflow)));
+    }
+}
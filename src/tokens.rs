@@ -0,0 +1,237 @@
+//! Conversion between Floats and the TI-BASIC tokens which spell out numeric literals.
+//!
+//! Tokens are given as their single-byte TI-83 Plus values, so this module can sit beneath any
+//! tokenizer: the digits `0` through `9` are `0x30` through `0x39`, alongside [`T_DEC_PT`],
+//! [`T_EE`], and [`T_CHS`].
+
+use crate::float::ParseFloatError;
+use crate::Float;
+
+/// The decimal point token (`.`).
+pub const T_DEC_PT: u8 = 0x3A;
+
+/// The exponent token (`ᴇ`).
+pub const T_EE: u8 = 0x3B;
+
+/// The negation token (`⁻`).
+pub const T_CHS: u8 = 0xB0;
+
+/// Parses the numeric literal at the start of `tokens`, returning it along with the number of
+/// tokens it spans.
+///
+/// Like the OS parser, a literal may begin with `⁻`, the exponent may be negated, and an
+/// exponent with no mantissa before it (`ᴇ3`) has an implicit mantissa of `1`. Parsing stops at
+/// the first token which can't be part of a numeric literal.
+pub fn parse_tokens(tokens: &[u8]) -> Result<(Float, usize), ParseFloatError> {
+    let mut literal = String::new();
+    let mut length = 0;
+
+    for (index, &token) in tokens.iter().enumerate() {
+        let character = match token {
+            b'0'..=b'9' => token as char,
+            T_DEC_PT => '.',
+            T_EE => 'E',
+            // negation is only part of the literal before the mantissa or the exponent
+            T_CHS if index == 0 || tokens[index - 1] == T_EE => '-',
+            _ => break,
+        };
+
+        literal.push(character);
+        length += 1;
+    }
+
+    let mantissa_length = literal.find('E').unwrap_or(literal.len());
+    if literal[..mantissa_length]
+        .trim_start_matches('-')
+        .is_empty()
+        && mantissa_length != literal.len()
+    {
+        literal.insert(mantissa_length, '1');
+    }
+
+    Ok((literal.parse()?, length))
+}
+
+impl Float {
+    /// Produces the shortest sequence of tokens which evaluates to exactly this Float.
+    ///
+    /// Ties are broken in favor of positional notation, so `1200` is preferred to `12ᴇ2`. A
+    /// mantissa of `1` is omitted before the exponent (`ᴇ10`), as the OS allows.
+    pub fn to_tokens(&self) -> Vec<u8> {
+        if self.is_zero() {
+            return vec![b'0'];
+        }
+
+        let digits = self
+            .significant_figures()
+            .iter()
+            .map(|digit| b'0' + digit)
+            .collect::<Vec<_>>();
+        let exponent = self.exponent() as i32;
+
+        let mut shortest = positional(&digits, exponent);
+        for point in 0..=digits.len() {
+            let candidate = scientific(&digits, exponent, point);
+
+            if candidate.len() < shortest.len() {
+                shortest = candidate;
+            }
+        }
+
+        if self.is_negative() {
+            shortest.insert(0, T_CHS);
+        }
+
+        shortest
+    }
+}
+
+/// The literal with no exponent, omitting the zero before the decimal point.
+fn positional(digits: &[u8], exponent: i32) -> Vec<u8> {
+    let mut tokens = Vec::new();
+
+    if exponent < 0 {
+        tokens.push(T_DEC_PT);
+        tokens.resize(exponent.unsigned_abs() as usize, b'0');
+        tokens.extend(digits);
+    } else {
+        let integer_digits = exponent as usize + 1;
+
+        tokens.extend(digits.iter().take(integer_digits));
+        tokens.resize(integer_digits, b'0');
+
+        if digits.len() > integer_digits {
+            tokens.push(T_DEC_PT);
+            tokens.extend(&digits[integer_digits..]);
+        }
+    }
+
+    tokens
+}
+
+/// The literal with an exponent, where `point` digits come before the decimal point.
+fn scientific(digits: &[u8], exponent: i32, point: usize) -> Vec<u8> {
+    let mut tokens = Vec::new();
+
+    if digits != b"1" || point != 1 {
+        tokens.extend(&digits[..point]);
+
+        if point < digits.len() {
+            tokens.push(T_DEC_PT);
+            tokens.extend(&digits[point..]);
+        }
+    }
+
+    let exponent = exponent - (point as i32 - 1);
+
+    tokens.push(T_EE);
+    if exponent < 0 {
+        tokens.push(T_CHS);
+    }
+    tokens.extend(exponent.unsigned_abs().to_string().bytes());
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn parse() {
+        let cases: [(&[u8], Float, usize); 6] = [
+            (b"12345", tifloat!(0x12345000000000 * 10 ^ 4), 5),
+            (
+                &[T_CHS, b'1', b'2', T_DEC_PT, b'5'],
+                tifloat!(-0x12500000000000 * 10 ^ 1),
+                5,
+            ),
+            (
+                &[T_DEC_PT, b'0', b'0', b'1', 0x70, b'2'],
+                tifloat!(0x10000000000000 * 10 ^ -3),
+                4,
+            ),
+            (
+                &[b'1', b'5', T_EE, T_CHS, b'3'],
+                tifloat!(0x15000000000000 * 10 ^ -2),
+                5,
+            ),
+            (&[T_EE, b'3'], tifloat!(0x10000000000000 * 10 ^ 3), 2),
+            (
+                &[T_CHS, T_EE, b'2', T_CHS],
+                tifloat!(-0x10000000000000 * 10 ^ 2),
+                3,
+            ),
+        ];
+
+        for (tokens, expected, length) in cases {
+            assert_eq!(parse_tokens(tokens), Ok((expected, length)), "{tokens:X?}");
+        }
+    }
+
+    #[test]
+    fn to_tokens() {
+        let cases: [(Float, &[u8]); 9] = [
+            (tifloat!(0x50000000000000 * 10 ^ -1), &[T_DEC_PT, b'5']),
+            (tifloat!(0x10000000000000 * 10 ^ 10), &[T_EE, b'1', b'0']),
+            (tifloat!(0x12000000000000 * 10 ^ 3), b"1200"),
+            (
+                tifloat!(0x12000000000000 * 10 ^ 4),
+                &[b'1', b'2', T_EE, b'3'],
+            ),
+            (
+                tifloat!(-0x10000000000000 * 10 ^ -5),
+                &[T_CHS, T_EE, T_CHS, b'5'],
+            ),
+            (
+                tifloat!(0x12300000000000 * 10 ^ -20),
+                &[b'1', b'2', b'3', T_EE, T_CHS, b'2', b'2'],
+            ),
+            (
+                tifloat!(0x12500000000000 * 10 ^ -2),
+                &[T_DEC_PT, b'0', b'1', b'2', b'5'],
+            ),
+            (
+                tifloat!(0x31415926535898 * 10 ^ 0),
+                &[
+                    b'3', T_DEC_PT, b'1', b'4', b'1', b'5', b'9', b'2', b'6', b'5', b'3', b'5',
+                    b'8', b'9', b'8',
+                ],
+            ),
+            (tifloat!(0x00000000000000 * 10 ^ 0), b"0"),
+        ];
+
+        for (float, expected) in cases {
+            let tokens = float.to_tokens();
+
+            assert_eq!(tokens, expected, "{float:?}");
+            assert_eq!(
+                parse_tokens(&tokens),
+                Ok((float, tokens.len())),
+                "{float:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        let cases: [(&[u8], ParseFloatError); 5] = [
+            (&[], ParseFloatError::InvalidString),
+            (&[0x70, b'1'], ParseFloatError::InvalidString),
+            (
+                &[b'1', T_DEC_PT, b'2', T_DEC_PT],
+                ParseFloatError::InvalidString,
+            ),
+            (&[b'1', T_EE], ParseFloatError::InvalidString),
+            (
+                &[b'1', T_EE, b'1', b'0', b'0'],
+                ParseFloatError::InvalidExponent,
+            ),
+        ];
+
+        for (tokens, expected) in cases {
+            assert_eq!(parse_tokens(tokens), Err(expected), "{tokens:X?}");
+        }
+    }
+}
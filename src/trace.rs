@@ -0,0 +1,189 @@
+//! Records the intermediate steps of arithmetic, for teaching or checking how a calculator does
+//! BCD math by hand.
+//!
+//! Wrap any computation in [`capture`] to get the [`Step`]s it took, in order.
+//!
+//! ```
+//! use tifloats::trace::{capture, Step};
+//! use tifloats::{tifloat, Float};
+//!
+//! let (sum, steps) = capture(|| Float::PI + tifloat!(0x10000000000000 * 10 ^ -14));
+//!
+//! assert_eq!(sum.unwrap(), Float::PI);
+//! assert!(matches!(steps[1], Step::Align { distance: 14, .. }));
+//! ```
+
+use std::cell::RefCell;
+
+use crate::{Float, RoundingMode};
+
+/// One step of an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    /// An operation began. Operations built from others, like subtraction, record the operations
+    /// they are built from instead.
+    Operation {
+        name: &'static str,
+        lhs: Float,
+        rhs: Float,
+    },
+    /// The operand with the smaller exponent, with this integer significand, was shifted right
+    /// `distance` digits to line its digits up with the other operand's, keeping three guard
+    /// digits and a sticky digit past the other operand's last digit.
+    Align { distance: u8, significand: u128 },
+    /// The aligned significands were added or subtracted, giving this integer significand with
+    /// the guard and sticky digits. `carry` is set if a sum carried into a new digit, or if a
+    /// difference borrowed, which flips its sign.
+    Combine { significand: u128, carry: bool },
+    /// The result was shifted `shift` digits left, or right if `shift` is negative, so that its
+    /// first digit is nonzero, leaving it with this exponent.
+    Normalize { shift: i8, exponent: i16 },
+    /// The exact value `kept + remainder / divisor` was rounded to `kept`, or to `kept + 1` if
+    /// `rounded_up` is set.
+    Round {
+        mode: RoundingMode,
+        kept: u128,
+        remainder: u128,
+        divisor: u128,
+        rounded_up: bool,
+    },
+    /// An operation on [`GuardedFloat`](crate::GuardedFloat)s produced this 18-digit significand
+    /// and exponent, before any guard digits were rounded away.
+    Guard { significand: u64, exponent: i16 },
+}
+
+thread_local! {
+    static STEPS: RefCell<Option<Vec<Step>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, returning its result and the steps of every operation it performed on this thread.
+/// Captures can be nested; an inner capture takes the steps recorded while it runs.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Step>) {
+    let outer = STEPS.with(|steps| steps.replace(Some(Vec::new())));
+    let result = f();
+    let steps = STEPS.with(|steps| steps.replace(outer)).unwrap_or_default();
+
+    (result, steps)
+}
+
+/// Appends a step if a capture is in progress. The step is only built if it will be kept.
+pub(crate) fn record(step: impl FnOnce() -> Step) {
+    STEPS.with(|steps| {
+        if let Some(steps) = steps.borrow_mut().as_mut() {
+            steps.push(step());
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tifloat, GuardedFloat};
+
+    #[test]
+    fn add() {
+        let a = tifloat!(0x99999999999999 * 10 ^ 0);
+        let b = tifloat!(0x15000000000000 * 10 ^ -1);
+        let (sum, steps) = capture(|| a + b);
+
+        assert_eq!(sum.unwrap(), tifloat!(0x10150000000000 * 10 ^ 1));
+        assert_eq!(
+            steps,
+            [
+                Step::Operation {
+                    name: "add",
+                    lhs: a,
+                    rhs: b
+                },
+                Step::Align {
+                    distance: 1,
+                    significand: 15000000000000
+                },
+                Step::Combine {
+                    significand: 1014999999999990000,
+                    carry: true
+                },
+                Step::Round {
+                    mode: RoundingMode::HalfUp,
+                    kept: 10149999999999,
+                    remainder: 90000,
+                    divisor: 100000,
+                    rounded_up: true
+                },
+                Step::Normalize {
+                    shift: -1,
+                    exponent: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn subtract() {
+        let a = tifloat!(0x10000000000000 * 10 ^ 0);
+        let b = tifloat!(-0x99999999999990 * 10 ^ -1);
+        let (_, steps) = capture(|| a + b);
+
+        assert_eq!(
+            steps[2],
+            Step::Combine {
+                significand: 10000,
+                carry: false
+            }
+        );
+        assert_eq!(
+            steps[3],
+            Step::Normalize {
+                shift: 13,
+                exponent: -13
+            }
+        );
+    }
+
+    #[test]
+    fn multiply_and_guard() {
+        let (_, steps) =
+            capture(|| (GuardedFloat::from(Float::ONE) / GuardedFloat::from(Float::PI)).unwrap());
+        assert!(matches!(
+            steps.last(),
+            Some(Step::Guard {
+                significand: 318309886183789986,
+                exponent: -1
+            })
+        ));
+
+        let (_, steps) = capture(|| Float::PI * Float::PI);
+        assert!(matches!(
+            steps[1],
+            Step::Round {
+                kept: 98696044010894,
+                rounded_up: false,
+                ..
+            }
+        ));
+        assert_eq!(
+            steps[2],
+            Step::Normalize {
+                shift: 0,
+                exponent: 0
+            }
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let (inner, outer) = capture(|| {
+            let _ = Float::ONE + Float::ONE;
+            capture(|| Float::ONE * Float::ONE).1
+        });
+
+        assert_eq!(outer.len(), 5);
+        assert!(matches!(
+            inner[0],
+            Step::Operation {
+                name: "multiply",
+                ..
+            }
+        ));
+    }
+}
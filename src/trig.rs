@@ -0,0 +1,757 @@
+use crate::fixed::{ratio_to_float, Fixed, PLACES};
+use crate::uint::U256;
+use crate::{tifloat, AngleMode, Float, FloatError};
+
+/// The first 160 decimal places of `2 / π`, enough to reduce the largest Float in radians.
+const TWO_OVER_PI: &[u8] = concat!(
+    "6366197723675813430755350534900574481378",
+    "3858296182579499066937623558719053690614",
+    "0360455211065012343824291370907031832147",
+    "5716473844583146115118696429267993569169",
+)
+.as_bytes();
+
+/// Places of `2 / π` used past the last digit of an angle in radians.
+const WINDOW: i64 = 60;
+
+/// `π / 2`, scaled by `10 ^ 38`.
+const PI_OVER_2: u128 = 157_079_632_679_489_661_923_132_169_163_975_144_210;
+
+/// `π / 180`, scaled by `10 ^ 40`.
+const PI_OVER_180: u128 = 174_532_925_199_432_957_692_369_076_848_861_271_344;
+
+/// `atan(k / 8)` for `k` in `1..=8`, scaled by `10 ^ 38`.
+const ARCTANGENTS: [u128; 8] = [
+    12_435_499_454_676_143_503_135_484_916_387_102_557,
+    24_497_866_312_686_415_417_208_248_121_127_581_091,
+    35_877_067_027_057_222_039_592_006_392_646_049_978,
+    46_364_760_900_080_611_621_425_623_146_121_440_203,
+    55_859_931_534_356_243_597_150_821_640_166_127_035,
+    64_350_110_879_328_438_680_280_922_871_732_263_804,
+    71_882_999_962_162_450_541_701_415_152_590_465_395,
+    78_539_816_339_744_830_961_566_084_581_987_572_105,
+];
+
+/// `180 / π`, scaled by `10 ^ 36`.
+const DEGREES_PER_RADIAN: u128 = 57_295_779_513_082_320_876_798_154_814_105_170_332;
+
+/// `200 / π`, scaled by `10 ^ 36`.
+const GRADS_PER_RADIAN: u128 = 63_661_977_236_758_134_307_553_505_349_005_744_814;
+
+/// An angle reduced to `quadrant * π/2 + r`, with `|r| <= π/4`, except that radian angles below 7
+/// in magnitude are kept whole as `r`, where the series still converge.
+struct Reduced {
+    quadrant: u8,
+    negative: bool,
+    /// `|r| * 10 ^ places`, which is zero or has at least 37 digits.
+    magnitude: u128,
+    places: i64,
+}
+
+impl Reduced {
+    fn new(quadrant: u8, negative: bool, mut magnitude: u128, mut places: i64) -> Self {
+        // extra digits keep the sine's relative precision when it is divided by the cosine
+        while magnitude != 0 && magnitude < 10u128.pow(37) {
+            magnitude *= 10;
+            places += 1;
+        }
+
+        Reduced {
+            quadrant: quadrant % 4,
+            negative,
+            magnitude,
+            places,
+        }
+    }
+
+    /// Reduces an angle in degrees or grads exactly, given the size of a right angle and the
+    /// size of one unit in radians, scaled by `10 ^ 40`.
+    fn exact(angle: &Float, right_angle: Float, radians_per_unit: u128) -> Self {
+        let full_turn = (right_angle * tifloat!(0x40000000000000 * 10 ^ 0)).unwrap();
+
+        // the remainder is on the same grid as the angle and no larger, so it is exact
+        let rest = angle.rem_euclid(&full_turn).unwrap();
+        let (mut units, mut places) = (rest.significand_dec() as u128, 13 - rest.exponent() as i64);
+
+        // a remainder of at least 10 might be past half a right angle, and its last place is no
+        // smaller than 10 ^ -12, so it can be measured from the nearest right angle in integers
+        let (mut quadrant, mut negative) = (0, false);
+        if rest.exponent() >= 1 {
+            units *= 10u128.pow(rest.exponent() as u32 - 1);
+            places = 12;
+
+            let right = right_angle.significand_dec() as u128
+                * 10u128.pow(right_angle.exponent() as u32 - 1);
+            quadrant = (units / right) as u8;
+            units %= right;
+
+            if 2 * units > right {
+                units = right - units;
+                quadrant += 1;
+                negative = true;
+            }
+        }
+
+        // r = units * 10 ^ -places * radians_per_unit * 10 ^ -40, keeping 37 or 38 digits
+        let mut product = U256::mul(units, radians_per_unit);
+        let mut places = places + 40;
+        while product >= U256::new(10u128.pow(38)) {
+            product = product.div_rem(10).0;
+            places -= 1;
+        }
+
+        Reduced::new(quadrant, negative, product.to_u128().unwrap(), places)
+    }
+
+    /// Reduces an angle in radians, using enough places of `2 / π` past the angle's last digit
+    /// that even an angle very close to a multiple of `π / 2` keeps 30 digits.
+    fn radians(angle: &Float) -> Self {
+        let significand = angle.significand_dec() as u128;
+        let exponent = angle.exponent() as i64 - 13;
+
+        // small angles are exact as they are, and the series converge for them unreduced
+        if angle.abs() < tifloat!(0x70000000000000 * 10 ^ -1) {
+            return Reduced::new(0, false, significand, -exponent);
+        }
+
+        // the places of 2 / π that matter for angle * 2 / π mod 4: the two before the angle's
+        // last digit and the window after it
+        let digits = (exponent - 1..=exponent + WINDOW).fold(U256::new(0), |digits, place| {
+            let digit = match usize::try_from(place - 1) {
+                Ok(index) => (TWO_OVER_PI[index] - b'0') as u128,
+                Err(_) => 0,
+            };
+
+            digits
+                .checked_mul(10)
+                .unwrap()
+                .checked_add(U256::new(digit))
+                .unwrap()
+        });
+
+        // angle * 2 / π = quadrants + high * 10 ^ -30 + low * 10 ^ -60
+        let product = digits.checked_mul(significand).unwrap();
+        let (product, mut low) = product.div_rem(10u128.pow(30));
+        let (quadrants, mut high) = product.div_rem(10u128.pow(30));
+        let mut quadrant = (quadrants.div_rem(4).1) as u8;
+
+        // past halfway to the next quadrant, measure back from it instead
+        let negative = high >= 5 * 10u128.pow(29);
+        if negative {
+            quadrant += 1;
+            if low == 0 {
+                high = 10u128.pow(30) - high;
+            } else {
+                high = 10u128.pow(30) - 1 - high;
+                low = 10u128.pow(30) - low;
+            }
+        }
+
+        // keep the leading 30 digits of the fraction, then multiply by π / 2
+        let mut fraction = U256::mul(high, 10u128.pow(30))
+            .checked_add(U256::new(low))
+            .unwrap();
+        let mut dropped = 0;
+        while fraction >= U256::new(10u128.pow(30)) {
+            fraction = fraction.div_rem(10).0;
+            dropped += 1;
+        }
+
+        let fraction = fraction.to_u128().unwrap();
+        let magnitude = U256::mul(fraction, PI_OVER_2)
+            .div_rem(10u128.pow(30))
+            .0
+            .to_u128()
+            .unwrap();
+
+        Reduced::new(quadrant, negative, magnitude, 68 - dropped)
+    }
+
+    /// `sin(r) / r` and `cos(r)`, by their Taylor series.
+    fn series(&self) -> (Fixed, Fixed) {
+        let square = -to_fixed(U256::mul(self.magnitude, self.magnitude), 2 * self.places);
+
+        let mut sine = Fixed::ONE;
+        let mut cosine = Fixed::ONE;
+        let mut sine_term = Fixed::ONE;
+        let mut cosine_term = Fixed::ONE;
+        for k in 1.. {
+            sine_term = Fixed(sine_term.mul(square).unwrap().0 / (2 * k * (2 * k + 1)));
+            cosine_term = Fixed(cosine_term.mul(square).unwrap().0 / ((2 * k - 1) * (2 * k)));
+            if sine_term == Fixed::ZERO && cosine_term == Fixed::ZERO {
+                break;
+            }
+
+            sine = sine + sine_term;
+            cosine = cosine + cosine_term;
+        }
+
+        (sine, cosine)
+    }
+
+    /// `|sin(r)|` scaled by `10 ^ places`, with at least 36 digits.
+    fn scaled_sine(&self, sine_over_r: Fixed) -> u128 {
+        U256::mul(self.magnitude, sine_over_r.0 as u128)
+            .div_rem(10u128.pow(PLACES))
+            .0
+            .to_u128()
+            .unwrap()
+    }
+
+    /// `sin(r)` and `cos(r)`, each as a sign, a magnitude of at least 34 digits, and places.
+    fn components(&self) -> [(bool, u128, i64); 2] {
+        let (sine, cosine) = self.series();
+
+        [
+            (self.negative, self.scaled_sine(sine), self.places),
+            (false, cosine.0 as u128, PLACES as i64),
+        ]
+    }
+
+    fn sin(&self) -> Result<Float, FloatError> {
+        let (sine, _) = self.series();
+
+        Float::from_scaled_integer(self.negative, self.scaled_sine(sine), -self.places)
+    }
+
+    fn cos(&self) -> Result<Float, FloatError> {
+        self.series().1.to_float()
+    }
+
+    fn tan(&self) -> Result<Float, FloatError> {
+        let (sine, cosine) = self.series();
+        let sine = self.scaled_sine(sine);
+        let cosine = cosine.0 as u128;
+
+        if self.quadrant.is_multiple_of(2) {
+            ratio_to_float(self.negative, sine, cosine, PLACES as i64 - self.places)
+        } else if sine == 0 {
+            Err(FloatError::Domain)
+        } else {
+            // tan(r + π/2) = -cos(r) / sin(r)
+            ratio_to_float(!self.negative, cosine, sine, self.places - PLACES as i64)
+        }
+    }
+}
+
+/// `value * 10 ^ -places`, truncated to a Fixed.
+fn to_fixed(mut value: U256, places: i64) -> Fixed {
+    let mut places = places - PLACES as i64;
+    while places > 0 {
+        let step = places.min(38) as u32;
+        value = value.div_rem(10u128.pow(step)).0;
+        places -= step as i64;
+    }
+
+    let value = value.to_u128().unwrap();
+    Fixed((value * 10u128.pow(-places as u32)) as i128)
+}
+
+/// `radius` times a component from [`Reduced::components`], rounded half-up to 14 significant
+/// digits.
+fn scale(
+    radius: &Float,
+    (negative, magnitude, places): (bool, u128, i64),
+) -> Result<Float, FloatError> {
+    // 37 or 38 digits of a product of at most 52
+    let product = U256::mul(magnitude, radius.significand_dec() as u128)
+        .div_rem(10u128.pow(15))
+        .0
+        .to_u128()
+        .unwrap();
+
+    Float::from_scaled_integer(
+        negative != radius.is_negative(),
+        product,
+        radius.exponent() as i64 + 2 - places,
+    )
+}
+
+/// The same component with the opposite sign.
+fn negate((negative, magnitude, places): (bool, u128, i64)) -> (bool, u128, i64) {
+    (!negative, magnitude, places)
+}
+
+/// `atan(z)` for `z = magnitude * 10 ^ -places` in `[0, 1]`, as a magnitude and places.
+fn arctangent(magnitude: u128, places: i64) -> (u128, i64) {
+    // atan(z) = z * (1 - z^2/3 + z^4/5 - ...)
+    let series = |square: Fixed| {
+        let mut sum = Fixed::ONE;
+        let mut power = Fixed::ONE;
+        for n in 1.. {
+            power = power.mul(-square).unwrap();
+            let term = Fixed(power.0 / (2 * n + 1));
+            if term == Fixed::ZERO {
+                break;
+            }
+
+            sum = sum + term;
+        }
+
+        sum
+    };
+
+    let z = to_fixed(U256::new(magnitude), places);
+    if z <= Fixed(Fixed::ONE.0 / 8) {
+        // small arguments keep their relative precision
+        let square = to_fixed(U256::mul(magnitude, magnitude), 2 * places);
+        let product = U256::mul(magnitude, series(square).0 as u128);
+
+        return (
+            product.div_rem(10u128.pow(PLACES)).0.to_u128().unwrap(),
+            places,
+        );
+    }
+
+    // atan(z) = atan(c) + atan((z - c) / (1 + zc)) for the nearest c = k / 8, leaving an
+    // argument below 1/16
+    let k = ((z.0 * 8 + Fixed::ONE.0 / 2) / Fixed::ONE.0) as usize;
+    let c = Fixed(Fixed::ONE.0 * k as i128 / 8);
+    let w = (z - c).div(Fixed::ONE + z.mul(c).unwrap()).unwrap();
+    let arctangent =
+        w.mul(series(w.mul(w).unwrap())).unwrap() + to_fixed(U256::new(ARCTANGENTS[k - 1]), 38);
+
+    (arctangent.0 as u128, PLACES as i64)
+}
+
+impl Float {
+    /// Reduces an angle's magnitude in the given mode.
+    fn reduce(&self, mode: AngleMode) -> Reduced {
+        let angle = self.abs();
+
+        match mode {
+            AngleMode::Radian => Reduced::radians(&angle),
+            AngleMode::Degree => {
+                Reduced::exact(&angle, tifloat!(0x90000000000000 * 10 ^ 1), PI_OVER_180)
+            }
+            // a grad is π/200 radians, which has the digits of π/2
+            AngleMode::Grad => {
+                Reduced::exact(&angle, tifloat!(0x10000000000000 * 10 ^ 2), PI_OVER_2)
+            }
+        }
+    }
+
+    /// `self * cos(angle)`, the x-coordinate of the point at a distance of `self` from the origin
+    /// and an angle of `angle` in the given mode, rounded half-up to 14 significant digits once
+    /// rather than after both the cosine and the product.
+    ///
+    /// Fails with [`FloatError::Underflow`] if the coordinate is nonzero but smaller than
+    /// `1E-99`.
+    pub(crate) fn polar_x(&self, angle: &Float, mode: AngleMode) -> Result<Float, FloatError> {
+        if self.is_undefined() || angle.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        let reduced = angle.reduce(mode);
+        let [sine, cosine] = reduced.components();
+
+        scale(
+            self,
+            match reduced.quadrant {
+                0 => cosine,
+                1 => negate(sine),
+                2 => negate(cosine),
+                _ => sine,
+            },
+        )
+    }
+
+    /// `self * sin(angle)`, the y-coordinate of the point, as [`Float::polar_x`] finds the
+    /// x-coordinate.
+    pub(crate) fn polar_y(&self, angle: &Float, mode: AngleMode) -> Result<Float, FloatError> {
+        if self.is_undefined() || angle.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        let reduced = angle.reduce(mode);
+        let [sine, cosine] = reduced.components();
+        let component = match reduced.quadrant {
+            0 => sine,
+            1 => cosine,
+            2 => negate(sine),
+            _ => negate(cosine),
+        };
+
+        scale(
+            self,
+            if angle.is_negative() {
+                negate(component)
+            } else {
+                component
+            },
+        )
+    }
+
+    /// The angle from the positive x-axis to the point `(x, self)`, in `(-π, π]` or its
+    /// equivalent in the given mode, rounded half-up to 14 significant digits. The sign of `self`
+    /// picks the half-plane, and the sign of `x` the quadrant within it, so `(-1)atan2(-1)` is
+    /// `-135°`.
+    ///
+    /// The angle to the origin is zero, as it is for `R►Pθ(`. Fails with
+    /// [`FloatError::Underflow`] if the angle is nonzero but smaller than `1E-99`. If either
+    /// coordinate is undefined, the angle is undefined.
+    pub fn atan2(&self, x: &Float, mode: AngleMode) -> Result<Float, FloatError> {
+        if self.is_undefined() || x.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        let right_angle = to_fixed(U256::new(PI_OVER_2), 38);
+
+        // the angle from the nearer axis, as a magnitude and places
+        let (magnitude, places) = if self.is_zero() {
+            (0, 0)
+        } else if x.is_zero() {
+            (right_angle.0 as u128, PLACES as i64)
+        } else {
+            let (y, x) = (self.abs(), x.abs());
+            let (numerator, denominator) = if y <= x { (y, x) } else { (x, y) };
+
+            // a ratio of 37 or 38 digits
+            let ratio = U256::mul(numerator.significand_dec() as u128, 10u128.pow(37))
+                .div_rem(denominator.significand_dec() as u128)
+                .0
+                .to_u128()
+                .unwrap();
+            let places = 37 - (numerator.exponent() as i64 - denominator.exponent() as i64);
+            let (magnitude, places) = arctangent(ratio, places);
+
+            if y <= x {
+                (magnitude, places)
+            } else {
+                let angle = right_angle - to_fixed(U256::new(magnitude), places);
+                (angle.0 as u128, PLACES as i64)
+            }
+        };
+
+        // measured from the negative x-axis, it's the supplement
+        let (magnitude, places) = if x.is_negative() {
+            let angle = right_angle + right_angle - to_fixed(U256::new(magnitude), places);
+            (angle.0 as u128, PLACES as i64)
+        } else {
+            (magnitude, places)
+        };
+
+        let (magnitude, places) = match mode {
+            AngleMode::Radian => (magnitude, places),
+            AngleMode::Degree => (
+                U256::mul(magnitude, DEGREES_PER_RADIAN)
+                    .div_rem(10u128.pow(38))
+                    .0
+                    .to_u128()
+                    .unwrap(),
+                places - 2,
+            ),
+            AngleMode::Grad => (
+                U256::mul(magnitude, GRADS_PER_RADIAN)
+                    .div_rem(10u128.pow(38))
+                    .0
+                    .to_u128()
+                    .unwrap(),
+                places - 2,
+            ),
+        };
+
+        Float::from_scaled_integer(self.is_negative(), magnitude, -places)
+    }
+
+    /// The sine of an angle in the given mode, rounded half-up to 14 significant digits.
+    ///
+    /// Angles are reduced exactly: in degrees and grads by exact remainders, so that angles such
+    /// as `180°` have a sine of exactly zero, and in radians with enough digits of `π` that the
+    /// sine of any Float is accurate. The sine of an undefined Float is undefined.
+    pub fn sin(&self, mode: AngleMode) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        let reduced = self.reduce(mode);
+        let sine = match reduced.quadrant {
+            0 => reduced.sin()?,
+            1 => reduced.cos()?,
+            2 => -reduced.sin()?,
+            _ => -reduced.cos()?,
+        };
+
+        Ok(if self.is_negative() { -sine } else { sine })
+    }
+
+    /// The cosine of an angle in the given mode, rounded half-up to 14 significant digits, with
+    /// angles reduced as [`Float::sin`] reduces them. The cosine of an undefined Float is
+    /// undefined.
+    pub fn cos(&self, mode: AngleMode) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        let reduced = self.reduce(mode);
+        match reduced.quadrant {
+            0 => reduced.cos(),
+            1 => Ok(-reduced.sin()?),
+            2 => Ok(-reduced.cos()?),
+            _ => reduced.sin(),
+        }
+    }
+
+    /// The tangent of an angle in the given mode, rounded half-up to 14 significant digits, with
+    /// angles reduced as [`Float::sin`] reduces them.
+    ///
+    /// Fails with [`FloatError::Domain`] where the tangent is undefined, such as at `90°`, which
+    /// only an angle in degrees or grads can land on exactly. The tangent of an undefined Float
+    /// is undefined.
+    pub fn tan(&self, mode: AngleMode) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        let tangent = self.reduce(mode).tan()?;
+
+        Ok(if self.is_negative() {
+            -tangent
+        } else {
+            tangent
+        })
+    }
+
+    /// Converts an angle in the given mode to radians, rounded half-up to 14 significant digits
+    /// once, as the calculator does for `°` in Radian mode.
+    ///
+    /// Fails with [`FloatError::Underflow`] if the converted angle is nonzero but smaller than
+    /// `1E-99`. An undefined angle stays undefined.
+    pub fn to_radians(&self, from: AngleMode) -> Result<Float, FloatError> {
+        self.convert_angle(from, AngleMode::Radian)
+    }
+
+    /// Converts an angle in the given mode to degrees, as [`Float::to_radians`] converts to
+    /// radians. This is `ʳ` in Degree mode.
+    ///
+    /// Fails with [`FloatError::Overflow`] if the converted angle is too large for a Float.
+    pub fn to_degrees(&self, from: AngleMode) -> Result<Float, FloatError> {
+        self.convert_angle(from, AngleMode::Degree)
+    }
+
+    /// Converts an angle in the given mode to grads, as [`Float::to_radians`] converts to
+    /// radians. Between degrees and grads the conversion is a ratio of integers, so `90°` is
+    /// exactly `100` grads.
+    ///
+    /// Fails with [`FloatError::Overflow`] if the converted angle is too large for a Float.
+    pub fn to_gradians(&self, from: AngleMode) -> Result<Float, FloatError> {
+        self.convert_angle(from, AngleMode::Grad)
+    }
+
+    /// Converts an angle between modes with a single rounding.
+    fn convert_angle(&self, from: AngleMode, to: AngleMode) -> Result<Float, FloatError> {
+        if self.is_undefined() || self.is_zero() || from == to {
+            return Ok(*self);
+        }
+
+        let significand = self.significand_dec() as u128;
+        let exponent = self.exponent() as i64 - 13;
+
+        // one unit of `from` in units of `to`, scaled by `10 ^ places`; a grad is π/200 radians,
+        // which has the digits of π/2
+        let (factor, places) = match (from, to) {
+            (AngleMode::Degree, AngleMode::Radian) => (PI_OVER_180, 40),
+            (AngleMode::Grad, AngleMode::Radian) => (PI_OVER_2, 40),
+            (AngleMode::Radian, AngleMode::Degree) => (DEGREES_PER_RADIAN, 36),
+            (AngleMode::Radian, AngleMode::Grad) => (GRADS_PER_RADIAN, 36),
+            (AngleMode::Grad, AngleMode::Degree) => (9, 1),
+            (AngleMode::Degree, AngleMode::Grad) => {
+                return Float::from_scaled_ratio(self.is_negative(), significand * 10, 9, exponent);
+            }
+            _ => unreachable!(),
+        };
+
+        U256::mul(significand, factor).to_float(self.is_negative(), exponent - places)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, AngleMode, Float, FloatError};
+
+    fn degrees(value: i64) -> Float {
+        let magnitude = Float::from(value.unsigned_abs());
+
+        if value < 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    #[test]
+    fn degrees_and_grads() {
+        let half = tifloat!(0x50000000000000 * 10 ^ -1);
+
+        assert_eq!(degrees(30).sin(AngleMode::Degree), Ok(half));
+        assert_eq!(degrees(180).sin(AngleMode::Degree), Ok(Float::ZERO));
+        assert_eq!(degrees(-540).sin(AngleMode::Degree), Ok(Float::ZERO));
+        assert_eq!(degrees(90).cos(AngleMode::Degree), Ok(Float::ZERO));
+        assert_eq!(degrees(270).cos(AngleMode::Degree), Ok(Float::ZERO));
+        assert_eq!(degrees(-120).cos(AngleMode::Degree), Ok(-half));
+        assert_eq!(degrees(225).tan(AngleMode::Degree), Ok(Float::ONE));
+        assert_eq!(degrees(-45).tan(AngleMode::Degree), Ok(-Float::ONE));
+        assert_eq!(
+            degrees(60).sin(AngleMode::Degree),
+            Ok(tifloat!(0x86602540378444 * 10 ^ -1))
+        );
+        assert_eq!(
+            degrees(1).sin(AngleMode::Degree),
+            Ok(tifloat!(0x17452406437284 * 10 ^ -2))
+        );
+        assert_eq!(Float::MAX.sin(AngleMode::Degree), Ok(Float::ZERO));
+        assert_eq!(degrees(90).tan(AngleMode::Degree), Err(FloatError::Domain));
+        assert_eq!(
+            degrees(-270).tan(AngleMode::Degree),
+            Err(FloatError::Domain)
+        );
+
+        assert_eq!(degrees(200).sin(AngleMode::Grad), Ok(Float::ZERO));
+        assert_eq!(degrees(100).sin(AngleMode::Grad), Ok(Float::ONE));
+        assert_eq!(degrees(50).tan(AngleMode::Grad), Ok(Float::ONE));
+        assert_eq!(degrees(300).tan(AngleMode::Grad), Err(FloatError::Domain));
+
+        // the last digit survives measuring from the nearest right angle
+        assert_eq!(
+            tifloat!(0x58661028488607 * 10 ^ 1).cos(AngleMode::Grad),
+            Ok(tifloat!(0x60466965660088 * 10 ^ -1))
+        );
+        assert_eq!(
+            tifloat!(0x15866102848861 * 10 ^ 2).sin(AngleMode::Degree),
+            Ok(tifloat!(0x36388486524192 * 10 ^ -1))
+        );
+    }
+
+    #[test]
+    fn atan2() {
+        let one = Float::ONE;
+        let zero = Float::ZERO;
+
+        assert_eq!(one.atan2(&one, AngleMode::Degree), Ok(degrees(45)));
+        assert_eq!((-one).atan2(&-one, AngleMode::Degree), Ok(degrees(-135)));
+        assert_eq!(one.atan2(&-one, AngleMode::Degree), Ok(degrees(135)));
+        assert_eq!(one.atan2(&zero, AngleMode::Degree), Ok(degrees(90)));
+        assert_eq!((-one).atan2(&zero, AngleMode::Grad), Ok(degrees(-100)));
+        assert_eq!(zero.atan2(&-one, AngleMode::Degree), Ok(degrees(180)));
+        assert_eq!(zero.atan2(&-one, AngleMode::Radian), Ok(Float::PI));
+        assert_eq!(zero.atan2(&zero, AngleMode::Radian), Ok(zero));
+        assert_eq!(zero.atan2(&one, AngleMode::Degree), Ok(zero));
+        assert_eq!(
+            tifloat!(0x30000000000000 * 10 ^ 0).atan2(&one, AngleMode::Degree),
+            Ok(tifloat!(0x71565051177078 * 10 ^ 1))
+        );
+        assert_eq!(
+            one.atan2(&tifloat!(-0x20000000000000 * 10 ^ 0), AngleMode::Radian),
+            Ok(tifloat!(0x26779450445890 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(-0x12345678901234 * 10 ^ -40).atan2(&one, AngleMode::Radian),
+            Ok(tifloat!(-0x12345678901234 * 10 ^ -40))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ -60).atan2(&-one, AngleMode::Radian),
+            Ok(Float::PI)
+        );
+        assert_eq!(
+            Float::MAX.atan2(&Float::MIN_POSITIVE, AngleMode::Degree),
+            Ok(degrees(90))
+        );
+        assert_eq!(
+            Float::MIN_POSITIVE.atan2(&Float::MAX, AngleMode::Radian),
+            Err(FloatError::Underflow)
+        );
+        assert!(one
+            .atan2(&Float::undefined(), AngleMode::Radian)
+            .unwrap()
+            .is_undefined());
+    }
+
+    #[test]
+    fn radians() {
+        assert_eq!(Float::ZERO.sin(AngleMode::Radian), Ok(Float::ZERO));
+        assert_eq!(Float::ZERO.cos(AngleMode::Radian), Ok(Float::ONE));
+        assert_eq!(
+            Float::ONE.sin(AngleMode::Radian),
+            Ok(tifloat!(0x84147098480790 * 10 ^ -1))
+        );
+        assert_eq!(
+            Float::ONE.cos(AngleMode::Radian),
+            Ok(tifloat!(0x54030230586814 * 10 ^ -1))
+        );
+        assert_eq!(
+            Float::ONE.tan(AngleMode::Radian),
+            Ok(tifloat!(0x15574077246549 * 10 ^ 0))
+        );
+        assert_eq!(
+            Float::PI.sin(AngleMode::Radian),
+            Ok(tifloat!(-0x67615373566167 * 10 ^ -15))
+        );
+        assert_eq!(Float::PI.cos(AngleMode::Radian), Ok(-Float::ONE));
+        assert_eq!(
+            tifloat!(-0x10000000000000 * 10 ^ 22).sin(AngleMode::Radian),
+            Ok(tifloat!(0x85220084976719 * 10 ^ -1))
+        );
+        assert_eq!(
+            Float::MAX.cos(AngleMode::Radian),
+            Ok(tifloat!(0x42320899672915 * 10 ^ -1))
+        );
+        assert_eq!(
+            tifloat!(0x12345678901234 * 10 ^ -50).sin(AngleMode::Radian),
+            Ok(tifloat!(0x12345678901234 * 10 ^ -50))
+        );
+        assert!(Float::undefined()
+            .tan(AngleMode::Radian)
+            .unwrap()
+            .is_undefined());
+    }
+
+    #[test]
+    fn conversions() {
+        let thirty = tifloat!(0x30000000000000 * 10 ^ 1);
+        assert_eq!(
+            thirty.to_radians(AngleMode::Degree),
+            Ok(tifloat!(0x52359877559830 * 10 ^ -1))
+        );
+        assert_eq!(
+            thirty.sin(AngleMode::Degree),
+            Ok(tifloat!(0x50000000000000 * 10 ^ -1))
+        );
+        assert_eq!(
+            Float::ONE.to_degrees(AngleMode::Radian),
+            Ok(tifloat!(0x57295779513082 * 10 ^ 1))
+        );
+        assert_eq!(
+            Float::ONE.to_gradians(AngleMode::Radian),
+            Ok(tifloat!(0x63661977236758 * 10 ^ 1))
+        );
+        assert_eq!(
+            tifloat!(0x12500000000000 * 10 ^ 1).to_radians(AngleMode::Grad),
+            Ok(tifloat!(0x19634954084936 * 10 ^ -1))
+        );
+        assert_eq!(
+            tifloat!(0x90000000000000 * 10 ^ 1).to_gradians(AngleMode::Degree),
+            Ok(tifloat!(0x10000000000000 * 10 ^ 2))
+        );
+        assert_eq!(
+            tifloat!(0x10000000000000 * 10 ^ 0).to_gradians(AngleMode::Degree),
+            Ok(tifloat!(0x11111111111111 * 10 ^ 0))
+        );
+        assert_eq!(
+            tifloat!(-0x10000000000000 * 10 ^ 2).to_degrees(AngleMode::Grad),
+            Ok(tifloat!(-0x90000000000000 * 10 ^ 1))
+        );
+        assert_eq!(Float::PI.to_radians(AngleMode::Radian), Ok(Float::PI));
+        assert_eq!(
+            Float::MAX.to_degrees(AngleMode::Radian),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            tifloat!(-0x10000000000000 * 10 ^ -98).to_radians(AngleMode::Degree),
+            Err(FloatError::Underflow)
+        );
+        assert!(Float::undefined()
+            .to_degrees(AngleMode::Grad)
+            .unwrap()
+            .is_undefined());
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{Float, FloatError};
+
+/// A 256-bit unsigned integer, just wide enough for the products of two `u128`s that exact
+/// roots and extended-precision functions need.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct U256 {
+    // field order makes the derived ordering compare the high half first
+    hi: u128,
+    lo: u128,
+}
+
+impl U256 {
+    pub(crate) const fn new(value: u128) -> Self {
+        U256 { hi: 0, lo: value }
+    }
+
+    /// The full product of two `u128`s.
+    pub(crate) fn mul(a: u128, b: u128) -> Self {
+        const MASK: u128 = u64::MAX as u128;
+
+        let (a_hi, a_lo) = (a >> 64, a & MASK);
+        let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+        let low = a_lo * b_lo;
+        let (middle, carry) = (a_hi * b_lo).overflowing_add(a_lo * b_hi);
+        let (lo, carry_lo) = low.overflowing_add(middle << 64);
+
+        U256 {
+            hi: a_hi * b_hi + (middle >> 64) + ((carry as u128) << 64) + carry_lo as u128,
+            lo,
+        }
+    }
+
+    /// The sum, or `None` if it doesn't fit in 256 bits.
+    pub(crate) fn checked_add(self, rhs: U256) -> Option<Self> {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+
+        Some(U256 {
+            hi: self.hi.checked_add(rhs.hi)?.checked_add(carry as u128)?,
+            lo,
+        })
+    }
+
+    /// The difference, or `None` if it would be negative.
+    pub(crate) fn checked_sub(self, rhs: U256) -> Option<Self> {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+
+        Some(U256 {
+            hi: self.hi.checked_sub(rhs.hi)?.checked_sub(borrow as u128)?,
+            lo,
+        })
+    }
+
+    /// The product with a `u128`, or `None` if it doesn't fit in 256 bits.
+    pub(crate) fn checked_mul(self, rhs: u128) -> Option<Self> {
+        let low = U256::mul(self.lo, rhs);
+        let high = U256::mul(self.hi, rhs);
+
+        if high.hi != 0 {
+            return None;
+        }
+
+        Some(U256 {
+            hi: high.lo.checked_add(low.hi)?,
+            lo: low.lo,
+        })
+    }
+
+    /// The quotient and remainder of dividing by a nonzero `u128`.
+    pub(crate) fn div_rem(self, divisor: u128) -> (Self, u128) {
+        let hi = self.hi / divisor;
+        let mut remainder = self.hi % divisor;
+        let mut lo = 0;
+
+        // long division one bit at a time; the remainder stays below the divisor, so a bit
+        // shifted out of it means the shifted remainder exceeds the divisor
+        for bit in (0..128).rev() {
+            let overflow = remainder >> 127 != 0;
+            remainder = remainder << 1 | (self.lo >> bit & 1);
+
+            if overflow || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                lo |= 1 << bit;
+            }
+        }
+
+        (U256 { hi, lo }, remainder)
+    }
+
+    /// The square root, rounded down. The value must be less than `2 ^ 254`.
+    pub(crate) fn isqrt(self) -> u128 {
+        if self == U256::new(0) {
+            return 0;
+        }
+
+        // from an estimate above the root, each step of Newton's method stays at or above it, and
+        // the first step that doesn't descend has found it
+        let estimate = (self.hi as f64 * 2f64.powi(128) + self.lo as f64).sqrt();
+        let mut root = (estimate * (1.0 + 1e-9)) as u128 + 2;
+        loop {
+            let next = (root + self.div_rem(root).0.to_u128().unwrap()) / 2;
+
+            if next >= root {
+                return root;
+            }
+
+            root = next;
+        }
+    }
+
+    /// The value as a `u128`, if it fits.
+    pub(crate) fn to_u128(self) -> Option<u128> {
+        (self.hi == 0).then_some(self.lo)
+    }
+
+    /// Rounds `self * 10 ^ exponent` half-up to a Float.
+    pub(crate) fn to_float(self, negative: bool, mut exponent: i64) -> Result<Float, FloatError> {
+        // drop digits down to 37, remembering whether any of them were nonzero
+        let limit = U256::new(10u128.pow(37));
+        let mut value = self;
+        let mut inexact = false;
+        while value >= limit {
+            let (quotient, remainder) = value.div_rem(10);
+
+            value = quotient;
+            inexact |= remainder != 0;
+            exponent += 1;
+        }
+
+        // a nonzero digit after those kept stands in for everything dropped, so that a value just
+        // past halfway still rounds up
+        let mut value = value.to_u128().unwrap();
+        if inexact {
+            value = value * 10 + 1;
+            exponent -= 1;
+        }
+
+        Float::from_scaled_integer(negative, value, exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let product = U256::mul(u128::MAX, u128::MAX);
+        assert_eq!(
+            product,
+            U256 {
+                hi: u128::MAX - 1,
+                lo: 1
+            }
+        );
+        assert_eq!(product.div_rem(u128::MAX), (U256::new(u128::MAX), 0));
+
+        // 10 ^ 46 = 10 ^ 4 (mod 7), since 10 ^ 6 = 1 (mod 7)
+        let power = U256::new(10u128.pow(30))
+            .checked_mul(10u128.pow(16))
+            .unwrap();
+        let (quotient, remainder) = power.div_rem(7);
+        assert_eq!(remainder, 4);
+        assert_eq!(quotient.checked_mul(7).unwrap().div_rem(7), (quotient, 0));
+        assert_eq!(quotient.to_u128(), None);
+        assert_eq!(
+            U256::new(10u128.pow(38)).div_rem(3),
+            (U256::new(10u128.pow(38) / 3), 1)
+        );
+        assert_eq!(U256::new(1).checked_mul(0), Some(U256::new(0)));
+        assert_eq!(
+            U256::new(u128::MAX).checked_add(U256::new(1)),
+            Some(U256 { hi: 1, lo: 0 })
+        );
+        assert_eq!(
+            U256 {
+                hi: u128::MAX,
+                lo: u128::MAX
+            }
+            .checked_add(U256::new(1)),
+            None
+        );
+        assert_eq!(product.checked_add(product), None);
+        assert_eq!(
+            U256 { hi: 1, lo: 0 }.checked_sub(U256::new(1)),
+            Some(U256::new(u128::MAX))
+        );
+        assert_eq!(U256::new(1).checked_sub(U256::new(2)), None);
+        assert_eq!(product.checked_mul(2), None);
+        assert!(U256::mul(1 << 64, 1 << 64) > U256::new(u128::MAX));
+    }
+
+    #[test]
+    fn isqrt() {
+        assert_eq!(U256::new(0).isqrt(), 0);
+        assert_eq!(U256::new(99).isqrt(), 9);
+        assert_eq!(U256::new(100).isqrt(), 10);
+
+        let root = 10u128.pow(30) + 7;
+        let square = U256::mul(root, root);
+        assert_eq!(square.isqrt(), root);
+        assert_eq!(
+            square.checked_add(U256::mul(2, root)).unwrap().isqrt(),
+            root
+        );
+
+        let root = (1 << 126) + 3;
+        let square = U256::mul(root, root);
+        assert_eq!(square.isqrt(), root);
+        assert_eq!(
+            square.checked_add(U256::mul(2, root)).unwrap().isqrt(),
+            root
+        );
+    }
+}
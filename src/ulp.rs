@@ -0,0 +1,182 @@
+use crate::{Float, FloatError};
+
+impl Float {
+    /// One unit in the last place of this Float: `10 ^ (exponent - 13)`, the gap between
+    /// adjacent Floats with this exponent. The ulp of zero is [`Float::MIN_POSITIVE`], its gap to
+    /// the nearest nonzero Float, and the ulp of an undefined Float is undefined.
+    ///
+    /// Fails with [`FloatError::Underflow`] if the ulp is smaller than `1E-99`, which is the case
+    /// for exponents below -86.
+    pub fn ulp(&self) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            return Ok(*self);
+        } else if self.is_zero() {
+            return Ok(Float::MIN_POSITIVE);
+        }
+
+        Float::from_scaled_integer(false, 1, self.exponent() as i64 - 13)
+    }
+
+    /// The least Float greater than this one. Undefined Floats are returned unchanged.
+    ///
+    /// Fails with [`FloatError::Overflow`] for [`Float::MAX`].
+    pub fn next_up(&self) -> Result<Float, FloatError> {
+        if self.is_undefined() {
+            Ok(*self)
+        } else if self.is_zero() {
+            Ok(Float::MIN_POSITIVE)
+        } else if self.is_negative() {
+            Ok(-(-*self).step_toward_zero())
+        } else {
+            self.step_away_from_zero()
+        }
+    }
+
+    /// The greatest Float less than this one. Undefined Floats are returned unchanged.
+    ///
+    /// Fails with [`FloatError::Overflow`] for `-MAX`.
+    pub fn next_down(&self) -> Result<Float, FloatError> {
+        (-*self).next_up().map(|float| -float)
+    }
+
+    /// Whether `other` is at most `max_ulps` representable Floats away from this one. Zero is
+    /// one step from `±MIN_POSITIVE`, and undefined Floats are never approximately equal.
+    pub fn approx_eq(&self, other: &Float, max_ulps: u64) -> bool {
+        if self.is_undefined() || other.is_undefined() {
+            return false;
+        }
+
+        self.ordinal().abs_diff(other.ordinal()) <= max_ulps
+    }
+
+    /// The position of this Float in the sequence of all Floats, counting from zero, so that
+    /// adjacent Floats differ by one.
+    fn ordinal(&self) -> i64 {
+        if self.is_zero() {
+            return 0;
+        }
+
+        // each exponent holds 9 * 10 ^ 13 significands, from 10000000000000 to 99999999999999
+        let position = (self.exponent() as i64 + 99) * 90000000000000
+            + (self.significand_dec() as i64 - 10000000000000)
+            + 1;
+
+        if self.is_negative() {
+            -position
+        } else {
+            position
+        }
+    }
+
+    /// The adjacent Float of greater magnitude and the same sign.
+    fn step_away_from_zero(&self) -> Result<Float, FloatError> {
+        let lsd_exponent = self.exponent() as i64 - 13;
+
+        // 99999999999999 + 1 carries into a new digit, which from_scaled_integer handles
+        Float::from_scaled_integer(
+            self.is_negative(),
+            self.significand_dec() as u128 + 1,
+            lsd_exponent,
+        )
+    }
+
+    /// The adjacent Float of lesser magnitude and the same sign, or zero.
+    fn step_toward_zero(&self) -> Float {
+        let lsd_exponent = self.exponent() as i64 - 13;
+        let significand = self.significand_dec() as u128;
+
+        let result = if significand == 10000000000000 {
+            // 10000000000000 - 1 has only 13 digits, so step within the next exponent down
+            Float::from_scaled_integer(self.is_negative(), 99999999999999, lsd_exponent - 1)
+        } else {
+            Float::from_scaled_integer(self.is_negative(), significand - 1, lsd_exponent)
+        };
+
+        // the only Float below MIN_POSITIVE in magnitude is zero
+        result.unwrap_or(Float::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatError};
+
+    #[test]
+    fn ulp() {
+        assert_eq!(
+            Float::ONE.ulp().unwrap(),
+            tifloat!(0x10000000000000 * 10 ^ -13)
+        );
+        assert_eq!(
+            tifloat!(-0x12500000000000 * 10 ^ 20).ulp().unwrap(),
+            tifloat!(0x10000000000000 * 10 ^ 7)
+        );
+        assert_eq!(Float::ZERO.ulp().unwrap(), Float::MIN_POSITIVE);
+        assert!(matches!(
+            Float::MIN_POSITIVE.ulp(),
+            Err(FloatError::Underflow)
+        ));
+        assert!(Float::undefined().ulp().unwrap().is_undefined());
+    }
+
+    #[test]
+    fn next_up_down() {
+        let cases = [
+            (
+                Float::ONE,
+                tifloat!(0x10000000000001 * 10 ^ 0),
+                tifloat!(0x99999999999999 * 10 ^ -1),
+            ),
+            (
+                Float::NEG_ONE,
+                tifloat!(-0x99999999999999 * 10 ^ -1),
+                tifloat!(-0x10000000000001 * 10 ^ 0),
+            ),
+            (
+                tifloat!(0x99999999999999 * 10 ^ 5),
+                tifloat!(0x10000000000000 * 10 ^ 6),
+                tifloat!(0x99999999999998 * 10 ^ 5),
+            ),
+            (Float::ZERO, Float::MIN_POSITIVE, -Float::MIN_POSITIVE),
+            (
+                Float::MIN_POSITIVE,
+                tifloat!(0x10000000000001 * 10 ^ -99),
+                Float::ZERO,
+            ),
+            (
+                -Float::MIN_POSITIVE,
+                Float::ZERO,
+                tifloat!(-0x10000000000001 * 10 ^ -99),
+            ),
+        ];
+
+        for (float, up, down) in cases {
+            assert_eq!(float.next_up().unwrap(), up, "{float:?}");
+            assert_eq!(float.next_down().unwrap(), down, "{float:?}");
+        }
+
+        assert!(matches!(Float::MAX.next_up(), Err(FloatError::Overflow)));
+        assert!(matches!(
+            (-Float::MAX).next_down(),
+            Err(FloatError::Overflow)
+        ));
+        assert!(Float::undefined().next_up().unwrap().is_undefined());
+        assert!(Float::undefined().next_down().unwrap().is_undefined());
+    }
+
+    #[test]
+    fn approx_eq() {
+        let float = tifloat!(0x99999999999999 * 10 ^ 5);
+        let next = float.next_up().unwrap();
+
+        assert!(float.approx_eq(&float, 0));
+        assert!(float.approx_eq(&next, 1));
+        assert!(!float.approx_eq(&next, 0));
+        assert!(next.approx_eq(&next.next_up().unwrap().next_up().unwrap(), 2));
+
+        assert!(Float::MIN_POSITIVE.approx_eq(&-Float::MIN_POSITIVE, 2));
+        assert!(!Float::MIN_POSITIVE.approx_eq(&-Float::MIN_POSITIVE, 1));
+        assert!(!Float::ONE.approx_eq(&Float::NEG_ONE, 1000));
+        assert!(!Float::undefined().approx_eq(&Float::undefined(), u64::MAX));
+    }
+}
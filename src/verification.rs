@@ -0,0 +1,232 @@
+//! Test vectors: operands paired with the results a calculator is expected to produce for
+//! them, and a harness that checks this crate's arithmetic against them.
+//!
+//! Vectors are written one per line, as `lhs op rhs = result`, where `op` is one of `+ - * /`
+//! and `result` is either a number or an error screen such as `ERR:DIVIDE BY 0`. Blank lines
+//! and lines starting with `#` are ignored.
+//!
+//! ```text
+//! # 2/3 rounds half-up in the 14th digit
+//! 2 / 3 = .66666666666667
+//! 1 / 0 = ERR:DIVIDE BY 0
+//! ```
+//!
+//! No vectors are included. Vectors captured by running the ROM, for example in an emulator,
+//! can be read with [`parse`] and checked with [`verify`]; a mismatch only means something if
+//! the vectors came from the calculator rather than from this crate's own rules.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{Error, Float, FloatError, OsError, ParseFloatError};
+
+/// An arithmetic operation that a vector exercises.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operation {
+    /// Performs the operation with this crate's arithmetic.
+    pub fn apply(self, lhs: Float, rhs: Float) -> Result<Float, FloatError> {
+        match self {
+            Operation::Add => lhs + rhs,
+            Operation::Subtract => lhs - rhs,
+            Operation::Multiply => lhs * rhs,
+            Operation::Divide => lhs / rhs,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Operation::Add => "+",
+            Operation::Subtract => "-",
+            Operation::Multiply => "*",
+            Operation::Divide => "/",
+        }
+    }
+}
+
+/// Two operands, an operation, and the result or error expected of it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Vector {
+    pub lhs: Float,
+    pub operation: Operation,
+    pub rhs: Float,
+    pub expected: Result<Float, OsError>,
+}
+
+impl Vector {
+    /// Runs the operation, returning a mismatch if the result isn't the expected one. Results
+    /// compare by value, and errors by the error screen they would show.
+    pub fn check(&self) -> Option<Mismatch> {
+        let actual = self.operation.apply(self.lhs, self.rhs);
+
+        let matches = match (&self.expected, &actual) {
+            (Ok(expected), Ok(actual)) => expected == actual,
+            (Err(expected), Err(actual)) => actual.os_error() == Some(*expected),
+            _ => false,
+        };
+
+        (!matches).then_some(Mismatch {
+            vector: *self,
+            actual,
+        })
+    }
+}
+
+impl Display for Vector {
+    /// Writes the vector in the line format that [`parse`] reads.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} = ",
+            self.lhs.to_shortest_string(),
+            self.operation.symbol(),
+            self.rhs.to_shortest_string()
+        )?;
+
+        match self.expected {
+            Ok(result) => f.write_str(&result.to_shortest_string()),
+            Err(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// A vector whose result this crate doesn't reproduce.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    pub vector: Vector,
+    pub actual: Result<Float, FloatError>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, but got ", self.vector)?;
+
+        match self.actual {
+            Ok(result) => f.write_str(&result.to_shortest_string()),
+            Err(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// Checks every vector, returning the ones this crate gets wrong.
+pub fn verify(vectors: &[Vector]) -> Vec<Mismatch> {
+    vectors.iter().filter_map(Vector::check).collect()
+}
+
+/// Reads vectors in the line format described in the [module documentation](self). A malformed
+/// line is an [`Error::Parse`] with the offset of the token that couldn't be read.
+pub fn parse(text: &str) -> Result<Vec<Vector>, Error> {
+    let mut vectors = vec![];
+    let mut line_start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let offset = line_start;
+        line_start += line.len();
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        // offsets of tokens within the line, relative to the whole text
+        let at = |token: &str| offset + (token.as_ptr() as usize - line.as_ptr() as usize);
+        let invalid = |token: &str| ParseFloatError::InvalidString.at(at(token));
+        let number = |token: &str| token.parse::<Float>().map_err(|err| err.at(at(token)));
+
+        let (operation, result) = trimmed.split_once('=').ok_or_else(|| invalid(trimmed))?;
+        let mut tokens = operation.split_whitespace();
+        let (Some(lhs), Some(symbol), Some(rhs), None) =
+            (tokens.next(), tokens.next(), tokens.next(), tokens.next())
+        else {
+            return Err(invalid(operation));
+        };
+
+        let operation = match symbol {
+            "+" => Operation::Add,
+            "-" => Operation::Subtract,
+            "*" => Operation::Multiply,
+            "/" => Operation::Divide,
+            _ => return Err(invalid(symbol)),
+        };
+
+        let result = result.trim();
+        let expected = match result.strip_prefix("ERR:") {
+            // every number the OS could give an error, without the `Goto` bit
+            Some(name) => Err((1..0x80)
+                .filter_map(|code| OsError::try_from(code).ok())
+                .find(|error| error.name() == name)
+                .ok_or_else(|| invalid(result))?),
+            None => Ok(number(result)?),
+        };
+
+        vectors.push(Vector {
+            lhs: number(lhs)?,
+            operation,
+            rhs: number(rhs)?,
+            expected,
+        });
+    }
+
+    Ok(vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn mismatches() {
+        let vectors = parse("1 + 1 = 3\n1 * 1 = ERR:OVERFLOW\n").unwrap();
+        let mismatches = verify(&vectors);
+
+        assert_eq!(mismatches.len(), 2);
+        assert_eq!(
+            mismatches[0].actual,
+            Ok(tifloat!(0x20000000000000 * 10 ^ 0))
+        );
+        assert_eq!(mismatches[0].to_string(), "1 + 1 = 3, but got 2");
+        assert_eq!(mismatches[1].to_string(), "1 * 1 = ERR:OVERFLOW, but got 1");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("1 + 1 = 2\n1 % 2 = 1\n"),
+            Err(ParseFloatError::InvalidString.at(12))
+        );
+        assert_eq!(
+            parse("1 + x = 2"),
+            Err(ParseFloatError::InvalidString.at(4))
+        );
+        assert_eq!(
+            parse("1 / 0 = ERR:DIVIDE BY ZERO"),
+            Err(ParseFloatError::InvalidString.at(8))
+        );
+        assert_eq!(parse("1 + 1"), Err(ParseFloatError::InvalidString.at(0)));
+    }
+
+    #[test]
+    fn error_names() {
+        let vectors = parse("1 + 1 = ERR:MODE\n").unwrap();
+
+        assert_eq!(vectors[0].expected, Err(OsError::Mode));
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "1 / 3 = .33333333333333\n-6 / 4 = -1.5\n5E99 + 5E99 = ERR:OVERFLOW\n";
+        let vectors = parse(text).unwrap();
+
+        assert_eq!(vectors.len(), 3);
+        assert_eq!(verify(&vectors), []);
+        for vector in vectors {
+            assert_eq!(parse(&vector.to_string()).unwrap(), [vector]);
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::cmp::Ordering;
+
+use crate::io::decode;
+use crate::{Error, Flags, Float, ParseFloatError};
+
+/// A borrowed view of a Float's byte representation (flags at index zero), for scanning reals in
+/// place, such as in a variable file, without copying each one out.
+///
+/// The bytes are only validated when they are converted with [`FloatRef::to_float`] or compared.
+/// Views of invalid bytes compare unequal to everything, like NaN.
+#[derive(Debug, Copy, Clone)]
+pub struct FloatRef<'a> {
+    bytes: &'a [u8; 9],
+}
+
+impl<'a> FloatRef<'a> {
+    pub fn new(bytes: &'a [u8; 9]) -> Self {
+        FloatRef { bytes }
+    }
+
+    /// Views the first 9 bytes of a slice. Bytes after the first 9 are ignored.
+    pub fn from_slice(bytes: &'a [u8]) -> Result<Self, ParseFloatError> {
+        let bytes = bytes
+            .get(..9)
+            .ok_or(ParseFloatError::InvalidLength)?
+            .try_into()
+            .unwrap();
+
+        Ok(FloatRef::new(bytes))
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8; 9] {
+        self.bytes
+    }
+
+    /// The flags byte, with any unknown bits dropped.
+    pub fn flags(&self) -> Flags {
+        Flags::from_bits_truncate(self.bytes[0])
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.flags().contains(Flags::NEGATIVE)
+    }
+
+    pub fn is_undefined(&self) -> bool {
+        self.flags().contains(Flags::UNDEFINED)
+    }
+
+    /// The unbiased exponent. Like [`Float::exponent`], it is meaningless for an undefined Float.
+    pub fn exponent(&self) -> i8 {
+        self.bytes[1].wrapping_sub(0x80) as i8
+    }
+
+    /// Copies the bytes into an owned Float, validating them as [`Float::from_raw_bytes`] does.
+    pub fn to_float(&self) -> Result<Float, ParseFloatError> {
+        Float::from_raw_bytes(*self.bytes)
+    }
+}
+
+/// A Float's 9-byte representation (flags at index zero), stored inline and unvalidated.
+///
+/// Unlike [`Float`], whose in-memory layout has padding and a native-endian mantissa, a slice of
+/// `RawFloat`s has the same layout as the bytes of a TI list, so with the `bytemuck` feature a
+/// byte slice can be cast to one without copying. Check the result with [`RawFloat::validate`]
+/// before relying on it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct RawFloat(pub [u8; 9]);
+
+impl RawFloat {
+    pub fn as_float_ref(&self) -> FloatRef<'_> {
+        FloatRef::new(&self.0)
+    }
+
+    /// Validates the bytes and produces a Float, as [`Float::from_raw_bytes`] does.
+    pub fn to_float(&self) -> Result<Float, ParseFloatError> {
+        Float::from_raw_bytes(self.0)
+    }
+
+    /// Checks that every element is a valid Float, reporting the byte offset of the first invalid
+    /// byte in the slice.
+    pub fn validate(raws: &[RawFloat]) -> Result<(), Error> {
+        for (index, raw) in raws.iter().enumerate() {
+            decode(raw.0).map_err(|(err, offset)| err.at(index * 9 + offset))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Float> for RawFloat {
+    fn from(value: Float) -> Self {
+        RawFloat(value.to_raw_bytes())
+    }
+}
+
+impl<'a> TryFrom<FloatRef<'a>> for Float {
+    type Error = ParseFloatError;
+
+    fn try_from(value: FloatRef<'a>) -> Result<Self, Self::Error> {
+        value.to_float()
+    }
+}
+
+impl PartialEq for FloatRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for FloatRef<'_> {
+    /// Compares by value, like [`Float`]. Returns `None` if either view holds invalid bytes.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.to_float().ok()?.cmp(&other.to_float().ok()?))
+    }
+}
+
+impl PartialEq<Float> for FloatRef<'_> {
+    fn eq(&self, other: &Float) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd<Float> for FloatRef<'_> {
+    fn partial_cmp(&self, other: &Float) -> Option<Ordering> {
+        Some(self.to_float().ok()?.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tifloat, Float, FloatRef, ParseFloatError, RawFloat};
+
+    #[test]
+    fn view() {
+        let bytes = tifloat!(-0x12345000000000 * 10 ^ -4).to_raw_bytes();
+        let view = FloatRef::new(&bytes);
+
+        assert!(view.is_negative());
+        assert!(!view.is_undefined());
+        assert_eq!(view.exponent(), -4);
+        assert_eq!(view.as_bytes(), &bytes);
+        assert_eq!(
+            Float::try_from(view).unwrap(),
+            tifloat!(-0x12345000000000 * 10 ^ -4)
+        );
+    }
+
+    #[test]
+    fn scan() {
+        let mut file = vec![0xFF];
+        for float in [Float::PI, -Float::ONE, Float::E, Float::MAX] {
+            file.extend(float.to_raw_bytes());
+        }
+
+        let largest = file[1..]
+            .chunks_exact(9)
+            .map(|chunk| FloatRef::from_slice(chunk).unwrap())
+            .filter(|view| !view.is_negative() && view.exponent() == 0)
+            .max_by(|a, b| a.partial_cmp(b).unwrap())
+            .unwrap();
+
+        assert_eq!(largest, Float::PI);
+        assert_eq!(
+            FloatRef::from_slice(&file[..8]).unwrap_err(),
+            ParseFloatError::InvalidLength
+        );
+    }
+
+    #[test]
+    fn comparisons() {
+        let zero = Float::ZERO.to_raw_bytes();
+        let negative_zero = Float::new_unchecked(true, 5, 0).to_raw_bytes();
+        let one = Float::ONE.to_raw_bytes();
+        let invalid = [0x00, 0x80, 0xAA, 0, 0, 0, 0, 0, 0];
+
+        assert_eq!(FloatRef::new(&zero), FloatRef::new(&negative_zero));
+        assert!(FloatRef::new(&zero) < FloatRef::new(&one));
+        assert!(FloatRef::new(&one) > Float::ZERO);
+
+        assert!(FloatRef::new(&invalid) != FloatRef::new(&invalid));
+        assert_eq!(FloatRef::new(&invalid).partial_cmp(&Float::ONE), None);
+        assert_eq!(
+            FloatRef::new(&invalid).to_float(),
+            Err(ParseFloatError::InvalidMantissa)
+        );
+    }
+
+    #[test]
+    fn raw() {
+        let mut raws = [Float::PI, Float::E, -Float::ONE].map(RawFloat::from);
+
+        assert_eq!(RawFloat::validate(&raws), Ok(()));
+        assert_eq!(raws[1].to_float().unwrap(), Float::E);
+        assert_eq!(raws[2].as_float_ref(), -Float::ONE);
+
+        raws[2].0[1] = 0x00;
+        assert_eq!(
+            RawFloat::validate(&raws),
+            Err(ParseFloatError::InvalidExponent.at(19))
+        );
+    }
+}
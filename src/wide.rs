@@ -0,0 +1,519 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::mantissa::Mantissa;
+use crate::uint::U256;
+use crate::{Float, FloatError, RoundingMode};
+
+/// Significant digits of a wide value, twice those of a [`Float`].
+const DIGITS: u32 = 28;
+
+/// The significand of a wide value.
+type Significand = Mantissa<{ DIGITS as usize }, u128>;
+
+/// A double-precision float with a 28-digit significand and the same `-99..=99` exponent range
+/// as [`Float`], for intermediate results that need more digits than a Float has.
+///
+/// [`Float::widening_mul`] and [`Float::widening_add`] produce wide values from floats, and
+/// [`WideFloat::to_float`] rounds back. Operations between wide values round half-up to 28
+/// significant digits. An undefined operand makes the result undefined.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WideFloat {
+    negative: bool,
+    /// Place-value of the leading digit.
+    exponent: i16,
+    /// Zero, or normalized to a nonzero leading digit.
+    significand: Significand,
+    undefined: bool,
+}
+
+impl WideFloat {
+    pub const ZERO: WideFloat = WideFloat {
+        negative: false,
+        exponent: 0,
+        significand: Significand::from_unchecked(0),
+        undefined: false,
+    };
+
+    const UNDEFINED: WideFloat = WideFloat {
+        undefined: true,
+        ..WideFloat::ZERO
+    };
+
+    /// Assembles a wide value from a rounded significand and the place-value of its leading
+    /// digit, failing if that is outside `-99..=99`.
+    fn from_parts(
+        negative: bool,
+        exponent: i32,
+        significand: Significand,
+    ) -> Result<Self, FloatError> {
+        if exponent > 99 {
+            Err(FloatError::Overflow)
+        } else if exponent < -99 {
+            Err(FloatError::Underflow)
+        } else {
+            Ok(WideFloat {
+                negative,
+                exponent: exponent as i16,
+                significand,
+                undefined: false,
+            })
+        }
+    }
+
+    /// Place-value of the last digit.
+    fn lsd_exponent(&self) -> i32 {
+        self.exponent as i32 - (DIGITS as i32 - 1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.significand.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_undefined(&self) -> bool {
+        self.undefined
+    }
+
+    /// The exponent of the leading digit.
+    pub fn exponent(&self) -> i8 {
+        self.exponent as i8
+    }
+
+    /// The significand as a 28-digit decimal integer, or zero.
+    pub fn significand_dec(&self) -> u128 {
+        self.significand.to_dec()
+    }
+
+    /// Rounds half-up to the nearest [`Float`]. The result is correctly rounded, since the wide
+    /// value is exact.
+    pub fn to_float(self) -> Result<Float, FloatError> {
+        if self.undefined {
+            Ok(Float::undefined())
+        } else if self.is_zero() {
+            Ok(Float::ZERO)
+        } else {
+            Float::from_scaled_integer(
+                self.negative,
+                self.significand.to_dec(),
+                self.lsd_exponent() as i64,
+            )
+        }
+    }
+}
+
+impl Float {
+    /// Multiplies two floats exactly, keeping all 28 digits of the product.
+    pub fn widening_mul(self, rhs: Float) -> Result<WideFloat, FloatError> {
+        WideFloat::from(self) * WideFloat::from(rhs)
+    }
+
+    /// `self * a + b`, rounded half-up to 14 significant digits once, rather than after both the
+    /// product and the sum as `(self * a)? + b` rounds it. The product is kept exact, so it may
+    /// be out of range on its own as long as the result is not.
+    ///
+    /// Fails with [`FloatError::Overflow`] or [`FloatError::Underflow`] if the result is out of
+    /// range. If any operand is undefined, the result is undefined.
+    pub fn mul_add(&self, a: &Float, b: &Float) -> Result<Float, FloatError> {
+        if self.is_undefined() || a.is_undefined() || b.is_undefined() {
+            return Ok(Float::undefined());
+        }
+
+        // each term is a sign, a magnitude, and the place-value of its last digit
+        let product = (
+            self.is_negative() != a.is_negative(),
+            self.significand_dec() as u128 * a.significand_dec() as u128,
+            self.exponent() as i64 + a.exponent() as i64 - 26,
+        );
+        let addend = (
+            b.is_negative(),
+            b.significand_dec() as u128,
+            b.exponent() as i64 - 13,
+        );
+
+        if product.1 == 0 {
+            return Ok(if b.is_zero() { Float::ZERO } else { *b });
+        } else if addend.1 == 0 {
+            return U256::new(product.1).to_float(product.0, product.2);
+        }
+
+        let top = |(_, magnitude, place): (bool, u128, i64)| place + magnitude.ilog10() as i64;
+        let (large, small) = if top(product) >= top(addend) {
+            (product, addend)
+        } else {
+            (addend, product)
+        };
+
+        // align both terms to a common place, no more than 75 digits below the larger term's
+        // leading digit. Only the smaller term can lose digits there, and like in a wide sum, it's
+        // truncated, or rounded up when it's subtracted, so that the exact result lies strictly
+        // between the computed one and the next integer.
+        let place = large.2.min(small.2).max(top(large) - 74);
+        let align = |(_, magnitude, term_place): (bool, u128, i64)| {
+            if term_place >= place {
+                (scale_up(magnitude, (term_place - place) as u32), false)
+            } else {
+                let divisor = 10u128.checked_pow((place - term_place) as u32);
+                let quotient = divisor.map_or(0, |divisor| magnitude / divisor);
+
+                (
+                    U256::new(quotient),
+                    divisor.is_none_or(|divisor| magnitude % divisor != 0),
+                )
+            }
+        };
+
+        let (large_magnitude, _) = align(large);
+        let (small_magnitude, inexact) = align(small);
+
+        let (negative, magnitude) = if large.0 == small.0 {
+            (
+                large.0,
+                large_magnitude.checked_add(small_magnitude).unwrap(),
+            )
+        } else if inexact {
+            let difference = large_magnitude.checked_sub(small_magnitude).unwrap();
+
+            (large.0, difference.checked_sub(U256::new(1)).unwrap())
+        } else if large_magnitude >= small_magnitude {
+            (
+                large.0,
+                large_magnitude.checked_sub(small_magnitude).unwrap(),
+            )
+        } else {
+            (
+                small.0,
+                small_magnitude.checked_sub(large_magnitude).unwrap(),
+            )
+        };
+
+        if magnitude == U256::new(0) {
+            Ok(Float::ZERO)
+        } else if inexact {
+            // a final nonzero digit stands in for the exact result's remaining digits
+            magnitude
+                .checked_mul(10)
+                .and_then(|magnitude| magnitude.checked_add(U256::new(1)))
+                .unwrap()
+                .to_float(negative, place - 1)
+        } else {
+            magnitude.to_float(negative, place)
+        }
+    }
+
+    /// Adds two floats, keeping 28 digits of the sum. The sum is exact unless the exponents of
+    /// the operands are more than 14 apart.
+    pub fn widening_add(self, rhs: Float) -> Result<WideFloat, FloatError> {
+        WideFloat::from(self) + WideFloat::from(rhs)
+    }
+}
+
+/// `magnitude * 10 ^ places`, for a result less than `10 ^ 77`.
+fn scale_up(magnitude: u128, places: u32) -> U256 {
+    let scaled = U256::new(magnitude).checked_mul(10u128.pow(places.min(38)));
+
+    scaled
+        .and_then(|scaled| scaled.checked_mul(10u128.pow(places.saturating_sub(38))))
+        .unwrap()
+}
+
+impl From<Float> for WideFloat {
+    fn from(float: Float) -> Self {
+        if float.is_undefined() {
+            WideFloat::UNDEFINED
+        } else if float.is_zero() {
+            WideFloat::ZERO
+        } else {
+            WideFloat {
+                negative: float.is_negative(),
+                exponent: float.exponent() as i16,
+                significand: Significand::from_dec(
+                    float.significand_dec() as u128 * 10u128.pow(DIGITS - 14),
+                ),
+                undefined: false,
+            }
+        }
+    }
+}
+
+impl Neg for WideFloat {
+    type Output = WideFloat;
+
+    fn neg(self) -> Self::Output {
+        if self.is_zero() || self.undefined {
+            self
+        } else {
+            WideFloat {
+                negative: !self.negative,
+                ..self
+            }
+        }
+    }
+}
+
+impl Add for WideFloat {
+    type Output = Result<WideFloat, FloatError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.undefined || rhs.undefined {
+            return Ok(WideFloat::UNDEFINED);
+        } else if rhs.is_zero() {
+            return Ok(self);
+        } else if self.is_zero() {
+            return Ok(rhs);
+        }
+
+        let (a, b) = if self.exponent < rhs.exponent {
+            (rhs, self)
+        } else {
+            (self, rhs)
+        };
+
+        let Some((significand, shift, borrowed)) = a.significand.rounded_sum(
+            b.significand,
+            (a.exponent - b.exponent) as u32,
+            a.negative != b.negative,
+            RoundingMode::HalfUp,
+        ) else {
+            return Ok(WideFloat::ZERO);
+        };
+
+        WideFloat::from_parts(
+            a.negative != borrowed,
+            a.exponent as i32 + shift,
+            significand,
+        )
+    }
+}
+
+impl Sub for WideFloat {
+    type Output = Result<WideFloat, FloatError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for WideFloat {
+    type Output = Result<WideFloat, FloatError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.undefined || rhs.undefined {
+            return Ok(WideFloat::UNDEFINED);
+        } else if self.is_zero() || rhs.is_zero() {
+            return Ok(WideFloat::ZERO);
+        }
+
+        let (significand, shift) = self
+            .significand
+            .overflowing_mul(rhs.significand, RoundingMode::HalfUp);
+
+        WideFloat::from_parts(
+            self.negative != rhs.negative,
+            self.exponent as i32 + rhs.exponent as i32 + shift as i32,
+            significand,
+        )
+    }
+}
+
+impl Div for WideFloat {
+    type Output = Result<WideFloat, FloatError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if self.undefined || rhs.undefined {
+            return Ok(WideFloat::UNDEFINED);
+        } else if rhs.is_zero() {
+            return Err(FloatError::DivideByZero);
+        } else if self.is_zero() {
+            return Ok(WideFloat::ZERO);
+        }
+
+        let (significand, shift) = self
+            .significand
+            .overflowing_div(rhs.significand, RoundingMode::HalfUp);
+
+        WideFloat::from_parts(
+            self.negative != rhs.negative,
+            self.exponent as i32 - rhs.exponent as i32 - 1 + shift as i32,
+            significand,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tifloat;
+
+    #[test]
+    fn widening_mul() {
+        let a = tifloat!(0x12345678901234 * 10 ^ 3);
+        let b = tifloat!(-0x98765432109876 * 10 ^ -5);
+
+        let product = a.widening_mul(b).unwrap();
+
+        assert!(product.is_negative());
+        assert_eq!(product.exponent(), -1);
+        assert_eq!(product.significand_dec(), 12345678901234 * 98765432109876);
+        assert_eq!(product.to_float().unwrap(), (a * b).unwrap());
+    }
+
+    #[test]
+    fn widening_add() {
+        let a = tifloat!(0x12345678901234 * 10 ^ 0);
+        let b = tifloat!(0x56789012345678 * 10 ^ -10);
+
+        let sum = a.widening_add(b).unwrap();
+
+        assert_eq!(sum.exponent(), 0);
+        assert_eq!(sum.significand_dec(), 1234567890691290123456780000);
+        assert_eq!(sum.to_float().unwrap(), (a + b).unwrap());
+
+        // cancellation keeps the low digits
+        let c = a.widening_add(-a.next_up().unwrap()).unwrap();
+        assert_eq!(
+            c.to_float().unwrap(),
+            tifloat!(-0x10000000000000 * 10 ^ -13)
+        );
+    }
+
+    #[test]
+    fn wide_arithmetic() {
+        let three = WideFloat::from(tifloat!(0x30000000000000 * 10 ^ 0));
+        let third = (WideFloat::from(Float::ONE) / three).unwrap();
+
+        assert_eq!(third.significand_dec(), 3333333333333333333333333333);
+        assert_eq!(third.exponent(), -1);
+        assert_eq!(
+            (third * three).unwrap().significand_dec(),
+            9999999999999999999999999999
+        );
+
+        // the sum of the rounding errors shows up in the 28th digit
+        let sum = ((third + third).unwrap() + third).unwrap();
+        assert_eq!(sum.significand_dec(), 9999999999999999999999999999);
+        assert_eq!(sum.to_float().unwrap(), Float::ONE);
+
+        let tiny = WideFloat::from(tifloat!(0x10000000000000 * 10 ^ -40));
+        assert_eq!((three - tiny).unwrap(), three);
+        assert_eq!(
+            (three - WideFloat::from(tifloat!(0x10000000000000 * 10 ^ -27)))
+                .unwrap()
+                .significand_dec(),
+            2999999999999999999999999999
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        // one and a half units in the last place round up
+        let a = WideFloat::from(tifloat!(0x10000000000000 * 10 ^ 0));
+        let b = WideFloat::from(tifloat!(0x15000000000000 * 10 ^ -27));
+        assert_eq!(
+            (a + b).unwrap().significand_dec(),
+            1000000000000000000000000002
+        );
+
+        // just over half of the last place, where the truncated digits must still borrow
+        let five = WideFloat::from(tifloat!(0x50000000000000 * 10 ^ 0));
+        let c = WideFloat::from(tifloat!(0x50000000000001 * 10 ^ -28));
+        assert_eq!(
+            (five - c).unwrap().significand_dec(),
+            4999999999999999999999999999
+        );
+    }
+
+    #[test]
+    fn mul_add() {
+        let five = tifloat!(0x50000000000000 * 10 ^ 0);
+        let x = tifloat!(0x10000000000001 * 10 ^ 0);
+        let tiny = tifloat!(0x10000000000000 * 10 ^ -60);
+
+        let cases = [
+            // x * a + b, where rounding the product first would give a different result
+            (
+                x,
+                x,
+                tifloat!(-0x10000000000002 * 10 ^ 0),
+                tifloat!(0x10000000000000 * 10 ^ -26),
+            ),
+            (
+                five,
+                tifloat!(0x99999999999999 * 10 ^ -15),
+                Float::ONE,
+                Float::ONE,
+            ),
+            (tiny, tiny, Float::MIN_POSITIVE, Float::MIN_POSITIVE),
+            (
+                tifloat!(0x10000000000000 * 10 ^ 60),
+                tifloat!(0x10000000000000 * 10 ^ 40),
+                -Float::MAX,
+                tifloat!(0x10000000000000 * 10 ^ 86),
+            ),
+            // terms far apart, in either order
+            (
+                Float::ONE,
+                Float::ONE,
+                tifloat!(-0x10000000000000 * 10 ^ -80),
+                Float::ONE,
+            ),
+            (
+                tiny,
+                tiny,
+                tifloat!(-0x50000000000005 * 10 ^ -99),
+                tifloat!(-0x50000000000005 * 10 ^ -99),
+            ),
+            (
+                tifloat!(-0x20000000000000 * 10 ^ 0),
+                tifloat!(0x30000000000000 * 10 ^ 0),
+                Float::ONE,
+                tifloat!(-0x50000000000000 * 10 ^ 0),
+            ),
+            (
+                tifloat!(0x10000000000000 * 10 ^ -1),
+                tifloat!(0x30000000000000 * 10 ^ 0),
+                tifloat!(-0x30000000000000 * 10 ^ -1),
+                Float::ZERO,
+            ),
+            (Float::ZERO, five, -five, -five),
+            (x, x, Float::ZERO, tifloat!(0x10000000000002 * 10 ^ 0)),
+        ];
+
+        for (x, a, b, expected) in cases {
+            assert_eq!(x.mul_add(&a, &b), Ok(expected), "{x:?} * {a:?} + {b:?}");
+        }
+
+        assert!(x.mul_add(&Float::undefined(), &x).unwrap().is_undefined());
+        assert_eq!(
+            Float::MAX.mul_add(&Float::MAX, &-Float::MAX),
+            Err(FloatError::Overflow)
+        );
+        assert_eq!(
+            tiny.mul_add(&tiny, &Float::ZERO),
+            Err(FloatError::Underflow)
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let big = tifloat!(0x50000000000000 * 10 ^ 99);
+
+        assert!(matches!(big.widening_add(big), Err(FloatError::Overflow)));
+        assert!(matches!(
+            Float::MIN_POSITIVE.widening_mul(Float::MIN_POSITIVE),
+            Err(FloatError::Underflow)
+        ));
+        assert!(matches!(
+            WideFloat::from(Float::ONE) / WideFloat::ZERO,
+            Err(FloatError::DivideByZero)
+        ));
+        assert!(Float::undefined()
+            .widening_mul(Float::ONE)
+            .unwrap()
+            .to_float()
+            .unwrap()
+            .is_undefined());
+    }
+}